@@ -3,7 +3,11 @@
 // use yew::platform::spawn_local;
 // use yewdux::{prelude::Dispatch, store::Reducer};
 
-mod a1_report;
+pub mod a1_report;
+mod a2_report;
 mod fan_series;
 mod fan_size;
+mod me;
+mod search;
+mod selection;
 pub mod store;
@@ -1,12 +1,20 @@
 use std::future::Future;
 
 use gloo_net::http;
-use loquat_common::api::a1_2010_report;
+use loquat_common::api::{a1_2010_report, ListQuery};
 use serde::Serialize;
 use serde_json::value::Serializer;
 
 const INDEX_REQ_URL: &str = "/api/a1_2010_report";
 
+pub fn index(
+    query: ListQuery,
+) -> impl Future<Output = Result<gloo_net::http::Response, gloo_net::Error>> {
+    http::Request::get(INDEX_REQ_URL)
+        .query(query.query_params())
+        .send()
+}
+
 pub fn get(id: String) -> impl Future<Output = Result<gloo_net::http::Response, gloo_net::Error>> {
     let req_url = format!("{}/{}", INDEX_REQ_URL, id);
     http::Request::get(req_url.as_str()).send()
@@ -30,3 +38,32 @@ pub fn post(
         .body(payload.serialize(Serializer).unwrap().to_string())
         .send()
 }
+
+// A plain link rather than a request, so the browser can show or save the file itself
+pub fn pdf_url(id: &str, query: &a1_2010_report::PdfQuery) -> String {
+    let req_url = format!("{}/{}/pdf", INDEX_REQ_URL, id);
+    match web_sys::UrlSearchParams::new() {
+        Ok(params) if !query.query_params().is_empty() => {
+            for (name, value) in query.query_params() {
+                params.append(name, &value);
+            }
+            format!("{}?{}", req_url, String::from(params.to_string()))
+        }
+        _ => req_url,
+    }
+}
+
+pub fn revisions(
+    id: String,
+) -> impl Future<Output = Result<gloo_net::http::Response, gloo_net::Error>> {
+    let req_url = format!("{}/{}/revisions", INDEX_REQ_URL, id);
+    http::Request::get(req_url.as_str()).send()
+}
+
+pub fn restore_revision(
+    id: String,
+    revision_id: i64,
+) -> impl Future<Output = Result<gloo_net::http::Response, gloo_net::Error>> {
+    let req_url = format!("{}/{}/revisions/{}/restore", INDEX_REQ_URL, id, revision_id);
+    http::Request::post(req_url.as_str()).send()
+}
@@ -7,8 +7,12 @@ use serde_json::value::Serializer;
 
 const INDEX_REQ_URL: &str = "/api/fan_series";
 
-pub fn index() -> impl Future<Output = Result<gloo_net::http::Response, gloo_net::Error>> {
-    http::Request::get(INDEX_REQ_URL).send()
+pub fn index(
+    query: api::ListQuery,
+) -> impl Future<Output = Result<gloo_net::http::Response, gloo_net::Error>> {
+    http::Request::get(INDEX_REQ_URL)
+        .query(query.query_params())
+        .send()
 }
 
 pub fn get(id: String) -> impl Future<Output = Result<gloo_net::http::Response, gloo_net::Error>> {
@@ -1,14 +1,50 @@
 use std::future::Future;
 
 use gloo_net::http;
+use loquat_common::api;
+use serde::Serialize;
+use serde_json::value::Serializer;
 
 const INDEX_REQ_URL: &str = "/api/fan_sizes";
 
-pub fn index() -> impl Future<Output = Result<gloo_net::http::Response, gloo_net::Error>> {
-    http::Request::get(INDEX_REQ_URL).send()
+pub fn index(
+    query: api::ListQuery,
+) -> impl Future<Output = Result<gloo_net::http::Response, gloo_net::Error>> {
+    http::Request::get(INDEX_REQ_URL)
+        .query(query.query_params())
+        .send()
 }
 
 pub fn get(id: String) -> impl Future<Output = Result<gloo_net::http::Response, gloo_net::Error>> {
     let req_url = format!("{}/{}", INDEX_REQ_URL, id);
     http::Request::get(req_url.as_str()).send()
 }
+
+pub fn rating_table(
+    id: String,
+    query: api::rating_table::RatingTableQuery,
+) -> impl Future<Output = Result<gloo_net::http::Response, gloo_net::Error>> {
+    let req_url = format!("{}/{}/rating_table", INDEX_REQ_URL, id);
+    http::Request::get(req_url.as_str())
+        .query(query.query_params())
+        .send()
+}
+
+pub fn put(
+    payload: api::fan_size::UpdateBody,
+) -> impl Future<Output = Result<gloo_net::http::Response, gloo_net::Error>> {
+    let req_url = format!("{}/{}", INDEX_REQ_URL, payload.id);
+    http::Request::put(req_url.as_str())
+        .header("Content-Type", "application/json")
+        .body(payload.serialize(Serializer).unwrap().to_string())
+        .send()
+}
+
+pub fn post(
+    payload: api::fan_size::UpdateBody,
+) -> impl Future<Output = Result<gloo_net::http::Response, gloo_net::Error>> {
+    http::Request::post(INDEX_REQ_URL)
+        .header("Content-Type", "application/json")
+        .body(payload.serialize(Serializer).unwrap().to_string())
+        .send()
+}
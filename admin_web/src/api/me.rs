@@ -0,0 +1,21 @@
+use std::future::Future;
+
+use gloo_net::http;
+use loquat_common::api::user::PreferencesBody;
+use serde::Serialize;
+use serde_json::value::Serializer;
+
+const REQ_URL: &str = "/api/me";
+
+pub fn get() -> impl Future<Output = Result<gloo_net::http::Response, gloo_net::Error>> {
+    http::Request::get(REQ_URL).send()
+}
+
+pub fn put(
+    payload: PreferencesBody,
+) -> impl Future<Output = Result<gloo_net::http::Response, gloo_net::Error>> {
+    http::Request::put(REQ_URL)
+        .header("Content-Type", "application/json")
+        .body(payload.serialize(Serializer).unwrap().to_string())
+        .send()
+}
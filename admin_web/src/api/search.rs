@@ -0,0 +1,14 @@
+use std::future::Future;
+
+use gloo_net::http;
+use loquat_common::api::ListQuery;
+
+const INDEX_REQ_URL: &str = "/api/search";
+
+pub fn index(
+    query: ListQuery,
+) -> impl Future<Output = Result<gloo_net::http::Response, gloo_net::Error>> {
+    http::Request::get(INDEX_REQ_URL)
+        .query(query.query_params())
+        .send()
+}
@@ -1,7 +1,10 @@
 use std::{collections::HashMap, future::Future, rc::Rc};
 
 use instant::Instant;
-use loquat_common::models::{A1Standard2010Report, FanSeries, FanSize};
+use loquat_common::{
+    api::{ApiError, ApiErrorCode, ListQuery, Page},
+    models::{A1Standard2010Report, FanSeries, FanSize, User},
+};
 use serde;
 use yew::platform::spawn_local;
 use yewdux::{
@@ -11,7 +14,7 @@ use yewdux::{
 
 use crate::store::app_dispatch;
 
-use super::{a1_report, fan_series, fan_size};
+use super::{a1_report, a2_report, fan_series, fan_size, me, search, selection};
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, prelude::Store)]
 pub struct Store {
@@ -25,7 +28,7 @@ pub enum RequestStatuses {
     Fetching(Instant),
     Refetching(Instant, Box<RequestStatuses>),
     Fetched(Instant),
-    Error(Instant, String),
+    Error(Instant, ApiError),
 }
 
 fn should_fetch(current_status: RequestStatuses, force: bool) -> bool {
@@ -52,38 +55,114 @@ pub enum ApiRequestAction {
 
 #[derive(Debug, Clone)]
 pub enum ApiResponseAction {
-    RecieveFanSerieses(Vec<FanSeries<()>>),
+    RecieveFanSerieses(ListQuery, Page<FanSeries<()>>),
     RecieveFanSeries(FanSeries<Vec<FanSize<()>>>),
-    RecieveFanSizes(Vec<FanSize<()>>),
+    RecieveFanSizes(ListQuery, Page<FanSize<()>>),
     RecieveFanSize(FanSize<FanSeries<()>>),
+    RecieveA1Reports(ListQuery, loquat_common::api::a1_2010_report::IndexResponse),
     RecieveA1Report(A1Standard2010Report<FanSize<FanSeries<()>>>),
+    RecieveA1ReportRevisions(String, loquat_common::api::a1_2010_report::RevisionsResponse),
+    RecieveA2Report(loquat_common::api::a2_2010_report::GetResponse),
+    RecieveSelections(
+        loquat_common::api::selection::IndexQuery,
+        loquat_common::api::selection::IndexResponse,
+    ),
+    RecieveSearchResults(ListQuery, loquat_common::api::search::IndexResponse),
+    RecieveCurrentUser(User),
+    RecieveRatingTable(
+        String,
+        loquat_common::api::rating_table::RatingTableQuery,
+        loquat_common::api::rating_table::RatingTableResponse,
+    ),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Gettable {
-    FanSeriesesIndex,
+    FanSeriesesIndex {
+        query: ListQuery,
+    },
     FanSeries {
         id: String,
     },
-    FanSizesIndex,
+    FanSizesIndex {
+        query: ListQuery,
+    },
     FanSize {
         id: String,
     },
+    RatingTable {
+        id: String,
+        query: loquat_common::api::rating_table::RatingTableQuery,
+    },
+    PutFanSize {
+        body: loquat_common::api::fan_size::UpdateBody,
+    },
+    PostFanSize {
+        body: loquat_common::api::fan_size::UpdateBody,
+    },
+    A1ReportsIndex {
+        query: ListQuery,
+    },
     A1Report {
         id: String,
     },
+    A1ReportRevisions {
+        id: String,
+    },
+    RestoreA1ReportRevision {
+        id: String,
+        revision_id: i64,
+    },
     PutA12010Report {
         body: loquat_common::api::a1_2010_report::UpdateBody,
     },
     PostA12010Report {
         body: loquat_common::api::a1_2010_report::UpdateBody,
     },
+    A2Report {
+        id: String,
+    },
+    PutA22010Report {
+        body: loquat_common::api::a2_2010_report::UpdateBody,
+    },
+    PostA22010Report {
+        body: loquat_common::api::a2_2010_report::UpdateBody,
+    },
     PutFanSeriesReport {
         body: loquat_common::api::fan_series::UpdateBody,
     },
     PostFanSeriesReport {
         body: loquat_common::api::fan_series::UpdateBody,
     },
+    Selections {
+        query: loquat_common::api::selection::IndexQuery,
+    },
+    Search {
+        query: ListQuery,
+    },
+    CurrentUser,
+    PutPreferences {
+        body: loquat_common::api::user::PreferencesBody,
+    },
+}
+
+impl Gettable {
+    // Saves are triggered by a click, so their errors are shown straight away
+    fn is_write(&self) -> bool {
+        matches!(
+            self,
+            Gettable::PutFanSize { .. }
+                | Gettable::PostFanSize { .. }
+                | Gettable::RestoreA1ReportRevision { .. }
+                | Gettable::PutA12010Report { .. }
+                | Gettable::PostA12010Report { .. }
+                | Gettable::PutA22010Report { .. }
+                | Gettable::PostA22010Report { .. }
+                | Gettable::PutFanSeriesReport { .. }
+                | Gettable::PostFanSeriesReport { .. }
+                | Gettable::PutPreferences { .. }
+        )
+    }
 }
 
 impl Reducer<Store> for ApiRequestAction {
@@ -97,31 +176,65 @@ impl Reducer<Store> for ApiRequestAction {
                     return store;
                 }
                 match gettable.clone() {
-                    Gettable::FanSeriesesIndex => handle_dispatches(
+                    Gettable::FanSeriesesIndex { query } => handle_dispatches(
                         gettable,
-                        fan_series::index(),
-                        ApiResponseAction::RecieveFanSerieses,
+                        fan_series::index(query.clone()),
+                        move |page| ApiResponseAction::RecieveFanSerieses(query.clone(), page),
                     ),
                     Gettable::FanSeries { id } => handle_dispatches(
                         gettable,
                         fan_series::get(id),
                         ApiResponseAction::RecieveFanSeries,
                     ),
-                    Gettable::FanSizesIndex => handle_dispatches(
+                    Gettable::FanSizesIndex { query } => handle_dispatches(
                         gettable,
-                        fan_size::index(),
-                        ApiResponseAction::RecieveFanSizes,
+                        fan_size::index(query.clone()),
+                        move |page| ApiResponseAction::RecieveFanSizes(query.clone(), page),
                     ),
                     Gettable::FanSize { id } => handle_dispatches(
                         gettable,
                         fan_size::get(id),
                         ApiResponseAction::RecieveFanSize,
                     ),
+                    Gettable::RatingTable { id, query } => handle_dispatches(
+                        gettable,
+                        fan_size::rating_table(id.clone(), query.clone()),
+                        move |table| {
+                            ApiResponseAction::RecieveRatingTable(id.clone(), query.clone(), table)
+                        },
+                    ),
+                    Gettable::PutFanSize { body } => handle_dispatches(
+                        gettable,
+                        fan_size::put(body),
+                        ApiResponseAction::RecieveFanSize,
+                    ),
+                    Gettable::PostFanSize { body } => handle_dispatches(
+                        gettable,
+                        fan_size::post(body),
+                        ApiResponseAction::RecieveFanSize,
+                    ),
+                    Gettable::A1ReportsIndex { query } => handle_dispatches(
+                        gettable,
+                        a1_report::index(query.clone()),
+                        move |page| ApiResponseAction::RecieveA1Reports(query.clone(), page),
+                    ),
                     Gettable::A1Report { id } => handle_dispatches(
                         gettable,
                         a1_report::get(id),
                         ApiResponseAction::RecieveA1Report,
                     ),
+                    Gettable::A1ReportRevisions { id } => handle_dispatches(
+                        gettable,
+                        a1_report::revisions(id.clone()),
+                        move |revisions| {
+                            ApiResponseAction::RecieveA1ReportRevisions(id.clone(), revisions)
+                        },
+                    ),
+                    Gettable::RestoreA1ReportRevision { id, revision_id } => handle_dispatches(
+                        gettable,
+                        a1_report::restore_revision(id, revision_id),
+                        ApiResponseAction::RecieveA1Report,
+                    ),
                     Gettable::PutA12010Report { body } => handle_dispatches(
                         gettable,
                         a1_report::put(body),
@@ -132,6 +245,21 @@ impl Reducer<Store> for ApiRequestAction {
                         a1_report::post(body),
                         ApiResponseAction::RecieveA1Report,
                     ),
+                    Gettable::A2Report { id } => handle_dispatches(
+                        gettable,
+                        a2_report::get(id),
+                        ApiResponseAction::RecieveA2Report,
+                    ),
+                    Gettable::PutA22010Report { body } => handle_dispatches(
+                        gettable,
+                        a2_report::put(body),
+                        ApiResponseAction::RecieveA2Report,
+                    ),
+                    Gettable::PostA22010Report { body } => handle_dispatches(
+                        gettable,
+                        a2_report::post(body),
+                        ApiResponseAction::RecieveA2Report,
+                    ),
                     Gettable::PutFanSeriesReport { body }  => handle_dispatches(
                         gettable,
                         fan_series::put(body),
@@ -142,6 +270,30 @@ impl Reducer<Store> for ApiRequestAction {
                         fan_series::post(body),
                         ApiResponseAction::RecieveFanSeries,
                     ),
+                    Gettable::Selections { query } => handle_dispatches(
+                        gettable,
+                        selection::index(query.clone()),
+                        move |selections| {
+                            ApiResponseAction::RecieveSelections(query.clone(), selections)
+                        },
+                    ),
+                    Gettable::Search { query } => handle_dispatches(
+                        gettable,
+                        search::index(query.clone()),
+                        move |results| {
+                            ApiResponseAction::RecieveSearchResults(query.clone(), results)
+                        },
+                    ),
+                    Gettable::CurrentUser => handle_dispatches(
+                        gettable,
+                        me::get(),
+                        ApiResponseAction::RecieveCurrentUser,
+                    ),
+                    Gettable::PutPreferences { body } => handle_dispatches(
+                        gettable,
+                        me::put(body),
+                        ApiResponseAction::RecieveCurrentUser,
+                    ),
                 }
             }
         }
@@ -170,19 +322,28 @@ fn handle_dispatches<Fut, Resp, ActionFactory>(
 
         if let Ok(resp) = api_resp {
             if !resp.ok() {
-                let err_msg = format!(
-                    "Error fetching data {} ({})",
-                    resp.status(),
-                    resp.status_text()
-                );
-                if resp.status() == 401 {
+                // Errors from the API have an ApiError body, but ones from a proxy may not
+                let err = resp.json::<ApiError>().await.unwrap_or_else(|_| {
+                    ApiError::new(
+                        ApiErrorCode::from_status(resp.status()),
+                        format!(
+                            "Error fetching data {} ({})",
+                            resp.status(),
+                            resp.status_text()
+                        ),
+                    )
+                });
+                log::warn!("{:?} failed: {:?}", gettable, err);
+                if err.code == ApiErrorCode::Unauthorized {
                     // not authed
                     log::warn!("401 unauthorized, redirecting");
                     web_sys::window().map(|w| w.location().set_pathname("/static/login.html"));
+                } else if gettable.is_write() {
+                    web_sys::window().map(|w| w.alert_with_message(&err.to_string()));
                 }
                 dispatch.reduce_mut(|s| {
                     s.get_status
-                        .insert(gettable, RequestStatuses::Error(Instant::now(), err_msg));
+                        .insert(gettable, RequestStatuses::Error(Instant::now(), err));
                 });
             } else {
                 let json_parse = resp.json().await;
@@ -196,7 +357,10 @@ fn handle_dispatches<Fut, Resp, ActionFactory>(
                     dispatch.reduce_mut(|s| {
                         s.get_status.insert(
                             gettable,
-                            RequestStatuses::Error(Instant::now(), err.to_string()),
+                            RequestStatuses::Error(
+                                Instant::now(),
+                                ApiError::internal(err.to_string()),
+                            ),
                         );
                     });
                 }
@@ -205,7 +369,10 @@ fn handle_dispatches<Fut, Resp, ActionFactory>(
             dispatch.reduce_mut(|s| {
                 s.get_status.insert(
                     gettable,
-                    RequestStatuses::Error(Instant::now(), error.to_string()),
+                    RequestStatuses::Error(
+                        Instant::now(),
+                        ApiError::internal(error.to_string()),
+                    ),
                 );
             });
         }
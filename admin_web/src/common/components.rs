@@ -2,7 +2,11 @@ pub mod determination_paste_text_area;
 pub use determination_paste_text_area::DeterminationsPasteTextArea;
 pub mod determination_table;
 pub use determination_table::DeterminationTable;
+pub mod determinations_file_drop;
+pub use determinations_file_drop::DeterminationsFileDrop;
 pub mod fan_series_and_size_picker;
 pub use fan_series_and_size_picker::FanSeriesAndSizePicker;
+pub mod pager;
+pub use pager::Pager;
 pub mod select;
 pub use select::Select;
@@ -5,26 +5,27 @@ use yew::{
 };
 
 #[derive(Properties, PartialEq)]
-pub struct DeterminationsPasteTextAreaProps<
-    const EXTRACTED_COLS: usize,
-    const EXTRACTED_ROWS: usize,
-> {
+pub struct DeterminationsPasteTextAreaProps<const EXTRACTED_COLS: usize> {
     pub on_extracted: Callback<Vec<[String; EXTRACTED_COLS]>>,
     pub expected_headers: Vec<&'static str>,
     pub cols_to_extract: [usize; EXTRACTED_COLS],
     pub expected_row_length: usize,
+    // Leave empty to accept however many points are pasted
+    #[prop_or_default]
+    pub expected_row_count: Option<usize>,
 }
 
 #[function_component]
-pub fn DeterminationsPasteTextArea<const EXTRACTED_COLS: usize, const EXTRACTED_ROWS: usize>(
+pub fn DeterminationsPasteTextArea<const EXTRACTED_COLS: usize>(
     DeterminationsPasteTextAreaProps {
         on_extracted,
         expected_headers,
         cols_to_extract,
         expected_row_length,
-    }: &DeterminationsPasteTextAreaProps<EXTRACTED_COLS, EXTRACTED_ROWS>,
+        expected_row_count,
+    }: &DeterminationsPasteTextAreaProps<EXTRACTED_COLS>,
 ) -> Html {
-    let total_rows = EXTRACTED_ROWS + expected_headers.len() + 1;
+    let total_rows = expected_row_count.unwrap_or(10) + expected_headers.len() + 1;
     let warnings: UseStateHandle<Vec<String>> = use_state(Vec::new);
     let errors: UseStateHandle<Vec<String>> = use_state(Vec::new);
 
@@ -35,6 +36,7 @@ pub fn DeterminationsPasteTextArea<const EXTRACTED_COLS: usize, const EXTRACTED_
         let on_extracted = on_extracted.clone();
         let cols_to_extract = *cols_to_extract;
         let expected_row_length = *expected_row_length;
+        let expected_row_count = *expected_row_count;
         let warnings_setter = warnings.setter();
         let errors_setter = errors.setter();
         use_callback(
@@ -63,6 +65,8 @@ pub fn DeterminationsPasteTextArea<const EXTRACTED_COLS: usize, const EXTRACTED_
                 );
 
                 let grid: Vec<[String; EXTRACTED_COLS]> = text_rows
+                    // Without a fixed count, a trailing newline isn't another point
+                    .filter(|row_str| expected_row_count.is_some() || !row_str.trim().is_empty())
                     .enumerate()
                     .map(|(i, row_str)| {
                         let split_row = row_str.split_whitespace().collect::<Vec<_>>();
@@ -79,7 +83,7 @@ pub fn DeterminationsPasteTextArea<const EXTRACTED_COLS: usize, const EXTRACTED_
                         }
                     })
                     .collect::<Vec<_>>();
-                if grid.len() != EXTRACTED_ROWS {
+                if expected_row_count.map_or(grid.is_empty(), |count| grid.len() != count) {
                     errors.push("Paste doesn't have the correct number of rows".to_string());
                 }
                 if errors.is_empty() {
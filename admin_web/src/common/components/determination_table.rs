@@ -4,22 +4,23 @@ use web_sys::HtmlInputElement;
 use yew::{function_component, html, use_callback, use_node_ref, Callback, Html, Properties};
 
 #[derive(Properties, PartialEq)]
-pub struct DeterminationTableProps<const COL_COUNT: usize, const ROW_COUNT: usize> {
+pub struct DeterminationTableProps<const COL_COUNT: usize> {
     pub headers: [String; COL_COUNT],
-    pub rows: [[String; COL_COUNT]; ROW_COUNT],
-    #[prop_or_else(|| [(); ROW_COUNT].map(|_| [(); COL_COUNT].map(|_| Rc::new(Vec::new()))))]
-    pub child_errs: [[Rc<Vec<String>>; COL_COUNT]; ROW_COUNT],
+    pub rows: Vec<[String; COL_COUNT]>,
+    // Rows past the end of the errors have none
+    #[prop_or_default]
+    pub child_errs: Vec<[Rc<Vec<String>>; COL_COUNT]>,
     pub onchange: Callback<(usize, usize, String), ()>,
 }
 
 #[function_component]
-pub fn DeterminationTable<const COL_COUNT: usize, const ROW_COUNT: usize>(
+pub fn DeterminationTable<const COL_COUNT: usize>(
     DeterminationTableProps {
         headers,
         rows,
         child_errs,
         onchange,
-    }: &DeterminationTableProps<COL_COUNT, ROW_COUNT>,
+    }: &DeterminationTableProps<COL_COUNT>,
 ) -> Html {
     let header_html: Html = headers
         .clone()
@@ -36,10 +37,13 @@ pub fn DeterminationTable<const COL_COUNT: usize, const ROW_COUNT: usize>(
         </tr>
         {rows
             .iter()
-            .zip(child_errs)
             .enumerate()
-            .map(|(row_index, (d, child_errs))| {
-                html! { <DeterminationTableRow<COL_COUNT> {onchange} {row_index} values={d.clone()} child_errs={child_errs.clone()} /> }
+            .map(|(row_index, d)| {
+                let child_errs = child_errs
+                    .get(row_index)
+                    .cloned()
+                    .unwrap_or_else(|| [(); COL_COUNT].map(|_| Rc::new(Vec::new())));
+                html! { <DeterminationTableRow<COL_COUNT> {onchange} {row_index} values={d.clone()} {child_errs} /> }
             })
             .collect::<Html>()
         }
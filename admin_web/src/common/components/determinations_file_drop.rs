@@ -0,0 +1,238 @@
+use loquat_common::{
+    import::{import_determinations, ImportFormat, ImportOptions, ImportUnit, Quantity},
+    models::A1Standard2010Determination,
+};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{File, HtmlInputElement, HtmlSelectElement};
+use yew::{
+    function_component, html, platform::spawn_local, use_callback, use_state, Callback, DragEvent,
+    Event, Html, Properties, TargetCast, UseStateHandle,
+};
+
+#[derive(Properties, PartialEq)]
+pub struct DeterminationsFileDropProps {
+    pub on_imported: Callback<Vec<A1Standard2010Determination>>,
+}
+
+fn column_mut(options: &mut ImportOptions, quantity: Quantity) -> &mut Option<usize> {
+    match quantity {
+        Quantity::Airflow => &mut options.airflow_column,
+        Quantity::StaticPressure => &mut options.static_pressure_column,
+        Quantity::BrakeHorsepower => &mut options.brake_horsepower_column,
+    }
+}
+
+fn unit_mut(options: &mut ImportOptions, quantity: Quantity) -> &mut Option<ImportUnit> {
+    match quantity {
+        Quantity::Airflow => &mut options.airflow_unit,
+        Quantity::StaticPressure => &mut options.static_pressure_unit,
+        Quantity::BrakeHorsepower => &mut options.brake_horsepower_unit,
+    }
+}
+
+// Reads a CSV, TSV or lab export dropped on it, or picked with its file input. The format,
+// columns and units are detected unless they're picked here first
+#[function_component]
+pub fn DeterminationsFileDrop(
+    DeterminationsFileDropProps { on_imported }: &DeterminationsFileDropProps,
+) -> Html {
+    let dragging_state: UseStateHandle<bool> = use_state(|| false);
+    let messages_state: UseStateHandle<Vec<String>> = use_state(Vec::new);
+    let options_state: UseStateHandle<ImportOptions> = use_state(ImportOptions::default);
+
+    let read_file = {
+        let messages_setter = messages_state.setter();
+        use_callback(
+            move |file: File, (on_imported, options)| {
+                let (messages_setter, on_imported, options) =
+                    (messages_setter.clone(), on_imported.clone(), options.clone());
+                spawn_local(async move {
+                    let text = match JsFuture::from(file.text()).await {
+                        Ok(text) => text.as_string().unwrap_or_default(),
+                        Err(_) => {
+                            messages_setter.set(vec![format!("Could not read {}", file.name())]);
+                            return;
+                        }
+                    };
+                    match import_determinations(&text, &options) {
+                        Ok(import) => {
+                            let mut messages = vec![format!(
+                                "Read {} points from {}, from columns {}, {} and {} in {}, {} and {}",
+                                import.determinations.len(),
+                                file.name(),
+                                import.columns.airflow + 1,
+                                import.columns.static_pressure + 1,
+                                import.columns.brake_horsepower + 1,
+                                import.units.airflow.symbol(),
+                                import.units.static_pressure.symbol(),
+                                import.units.brake_horsepower.symbol(),
+                            )];
+                            messages.extend(import.warnings);
+                            messages_setter.set(messages);
+                            on_imported.emit(import.determinations);
+                        }
+                        Err(err) => messages_setter.set(vec![err.to_string()]),
+                    }
+                });
+            },
+            (on_imported.clone(), (*options_state).clone()),
+        )
+    };
+
+    let on_format_change = {
+        let options_setter = options_state.setter();
+        use_callback(
+            move |evt: Event, options| {
+                let value = evt.target_unchecked_into::<HtmlSelectElement>().value();
+                let mut options = options.clone();
+                options.format = ImportFormat::try_from(&value[..]).ok();
+                options_setter.set(options);
+            },
+            (*options_state).clone(),
+        )
+    };
+    // Columns are numbered from 1 here, as a spreadsheet's are, and left blank to be detected
+    let on_column_change = {
+        let options_setter = options_state.setter();
+        use_callback(
+            move |(quantity, value): (Quantity, String), options| {
+                let mut options = options.clone();
+                *column_mut(&mut options, quantity) = value
+                    .trim()
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|column| column.checked_sub(1));
+                options_setter.set(options);
+            },
+            (*options_state).clone(),
+        )
+    };
+    let on_unit_change = {
+        let options_setter = options_state.setter();
+        use_callback(
+            move |(quantity, value): (Quantity, String), options| {
+                let mut options = options.clone();
+                *unit_mut(&mut options, quantity) = ImportUnit::try_from(&value[..]).ok();
+                options_setter.set(options);
+            },
+            (*options_state).clone(),
+        )
+    };
+
+    let on_drag_over = {
+        let dragging_setter = dragging_state.setter();
+        use_callback(
+            move |evt: DragEvent, _deps| {
+                // Without this the browser opens the file instead of dropping it here
+                evt.prevent_default();
+                dragging_setter.set(true);
+            },
+            (),
+        )
+    };
+    let on_drag_leave = {
+        let dragging_setter = dragging_state.setter();
+        use_callback(move |_evt: DragEvent, _deps| dragging_setter.set(false), ())
+    };
+    let on_drop = {
+        let dragging_setter = dragging_state.setter();
+        use_callback(
+            move |evt: DragEvent, read_file| {
+                evt.prevent_default();
+                dragging_setter.set(false);
+                if let Some(file) = evt
+                    .data_transfer()
+                    .and_then(|data_transfer| data_transfer.files())
+                    .and_then(|files| files.get(0))
+                {
+                    read_file.emit(file);
+                }
+            },
+            read_file.clone(),
+        )
+    };
+    let on_file_pick = use_callback(
+        move |evt: Event, read_file| {
+            let input: HtmlInputElement = evt.target_unchecked_into();
+            if let Some(file) = input.files().and_then(|files| files.get(0)) {
+                read_file.emit(file);
+            }
+        },
+        read_file,
+    );
+
+    let options = (*options_state).clone();
+    let overrides_html: Html = Quantity::all()
+        .into_iter()
+        .map(|quantity| {
+            let on_column_change = on_column_change.clone();
+            let on_unit_change = on_unit_change.clone();
+            let column = options
+                .column(quantity)
+                .map_or("".to_string(), |column| (column + 1).to_string());
+            let unit = options.unit(quantity);
+            html! {
+                <>
+                    <label>{quantity.label()}</label>
+                    <input
+                        type="number"
+                        min="1"
+                        placeholder="Detect"
+                        value={column}
+                        onchange={move |evt: Event| on_column_change.emit((
+                            quantity,
+                            evt.target_unchecked_into::<HtmlInputElement>().value(),
+                        ))}
+                    />
+                    <select onchange={move |evt: Event| on_unit_change.emit((
+                        quantity,
+                        evt.target_unchecked_into::<HtmlSelectElement>().value(),
+                    ))}>
+                        <option value="" selected={unit.is_none()}>{"Detect"}</option>
+                        {ImportUnit::all()
+                            .into_iter()
+                            .filter(|option| option.quantity() == quantity)
+                            .map(|option| html! {
+                                <option value={option.as_str()} selected={Some(option) == unit}>
+                                    {option.symbol()}
+                                </option>
+                            })
+                            .collect::<Html>()}
+                    </select>
+                </>
+            }
+        })
+        .collect();
+
+    let border = if *dragging_state { "solid" } else { "dashed" };
+    html! {
+        <div
+            style={format!("border: 2px {} gray; padding: 8px; width: fit-content;", border)}
+            ondragover={on_drag_over}
+            ondragleave={on_drag_leave}
+            ondrop={on_drop}
+        >
+            <div style="display: grid; grid-template-columns: auto auto auto; width: fit-content; column-gap: 8px; row-gap: 4px;">
+                <label>{"Format"}</label>
+                <select onchange={on_format_change}>
+                    <option value="" selected={options.format.is_none()}>{"Detect"}</option>
+                    {ImportFormat::all_options().into_iter().map(|option| html! {
+                        <option value={option.as_str()} selected={Some(option) == options.format}>
+                            {option.label()}
+                        </option>
+                    }).collect::<Html>()}
+                </select>
+                <span />
+                <span />
+                <span>{"Column"}</span>
+                <span>{"Unit"}</span>
+                {overrides_html}
+            </div>
+            <p>{"Drop a CSV, TSV or lab export here, or "}</p>
+            <input type="file" accept=".csv,.tsv,.txt" onchange={on_file_pick} />
+            <ul>
+                { messages_state.iter().map(|message| html! { <li>{message}</li> }).collect::<Html>() }
+            </ul>
+        </div>
+    }
+}
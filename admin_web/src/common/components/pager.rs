@@ -0,0 +1,42 @@
+use yew::{function_component, html, Callback, Html, MouseEvent, Properties};
+
+#[derive(Properties, PartialEq)]
+pub struct PagerProps {
+    pub offset: i64,
+    pub limit: i64,
+    pub total: i64,
+    // Emits the offset of the requested page
+    pub on_page: Callback<i64>,
+}
+
+#[function_component]
+pub fn Pager(
+    PagerProps {
+        offset,
+        limit,
+        total,
+        on_page,
+    }: &PagerProps,
+) -> Html {
+    if *total == 0 {
+        return html! {};
+    }
+    let (offset, limit, total) = (*offset, *limit, *total);
+
+    let on_previous_click = {
+        let on_page = on_page.clone();
+        Callback::from(move |_evt: MouseEvent| on_page.emit((offset - limit).max(0)))
+    };
+    let on_next_click = {
+        let on_page = on_page.clone();
+        Callback::from(move |_evt: MouseEvent| on_page.emit(offset + limit))
+    };
+
+    html! {
+        <p>
+            <button onclick={on_previous_click} disabled={offset == 0}>{"Previous"}</button>
+            {format!(" {}\u{2013}{} of {} ", offset + 1, (offset + limit).min(total), total)}
+            <button onclick={on_next_click} disabled={offset + limit >= total}>{"Next"}</button>
+        </p>
+    }
+}
@@ -1,3 +1,7 @@
 pub mod a1_2010_report;
+pub mod a2_2010_report;
 pub mod fan_series;
 pub mod fan_size;
+pub mod preferences;
+pub mod search;
+pub mod selection;
@@ -2,5 +2,15 @@ pub mod a1_determination_table;
 pub use a1_determination_table::A12010DeterminationTable;
 pub mod a1_fan_plot;
 pub use a1_fan_plot::A1FanPlot;
+pub mod a1_fan_size_reports;
+pub use a1_fan_size_reports::A1FanSizeReports;
 pub mod a1_form;
 pub use a1_form::A1Form;
+pub mod a1_report_summary_table;
+pub use a1_report_summary_table::A1ReportSummaryTable;
+pub mod a1_revision_history;
+pub use a1_revision_history::A1RevisionHistory;
+pub mod curve_family_input;
+pub use curve_family_input::CurveFamilyInput;
+pub mod system_curve_input;
+pub use system_curve_input::SystemCurveInput;
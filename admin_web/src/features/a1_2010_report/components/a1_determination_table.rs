@@ -1,5 +1,9 @@
-use std::{fmt::Debug, iter, rc::Rc};
+use std::rc::Rc;
 
+use loquat_common::{
+    calculations::units::{BrakeHorsepower, InletAirflow, StaticPressure},
+    display::{Measure, UnitSystem},
+};
 use yew::{function_component, html, use_callback, Callback, Html, Properties};
 
 use crate::common::components::DeterminationTable;
@@ -8,28 +12,25 @@ use crate::common::components::DeterminationTable;
 pub struct A12010DeterminationTableProps {
     pub fields: Vec<[String; 3]>,
     pub child_errs: Rc<Vec<[Rc<Vec<String>>; 3]>>,
-    pub onchange: Callback<[[String; 3]; 10]>,
-}
-
-fn to_filled_table<T: Clone + Debug>(given_rows: &[[T; 3]], empty: impl Fn() -> T) -> [[T; 3]; 10] {
-    given_rows
-        .iter()
-        .chain(iter::repeat(&[empty(), empty(), empty()]))
-        .take(10)
-        .cloned()
-        .collect::<Vec<[T; 3]>>()
-        .try_into()
-        .unwrap()
+    pub onchange: Callback<Vec<[String; 3]>>,
+    pub units: UnitSystem,
 }
 
+// A curve can have any number of points, so there is always a blank row to add the next one in,
+// and a row that is cleared out is taken away
 #[function_component]
 pub fn A12010DeterminationTable(props: &A12010DeterminationTableProps) -> Html {
-    let rows = to_filled_table(&props.fields, || "".to_string());
-    let child_errs = to_filled_table(&props.child_errs, || Rc::new(Vec::new()));
+    let rows: Vec<[String; 3]> = props
+        .fields
+        .iter()
+        .cloned()
+        .chain([Default::default()])
+        .collect();
     let on_determination_value_change: Callback<(usize, usize, String)> = use_callback(
         move |(row_index, col_index, value): (usize, usize, String), (rows, onchange)| {
             let mut rows = rows.clone();
             rows[row_index][col_index] = value;
+            rows.retain(|row| row.iter().any(|cell| !cell.trim().is_empty()));
 
             onchange.emit(rows);
         },
@@ -37,17 +38,20 @@ pub fn A12010DeterminationTable(props: &A12010DeterminationTableProps) -> Html {
     );
 
     let headers_lables: [String; 3] = [
-        "Static Pressure (in. wg)".to_string(),
-        "Flow Rate (cfm)".to_string(),
-        "Brake Horsepower (hp)".to_string(),
+        format!("Static Pressure ({})", StaticPressure::symbol(props.units)),
+        format!("Flow Rate ({})", InletAirflow::symbol(props.units)),
+        format!(
+            "Brake Horsepower ({})",
+            BrakeHorsepower::symbol(props.units)
+        ),
     ];
 
     html! {
-        <DeterminationTable<3, 10>
+        <DeterminationTable<3>
             headers={headers_lables}
             onchange={on_determination_value_change}
             {rows}
-            {child_errs}
+            child_errs={props.child_errs.to_vec()}
         />
     }
 }
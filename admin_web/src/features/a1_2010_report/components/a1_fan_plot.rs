@@ -1,22 +1,162 @@
-use loquat_common::models::A1Standard2010Determination;
+use std::rc::Rc;
+
+use loquat_common::{
+    api::{
+        a1_2010_report::{CurveFamilyQuery, CurveFamilyResponse},
+        ApiError,
+    },
+    calculations::{
+        core::{FanCurve, SystemCurve},
+        standards::A1OperatingPoint,
+        units::{
+            BrakeHorsepower, FanSpeed, InletAirflow, OutletArea, OutletVelocity, StaticPressure,
+            TotalPressure, VelocityPressure,
+        },
+    },
+    chart::smoothed_line,
+    models::A1Standard2010Determination,
+};
 use plotly::{
-    common::{AxisSide, Marker, MarkerSymbol, Mode},
+    common::{AxisSide, DashType, Line, Marker, MarkerSymbol, Mode},
     layout::{Axis, Legend, RangeMode},
     Layout, Plot, Scatter,
 };
 use yew::{
-    function_component, html, platform::spawn_local, use_effect_with_deps, Html, Properties,
+    function_component, html, platform::spawn_local, use_effect_with_deps, use_memo, Html,
+    Properties,
 };
 
 #[derive(Properties, PartialEq)]
 pub struct A1FanPlotProps {
     pub points: Vec<A1Standard2010Determination>,
+    // Square inches, total pressure is only plotted once the fan size is known
+    #[prop_or_default]
+    pub outlet_area: Option<f64>,
+    #[prop_or_default]
+    pub rpm: Option<f64>,
+    #[prop_or_default]
+    pub system_curve: Option<SystemCurve>,
+    // Drawn around the test speed, so only once the rpm is known
+    #[prop_or_default]
+    pub curve_family: Option<CurveFamilyQuery>,
+}
+
+fn smoothed_trace(
+    points: &[A1Standard2010Determination],
+    value_of: impl Fn(&A1Standard2010Determination) -> f64,
+    name: &str,
+    symbol: MarkerSymbol,
+    y_axis: &str,
+) -> Vec<Box<Scatter<f64, f64>>> {
+    // The same smoothing as the exported chart, so the two look alike
+    let (line_cfms, line_values) = smoothed_line(
+        &points
+            .iter()
+            .map(|p| (p.cfm, value_of(p)))
+            .collect::<Vec<_>>(),
+    )
+    .into_iter()
+    .unzip();
+    vec![
+        Scatter::new(line_cfms, line_values)
+            .name(name)
+            .legend_group(name)
+            .mode(Mode::Lines)
+            .y_axis(y_axis),
+        Scatter::new(
+            points.iter().map(|p| p.cfm).collect(),
+            points.iter().map(value_of).collect(),
+        )
+        .name(name)
+        .legend_group(name)
+        .show_legend(false)
+        .mode(Mode::Markers)
+        .marker(Marker::new().symbol(symbol))
+        .y_axis(y_axis),
+    ]
+}
+
+// Speed curves, power lines and efficiency contours are all pressure against
+// airflow, drawn thin so the tested curve stands out
+fn family_trace(
+    points: &[A1Standard2010Determination],
+    name: String,
+    dash: DashType,
+) -> Box<Scatter<f64, f64>> {
+    Scatter::new(
+        points.iter().map(|p| p.cfm).collect(),
+        points.iter().map(|p| p.static_pressure).collect(),
+    )
+    .name(&name)
+    .legend_group("Speed Family")
+    .mode(Mode::Lines)
+    .line(Line::new().dash(dash).width(1.0))
+    .y_axis("y")
 }
 
 #[function_component]
-pub fn A1FanPlot(A1FanPlotProps { points }: &A1FanPlotProps) -> Html {
+pub fn A1FanPlot(
+    A1FanPlotProps {
+        points,
+        outlet_area,
+        rpm,
+        system_curve,
+        curve_family,
+    }: &A1FanPlotProps,
+) -> Html {
+    let maybe_intersection = use_memo(
+        |(points, rpm, system_curve)| {
+            let (rpm, system_curve) = (rpm.as_ref()?, system_curve.as_ref()?);
+            let fan_curve: FanCurve<A1OperatingPoint> = points
+                .iter()
+                .map(|p| {
+                    A1OperatingPoint::new(
+                        FanSpeed::from_rpm(*rpm),
+                        InletAirflow::from_cfm(p.cfm),
+                        StaticPressure::from_inches(p.static_pressure),
+                        BrakeHorsepower::from_hp(p.brake_horsepower),
+                    )
+                })
+                .collect();
+            Some(fan_curve.system_curve_intersection(system_curve).map(|op| {
+                let inlet_airflow: &InletAirflow = op.as_ref();
+                let static_pressure: &StaticPressure = op.as_ref();
+                let brake_horsepower: &BrakeHorsepower = op.as_ref();
+                A1Standard2010Determination {
+                    cfm: inlet_airflow.cfm(),
+                    static_pressure: static_pressure.inches(),
+                    brake_horsepower: brake_horsepower.hp(),
+                }
+            }))
+        },
+        (points.clone(), *rpm, *system_curve),
+    );
+
+    let maybe_curve_family: Rc<Option<Result<CurveFamilyResponse, ApiError>>> = use_memo(
+        |(points, rpm, curve_family)| {
+            let (rpm, curve_family) = (rpm.as_ref()?, curve_family.as_ref()?);
+            let fan_curve: FanCurve<A1OperatingPoint> = points
+                .iter()
+                .map(|p| {
+                    A1OperatingPoint::new(
+                        FanSpeed::from_rpm(*rpm),
+                        InletAirflow::from_cfm(p.cfm),
+                        StaticPressure::from_inches(p.static_pressure),
+                        BrakeHorsepower::from_hp(p.brake_horsepower),
+                    )
+                })
+                .collect();
+            Some(
+                curve_family
+                    .fan_speeds(*rpm)
+                    .and_then(|fan_speeds| Ok(fan_curve.curve_family(&fan_speeds)?.into())),
+            )
+        },
+        (points.clone(), *rpm, curve_family.clone()),
+    );
+
     use_effect_with_deps(
-        move |points| {
+        move |(points, outlet_area, system_curve, maybe_intersection, maybe_curve_family)| {
             let id = "plot-div";
             let mut plot = Plot::new();
 
@@ -37,23 +177,106 @@ pub fn A1FanPlot(A1FanPlotProps { points }: &A1FanPlotProps) -> Html {
             } else {
                 None
             };
-            let pressure_curve = Scatter::new(
-                points.iter().map(|p| p.cfm).collect(),
-                points.iter().map(|p| p.static_pressure).collect(),
-            )
-            .name("Static Pressure (In. Wg.)")
-            .mode(Mode::LinesMarkers)
-            .marker(Marker::new().symbol(MarkerSymbol::SquareOpen))
-            .y_axis("y");
-
-            let bhp_curve = Scatter::new(
-                points.iter().map(|p| p.cfm).collect(),
-                points.iter().map(|p| p.brake_horsepower).collect(),
-            )
-            .name("Power Input (HP)")
-            .mode(Mode::LinesMarkers)
-            .marker(Marker::new().symbol(MarkerSymbol::CircleOpen))
-            .y_axis("y2");
+            let pressure_curve = smoothed_trace(
+                points,
+                |p| p.static_pressure,
+                "Static Pressure (In. Wg.)",
+                MarkerSymbol::SquareOpen,
+                "y",
+            );
+
+            let total_pressure_curve = outlet_area.map(|outlet_area| {
+                let outlet_area = OutletArea::from_square_inches(outlet_area);
+                smoothed_trace(
+                    points,
+                    |p| {
+                        TotalPressure::from_pressures(
+                            &StaticPressure::from_inches(p.static_pressure),
+                            &VelocityPressure::from_outlet_velocity(&OutletVelocity::from_airflow(
+                                &InletAirflow::from_cfm(p.cfm),
+                                &outlet_area,
+                            )),
+                        )
+                        .inches()
+                    },
+                    "Total Pressure (In. Wg.)",
+                    MarkerSymbol::TriangleUpOpen,
+                    "y",
+                )
+            });
+
+            let max_cfm = points
+                .iter()
+                .map(|p| p.cfm)
+                .reduce(f64::max)
+                .unwrap_or_default();
+            let system_curve_traces = system_curve.map(|system_curve| {
+                let airflows: Vec<f64> = (0..=20).map(|i| max_cfm * i as f64 / 20.0).collect();
+                let system_trace = Scatter::new(
+                    airflows.clone(),
+                    airflows
+                        .iter()
+                        .map(|cfm| {
+                            system_curve
+                                .static_pressure_at(&InletAirflow::from_cfm(*cfm))
+                                .inches()
+                        })
+                        .collect(),
+                )
+                .name("System Curve (In. Wg.)")
+                .mode(Mode::Lines)
+                .y_axis("y");
+                let intersection_trace = match maybe_intersection.as_ref() {
+                    Some(Ok(intersection)) => Some(
+                        Scatter::new(vec![intersection.cfm], vec![intersection.static_pressure])
+                            .name("Operating Point")
+                            .mode(Mode::Markers)
+                            .marker(Marker::new().symbol(MarkerSymbol::Diamond).size(12))
+                            .y_axis("y"),
+                    ),
+                    _ => None,
+                };
+                (system_trace, intersection_trace)
+            });
+
+            let curve_family_traces: Vec<Box<Scatter<f64, f64>>> = match maybe_curve_family.as_ref()
+            {
+                Some(Ok(curve_family)) => curve_family
+                    .curves
+                    .iter()
+                    .map(|curve| {
+                        family_trace(
+                            &curve.points,
+                            format!("{:.0} rpm", curve.rpm),
+                            DashType::DashDot,
+                        )
+                    })
+                    .chain(curve_family.brake_horsepower_lines.iter().map(|line| {
+                        family_trace(&line.points, format!("{} hp", line.value), DashType::Dash)
+                    }))
+                    .chain(
+                        curve_family
+                            .static_efficiency_contours
+                            .iter()
+                            .map(|contour| {
+                                family_trace(
+                                    &contour.points,
+                                    format!("{:.0}% static efficiency", contour.value * 100.0),
+                                    DashType::Dot,
+                                )
+                            }),
+                    )
+                    .collect(),
+                _ => vec![],
+            };
+
+            let bhp_curve = smoothed_trace(
+                points,
+                |p| p.brake_horsepower,
+                "Power Input (HP)",
+                MarkerSymbol::CircleOpen,
+                "y2",
+            );
 
             let layout = Layout::new()
                 .title("A1 Fan Curve".into())
@@ -62,7 +285,7 @@ pub fn A1FanPlot(A1FanPlotProps { points }: &A1FanPlotProps) -> Html {
                 .y_axis(
                     Axis::new()
                         .range_mode(RangeMode::ToZero)
-                        .title("Static Pressure (In. Wg.)".into())
+                        .title("Pressure (In. Wg.)".into())
                         .side(AxisSide::Left),
                 )
                 .y_axis({
@@ -87,17 +310,59 @@ pub fn A1FanPlot(A1FanPlotProps { points }: &A1FanPlotProps) -> Html {
                     }
                 });
             plot.set_layout(layout);
-            plot.add_trace(pressure_curve);
-            plot.add_trace(bhp_curve);
+            for trace in pressure_curve
+                .into_iter()
+                .chain(total_pressure_curve.into_iter().flatten())
+                .chain(bhp_curve)
+            {
+                plot.add_trace(trace);
+            }
+            for trace in curve_family_traces {
+                plot.add_trace(trace);
+            }
+            if let Some((system_trace, intersection_trace)) = system_curve_traces {
+                plot.add_trace(system_trace);
+                if let Some(intersection_trace) = intersection_trace {
+                    plot.add_trace(intersection_trace);
+                }
+            }
             spawn_local(async move {
                 plotly::bindings::new_plot(id, &plot).await;
             });
             || ()
         },
-        points.clone(),
+        (
+            points.clone(),
+            *outlet_area,
+            *system_curve,
+            Rc::clone(&maybe_intersection),
+            Rc::clone(&maybe_curve_family),
+        ),
     );
 
+    let intersection_html = match maybe_intersection.as_ref() {
+        Some(Ok(intersection)) => html! {
+            <p>
+                {format!(
+                    "Operates at {:.0} cfm and {:.3} in. wg, drawing {:.3} hp",
+                    intersection.cfm, intersection.static_pressure, intersection.brake_horsepower
+                )}
+            </p>
+        },
+        Some(Err(err)) => html! { <p>{err.to_string()}</p> },
+        None => html! {},
+    };
+
+    let curve_family_html = match maybe_curve_family.as_ref() {
+        Some(Err(err)) => html! { <p>{err.to_string()}</p> },
+        _ => html! {},
+    };
+
     html! {
-        <div id="plot-div"></div>
+        <>
+            <div id="plot-div"></div>
+            {intersection_html}
+            {curve_family_html}
+        </>
     }
 }
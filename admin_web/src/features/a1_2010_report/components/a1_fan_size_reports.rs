@@ -0,0 +1,86 @@
+use std::rc::Rc;
+
+use loquat_common::api::{a1_2010_report::ReportSummary, ListQuery, Page};
+use yew::prelude::*;
+use yew_router::prelude::Link;
+use yewdux::prelude::{use_selector_with_deps, use_store};
+
+use crate::api::store::{
+    ApiRequestAction, GetParameters, Gettable, RequestStatuses, Store as ApiStore,
+};
+use crate::features::a1_2010_report::components::A1ReportSummaryTable;
+use crate::route::Route;
+use crate::store::{select_a1_report_page, use_app_store_selector_with_deps};
+
+#[derive(Properties, PartialEq)]
+pub struct A1FanSizeReportsProps {
+    pub fan_size_id: AttrValue,
+}
+
+// Every A1 report tested on one fan size
+#[function_component]
+pub fn A1FanSizeReports(A1FanSizeReportsProps { fan_size_id }: &A1FanSizeReportsProps) -> Html {
+    let (_state, dispatch) = use_store::<ApiStore>();
+    let query = ListQuery {
+        fan_size_id: Some(fan_size_id.to_string()),
+        limit: Some(ListQuery::MAX_LIMIT),
+        ..Default::default()
+    };
+    let gettable = Gettable::A1ReportsIndex {
+        query: query.clone(),
+    };
+    let maybe_page: Rc<Option<Page<ReportSummary>>> =
+        use_app_store_selector_with_deps(select_a1_report_page, query);
+    let request_status = use_selector_with_deps(
+        |store: &ApiStore, dep_gettable| {
+            store
+                .get_status
+                .get(dep_gettable)
+                .cloned()
+                .unwrap_or_default()
+        },
+        gettable.clone(),
+    );
+
+    use_effect_with_deps(
+        move |dep_gettable| {
+            dispatch.apply(ApiRequestAction::Get(
+                GetParameters { ignore_cache: true },
+                dep_gettable.clone(),
+            ));
+            || {}
+        },
+        gettable,
+    );
+
+    let reports = match (request_status.as_ref(), maybe_page.as_ref()) {
+        (RequestStatuses::Error(_error_at, err), _) => {
+            html! { <p>{"Error: "}{err.to_string()}</p> }
+        }
+        (_, Some(page)) if page.items.is_empty() => {
+            html! { <p>{"No A1 reports have been entered for this fan size"}</p> }
+        }
+        (_, Some(page)) => html! {
+            <>
+                <A1ReportSummaryTable summaries={Rc::new(page.items.clone())} />
+                if page.has_next() {
+                    <p>{format!("Showing the first {} of {} reports", page.items.len(), page.total)}</p>
+                }
+            </>
+        },
+        (_, None) => html! { <p>{"Loading..."}</p> },
+    };
+
+    html! {
+        <div>
+            <h2>
+                {"A1 Reports"}
+                <Link<Route> to={Route::NewA1Report}>
+                    {'\u{2002}'} // En-space
+                    {"New"}
+                </Link<Route>>
+            </h2>
+            {reports}
+        </div>
+    }
+}
@@ -1,7 +1,11 @@
 use std::ops::Deref;
 use std::rc::Rc;
 
-use loquat_common::api::a1_2010_report::UpdateBody;
+use loquat_common::api::{a1_2010_report::UpdateBody, ApiError};
+use loquat_common::calculations::units::{
+    AirDensity, BrakeHorsepower, InletAirflow, StaticPressure,
+};
+use loquat_common::display::{Measure, UnitSystem};
 
 use yew::prelude::*;
 
@@ -10,8 +14,11 @@ use loquat_common::models::{
 };
 
 use crate::common::components::determination_table::TaggedInput;
-use crate::common::components::{DeterminationsPasteTextArea, FanSeriesAndSizePicker};
+use crate::common::components::{
+    DeterminationsFileDrop, DeterminationsPasteTextArea, FanSeriesAndSizePicker,
+};
 use crate::features::a1_2010_report::components::A12010DeterminationTable;
+use crate::store::{select_unit_system, use_app_store_selector};
 
 #[derive(Debug, Properties, PartialEq)]
 pub struct A1FormProps {
@@ -22,13 +29,60 @@ pub struct A1FormProps {
     pub on_submit_click: Callback<MouseEvent>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 struct UpdateBodyErrors {
+    id_errs: Vec<String>,
     size_errs: Vec<String>,
     rpm_errs: Vec<String>,
+    air_density_errs: Vec<String>,
+    // Problems with the curve as a whole, rather than any one point
+    determinations_errs: Vec<String>,
     determination_errs: Vec<[Rc<Vec<String>>; 3]>,
 }
 
+impl UpdateBodyErrors {
+    // Places the shared validation's field errors on the inputs they refer to
+    fn from_validation(err: ApiError, row_count: usize) -> Self {
+        let mut errors = UpdateBodyErrors::default();
+        let mut determination_errs: Vec<[Vec<String>; 3]> = vec![Default::default(); row_count];
+        for field_error in err.field_errors {
+            let message = field_error.message;
+            match field_error.field.as_str() {
+                "id" => errors.id_errs.push(message),
+                "fan_size_id" => errors.size_errs.push(message),
+                "fan_rpm" => errors.rpm_errs.push(message),
+                "air_density" => errors.air_density_errs.push(message),
+                field => {
+                    let point = field
+                        .strip_prefix("determinations[")
+                        .and_then(|rest| rest.split_once("]."))
+                        .and_then(|(row, name)| {
+                            // The table's columns are ordered as on the test sheet
+                            let column = match name {
+                                "static_pressure" => 0,
+                                "cfm" => 1,
+                                "brake_horsepower" => 2,
+                                _ => return None,
+                            };
+                            Some((row.parse::<usize>().ok()?, column))
+                        });
+                    match point {
+                        Some((row, column)) if row < row_count => {
+                            determination_errs[row][column].push(message)
+                        }
+                        _ => errors.determinations_errs.push(message),
+                    }
+                }
+            }
+        }
+        errors.determination_errs = determination_errs
+            .into_iter()
+            .map(|row| row.map(Rc::new))
+            .collect();
+        errors
+    }
+}
+
 #[function_component]
 pub fn A1Form(
     A1FormProps {
@@ -45,6 +99,7 @@ pub fn A1Form(
     let picked_fan_series_state: UseStateHandle<Option<FanSeries<()>>> = use_state(|| None);
     let picked_fan_size_state: UseStateHandle<Option<FanSize<()>>> = use_state(|| None);
     let entered_rpm_state: UseStateHandle<String> = use_state(|| "".to_string());
+    let entered_air_density_state: UseStateHandle<String> = use_state(|| "".to_string());
     let determinations_state: UseStateHandle<Vec<[String; 3]>> = use_state(Vec::new);
 
     let parsed_fan_size_id: Rc<Result<String, Vec<String>>> = use_memo(
@@ -75,60 +130,89 @@ pub fn A1Form(
         entered_rpm_state.deref().clone(),
     );
 
-    let parsed_rpm_errs_rc = use_memo(
-        |parsed_rpm| match parsed_rpm.as_ref() {
-            Ok(_) => Vec::new(),
-            Err(errs) => errs.clone(),
+    // Left blank when the test was run at standard air
+    let parsed_air_density: Rc<Result<Option<f64>, Vec<String>>> = use_memo(
+        |entered_air_density: &String| {
+            if entered_air_density.is_empty() {
+                return Ok(None);
+            }
+            match entered_air_density.parse::<f64>() {
+                Ok(value) => {
+                    if value <= 0.0 {
+                        Err(vec!["The air density must be positive".to_string()])
+                    } else {
+                        Ok(Some(value))
+                    }
+                }
+                Err(_) => Err(vec!["You must enter a valid number".to_string()]),
+            }
         },
-        Rc::clone(&parsed_rpm),
+        entered_air_density_state.deref().clone(),
     );
 
     let parsed_determinations =
         use_memo(parse_determenations, determinations_state.deref().clone());
 
-    let fan_size_errs_rc = use_memo(
-        |parsed_fan_size_id| match (*parsed_fan_size_id).as_ref() {
-            Ok(_) => Vec::new(),
-            Err(errs) => errs.clone(),
-        },
-        Rc::clone(&parsed_fan_size_id),
-    );
-
+    // Everything on the form is entered in the user's units, and sent in them too
+    let units: UnitSystem = *use_app_store_selector(select_unit_system);
     let parsed_update_body: Rc<Result<UpdateBody, UpdateBodyErrors>> = use_memo(
-        |(parsed_fan_size_id, parsed_rpm, parsed_determinations)| {
+        |(
+            report_id,
+            parsed_fan_size_id,
+            parsed_rpm,
+            parsed_air_density,
+            parsed_determinations,
+            units,
+        )| {
             let parses = (
                 parsed_fan_size_id.as_ref(),
                 (parsed_rpm.as_ref()),
+                (parsed_air_density.as_ref()),
                 (parsed_determinations.as_ref()),
             );
-            if let (Ok(fan_size_id), Ok(fan_rpm), Ok(determinations)) = parses {
-                let id: String = report_id_state.to_string();
-                Ok(UpdateBody {
-                    id,
+            if let (Ok(fan_size_id), Ok(fan_rpm), Ok(air_density), Ok(determinations)) = parses {
+                let body = UpdateBody {
+                    id: report_id.clone(),
                     determinations: determinations.clone(),
                     fan_rpm: *fan_rpm,
                     fan_size_id: fan_size_id.clone(),
-                })
+                    air_density: *air_density,
+                    units: *units,
+                };
+                match body.validate_in(*units) {
+                    Ok(()) => Ok(body),
+                    Err(err) => Err(UpdateBodyErrors::from_validation(err, determinations.len())),
+                }
             } else {
                 Err(UpdateBodyErrors {
+                    id_errs: vec![],
                     size_errs: parsed_fan_size_id
                         .as_ref()
                         .clone()
                         .err()
                         .unwrap_or_default(),
                     rpm_errs: parsed_rpm.as_ref().clone().err().unwrap_or_default(),
+                    air_density_errs: parsed_air_density
+                        .as_ref()
+                        .clone()
+                        .err()
+                        .unwrap_or_default(),
                     determination_errs: parsed_determinations
                         .as_ref()
                         .clone()
                         .err()
                         .unwrap_or_default(),
+                    determinations_errs: vec![],
                 })
             }
         },
         (
+            report_id_state.deref().clone(),
             Rc::clone(&parsed_fan_size_id),
             Rc::clone(&parsed_rpm),
+            Rc::clone(&parsed_air_density),
             Rc::clone(&parsed_determinations),
+            units,
         ),
     );
 
@@ -150,39 +234,46 @@ pub fn A1Form(
     use_effect_with_deps(
         {
             let rpm_string_setter = entered_rpm_state.setter();
+            let air_density_string_setter = entered_air_density_state.setter();
             let picked_fan_series_setter = picked_fan_series_state.setter();
             let picked_fan_size_setter = picked_fan_size_state.setter();
             let determinations_setter = determinations_state.setter();
-            move |report_option: &Option<A1Standard2010Report<FanSize<FanSeries<()>>>>| {
+            move |(report_option, units): &(
+                Option<A1Standard2010Report<FanSize<FanSeries<()>>>>,
+                UnitSystem,
+            )| {
                 if let Some(report) = report_option {
                     let (new_fan_size, new_fan_series): (FanSize<()>, FanSeries<()>) =
                         report.fan_size.clone().into();
                     picked_fan_series_setter.set(Some(new_fan_series));
                     picked_fan_size_setter.set(Some(new_fan_size));
                     rpm_string_setter.set(report.parameters.rpm.to_string());
+                    air_density_string_setter.set(report.parameters.air_density.map_or(
+                        "".to_string(),
+                        |density| {
+                            AirDensity::from_lb_per_cubic_foot(density)
+                                .measurement(*units)
+                                .value
+                                .to_string()
+                        },
+                    ));
                     determinations_setter.set(
                         report
                             .determinations
-                            .clone()
-                            .into_iter()
-                            .map(|det| {
-                                [
-                                    det.static_pressure.to_string(),
-                                    det.cfm.to_string(),
-                                    det.brake_horsepower.to_string(),
-                                ]
-                            })
+                            .iter()
+                            .map(|det| determination_fields(det, *units))
                             .collect(),
                     );
                 } else {
                     picked_fan_series_setter.set(None);
                     picked_fan_size_setter.set(None);
                     rpm_string_setter.set("".to_string());
+                    air_density_string_setter.set("".to_string());
                     determinations_setter.set(vec![])
                 }
             }
         },
-        maybe_report.as_ref().clone(),
+        (maybe_report.as_ref().clone(), units),
     );
 
     let on_report_id_change = {
@@ -201,19 +292,60 @@ pub fn A1Form(
         )
     };
 
+    let on_air_density_input_change = {
+        let entered_air_density_setter = entered_air_density_state.setter();
+        use_callback(
+            move |(_index, air_density), _deps| entered_air_density_setter.set(air_density),
+            (),
+        )
+    };
+
     let on_dets_input_change = {
         let determinations_table_setter = determinations_state.setter();
         use_callback(
-            move |data: [[String; 3]; 10], _dets| determinations_table_setter.set(data.to_vec()),
+            move |data: Vec<[String; 3]>, _dets| determinations_table_setter.set(data),
             (),
         )
     };
 
+    // The lab export is in in. wg, cfm and hp, rows that don't parse are left for the table to flag
     let on_determinations_extracted = {
         let determinations_setter = determinations_state.setter();
         use_callback(
-            move |dets: Vec<[String; 3]>, _deps| determinations_setter.set(dets),
-            (),
+            move |dets: Vec<[String; 3]>, units| {
+                determinations_setter.set(
+                    dets.into_iter()
+                        .map(|det| match det.clone().map(|x| x.parse::<f64>()) {
+                            [Ok(static_pressure), Ok(cfm), Ok(brake_horsepower)] => {
+                                determination_fields(
+                                    &A1Standard2010Determination {
+                                        static_pressure,
+                                        cfm,
+                                        brake_horsepower,
+                                    },
+                                    *units,
+                                )
+                            }
+                            _ => det,
+                        })
+                        .collect(),
+                )
+            },
+            units,
+        )
+    };
+
+    let on_determinations_imported = {
+        let determinations_setter = determinations_state.setter();
+        use_callback(
+            move |dets: Vec<A1Standard2010Determination>, units| {
+                determinations_setter.set(
+                    dets.iter()
+                        .map(|det| determination_fields(det, *units))
+                        .collect(),
+                )
+            },
+            units,
         )
     };
 
@@ -222,10 +354,18 @@ pub fn A1Form(
         fan_size
     });
 
-    let det_errs = use_memo(
-        |parsed_determinations| (**parsed_determinations).clone().err().unwrap_or_default(),
-        Rc::clone(&parsed_determinations),
-    );
+    let UpdateBodyErrors {
+        id_errs,
+        size_errs,
+        rpm_errs,
+        air_density_errs,
+        determinations_errs,
+        determination_errs,
+    } = parsed_update_body
+        .as_ref()
+        .clone()
+        .err()
+        .unwrap_or_default();
 
     html! {
 
@@ -234,33 +374,45 @@ pub fn A1Form(
                     <div style="display: grid; grid-template-columns: auto auto; width: fit-content; column-gap: 8px; row-gap: 4px;">
                         <label>{"Report ID"}</label>
                         <TaggedInput<()>
+                            errs={Rc::new(id_errs)}
                             value={(*report_id_state).clone()}
                             tag={()}
                             onchange={on_report_id_change}
                             disabled={report_id.is_some()}
                         />
                         <FanSeriesAndSizePicker
-                            size_errs={fan_size_errs_rc}
+                            size_errs={Rc::new(size_errs)}
                             {saved_size}
                             {picked_fan_series_state}
                             {picked_fan_size_state}
                         />
                         <label>{"Test RPM"}</label>
                         <TaggedInput<()>
-                            errs={parsed_rpm_errs_rc}
+                            errs={Rc::new(rpm_errs)}
                             value={(*entered_rpm_state).clone()}
                             tag={()}
                             onchange={on_rpm_input_change}
                         />
+                        <label>{format!("Test Air Density ({})", AirDensity::symbol(units))}</label>
+                        <TaggedInput<()>
+                            errs={Rc::new(air_density_errs)}
+                            value={(*entered_air_density_state).clone()}
+                            tag={()}
+                            onchange={on_air_density_input_change}
+                        />
                     </div>
                     <label><h2>{"Determination Points"}</h2></label>
+                    <ul>
+                        { determinations_errs.iter().map(|err| html! { <li>{err}</li> }).collect::<Html>() }
+                    </ul>
                     <A12010DeterminationTable
                         fields={(*determinations_state).clone()}
-                        child_errs={det_errs}
+                        child_errs={Rc::new(determination_errs)}
                         onchange={on_dets_input_change}
+                        {units}
                     />
                     <label><h3>{"Quick Paste Determination Points"}</h3></label>
-                    <DeterminationsPasteTextArea<3,10>
+                    <DeterminationsPasteTextArea<3>
                         on_extracted={on_determinations_extracted}
                         cols_to_extract={[3,4,5]}
                         expected_row_length={9}
@@ -269,6 +421,10 @@ pub fn A1Form(
                             "(in. wg) (in. wg) (in. wg) (cfm) (hp) - (%) (%)"
                         ]}
                     />
+                    <label><h3>{"Import Determination Points"}</h3></label>
+                    <DeterminationsFileDrop
+                        on_imported={on_determinations_imported}
+                    />
                     <button onclick={on_submit_click}>
                         {"Save"}
                     </button>
@@ -276,6 +432,27 @@ pub fn A1Form(
 
     }
 }
+// Saved in imperial, shown in the table's order of static pressure, airflow then power
+fn determination_fields(
+    determination: &A1Standard2010Determination,
+    units: UnitSystem,
+) -> [String; 3] {
+    [
+        StaticPressure::from_inches(determination.static_pressure)
+            .measurement(units)
+            .value
+            .to_string(),
+        InletAirflow::from_cfm(determination.cfm)
+            .measurement(units)
+            .value
+            .to_string(),
+        BrakeHorsepower::from_hp(determination.brake_horsepower)
+            .measurement(units)
+            .value
+            .to_string(),
+    ]
+}
+
 type DeterminationErrors = [Rc<Vec<String>>; 3];
 fn parse_determenations(
     determinations_state: &Vec<[String; 3]>,
@@ -0,0 +1,67 @@
+use std::rc::Rc;
+
+use loquat_common::{
+    api::a1_2010_report::ReportSummary,
+    calculations::units::{BrakeHorsepower, InletAirflow, StaticPressure},
+    display::{Measure, UnitSystem},
+};
+use yew::prelude::*;
+use yew_router::prelude::Link;
+
+use crate::route::Route;
+use crate::store::{select_unit_system, use_app_store_selector};
+
+#[derive(Properties, PartialEq)]
+pub struct A1ReportSummaryTableProps {
+    pub summaries: Rc<Vec<ReportSummary>>,
+}
+
+#[function_component]
+pub fn A1ReportSummaryTable(
+    A1ReportSummaryTableProps { summaries }: &A1ReportSummaryTableProps,
+) -> Html {
+    let units: UnitSystem = *use_app_store_selector(select_unit_system);
+    html! {
+        <table>
+            <tr>
+                <th>{"Report"}</th>
+                <th>{"Fan Size"}</th>
+                <th>{"Fan Series"}</th>
+                <th>{"Speed (RPM)"}</th>
+                <th>{format!("Max. Airflow ({})", InletAirflow::symbol(units))}</th>
+                <th>{format!("Shutoff Static Pressure ({})", StaticPressure::symbol(units))}</th>
+                <th>{format!("Peak Brake Horsepower ({})", BrakeHorsepower::symbol(units))}</th>
+            </tr>
+            { summaries.iter().map(|summary| html! {
+                <tr>
+                    <td>
+                        <Link<Route> to={Route::EditA1Report { id: summary.id.clone() }}>
+                            {summary.id.clone()}
+                        </Link<Route>>
+                    </td>
+                    <td>
+                        <Link<Route> to={Route::GetFanSize { id: summary.fan_size_id.clone() }}>
+                            {summary.fan_size_id.clone()}
+                        </Link<Route>>
+                    </td>
+                    <td>
+                        <Link<Route> to={Route::GetFanSeries { id: summary.fan_series_id.clone() }}>
+                            {summary.fan_series_id.clone()}
+                        </Link<Route>>
+                    </td>
+                    <td>{format!("{:.0}", summary.rpm)}</td>
+                    <td>{format_stat(summary.max_cfm.map(InletAirflow::from_cfm), units)}</td>
+                    <td>{format_stat(summary.shutoff_static_pressure.map(StaticPressure::from_inches), units)}</td>
+                    <td>{format_stat(summary.peak_brake_horsepower.map(BrakeHorsepower::from_hp), units)}</td>
+                </tr>
+            }).collect::<Html>() }
+        </table>
+    }
+}
+
+// Reports without determinations have nothing to summarize
+fn format_stat<M: Measure>(maybe_stat: Option<M>, units: UnitSystem) -> String {
+    maybe_stat
+        .map(|stat| stat.measurement(units).value_string())
+        .unwrap_or_else(|| "-".to_string())
+}
@@ -0,0 +1,154 @@
+use std::rc::Rc;
+
+use loquat_common::api::a1_2010_report::{Revision, RevisionAction};
+use yew::prelude::*;
+use yewdux::prelude::use_store;
+
+use crate::api::store::Store as ApiStore;
+use crate::api::store::{ApiRequestAction, GetParameters, Gettable};
+use crate::store::{
+    select_a1_report, select_a1_report_revisions, use_app_store_selector_with_deps,
+};
+
+#[derive(Properties, PartialEq)]
+pub struct A1RevisionHistoryProps {
+    pub report_id: AttrValue,
+}
+
+#[function_component]
+pub fn A1RevisionHistory(A1RevisionHistoryProps { report_id }: &A1RevisionHistoryProps) -> Html {
+    let report_id = report_id.to_string();
+    let (_state, api_dispatch) = use_store::<ApiStore>();
+
+    let maybe_report = use_app_store_selector_with_deps(select_a1_report, Some(report_id.clone()));
+    let maybe_revisions: Rc<Option<Vec<Revision>>> =
+        use_app_store_selector_with_deps(select_a1_report_revisions, Some(report_id.clone()));
+    let compared_revision_id_state: UseStateHandle<Option<i64>> = use_state(|| None);
+
+    // Saving or restoring the report adds a revision, so refetch whenever it changes
+    use_effect_with_deps(
+        {
+            let api_dispatch = api_dispatch.clone();
+            move |(report_id, _report): &(String, Rc<Option<_>>)| {
+                api_dispatch.apply(ApiRequestAction::Get(
+                    GetParameters { ignore_cache: true },
+                    Gettable::A1ReportRevisions {
+                        id: report_id.clone(),
+                    },
+                ));
+                || {}
+            }
+        },
+        (report_id.clone(), Rc::clone(&maybe_report)),
+    );
+
+    let revisions = match maybe_revisions.as_ref() {
+        Some(revisions) => revisions,
+        None => return html! { <p>{"Loading revisions..."}</p> },
+    };
+    let latest = match revisions.first() {
+        Some(latest) => latest,
+        None => return html! { <p>{"This report has no saved revisions"}</p> },
+    };
+
+    let rows = revisions
+        .iter()
+        .map(|revision| {
+            let on_compare_click = {
+                let compared_revision_id_setter = compared_revision_id_state.setter();
+                let revision_id = revision.revision_id;
+                Callback::from(move |_evt: MouseEvent| {
+                    compared_revision_id_setter.set(Some(revision_id))
+                })
+            };
+            let on_restore_click = {
+                let api_dispatch = api_dispatch.clone();
+                let report_id = report_id.clone();
+                let revision_id = revision.revision_id;
+                Callback::from(move |_evt: MouseEvent| {
+                    api_dispatch.apply(ApiRequestAction::Get(
+                        GetParameters { ignore_cache: true },
+                        Gettable::RestoreA1ReportRevision {
+                            id: report_id.clone(),
+                            revision_id,
+                        },
+                    ))
+                })
+            };
+            let is_latest = revision.revision_id == latest.revision_id;
+            html! {
+                <tr>
+                    <td>{format!("#{}", revision.revision_id)}</td>
+                    <td>{revision.created_at.clone()}</td>
+                    <td>{revision.user_name.clone().or(revision.user_id.clone()).unwrap_or("Unknown".to_string())}</td>
+                    <td>{describe_action(revision)}</td>
+                    <td>
+                        <button onclick={on_compare_click} disabled={is_latest}>{"Compare"}</button>
+                        <button onclick={on_restore_click} disabled={is_latest}>{"Restore"}</button>
+                    </td>
+                </tr>
+            }
+        })
+        .collect::<Html>();
+
+    let compared_revision = (*compared_revision_id_state).and_then(|revision_id| {
+        revisions
+            .iter()
+            .find(|revision| revision.revision_id == revision_id)
+    });
+    let diff_html = match compared_revision {
+        Some(compared) => {
+            let changes = compared.snapshot.changes_to(&latest.snapshot);
+            html! {
+                <>
+                    <h4>{format!("Changes from #{} to #{}", compared.revision_id, latest.revision_id)}</h4>
+                    if changes.is_empty() {
+                        <p>{"No changes"}</p>
+                    } else {
+                        <table>
+                            <tr>
+                                <th>{"Field"}</th>
+                                <th>{"Before"}</th>
+                                <th>{"After"}</th>
+                            </tr>
+                            { changes.into_iter().map(|change| html! {
+                                <tr>
+                                    <td>{change.field}</td>
+                                    <td>{change.before.unwrap_or_default()}</td>
+                                    <td>{change.after.unwrap_or_default()}</td>
+                                </tr>
+                            }).collect::<Html>() }
+                        </table>
+                    }
+                </>
+            }
+        }
+        None => html! {},
+    };
+
+    html! {
+        <>
+            <h3>{"Revision History"}</h3>
+            <table>
+                <tr>
+                    <th>{"Revision"}</th>
+                    <th>{"Saved At (UTC)"}</th>
+                    <th>{"Saved By"}</th>
+                    <th>{"Change"}</th>
+                    <th></th>
+                </tr>
+                {rows}
+            </table>
+            {diff_html}
+        </>
+    }
+}
+
+fn describe_action(revision: &Revision) -> String {
+    match (revision.action, revision.restored_from_revision_id) {
+        (RevisionAction::Create, _) => "Created".to_string(),
+        (RevisionAction::Update, _) => "Edited".to_string(),
+        (RevisionAction::Restore, Some(revision_id)) => format!("Restored #{}", revision_id),
+        (RevisionAction::Restore, None) => "Restored".to_string(),
+    }
+}
@@ -0,0 +1,109 @@
+use std::ops::Deref;
+use std::rc::Rc;
+
+use loquat_common::api::a1_2010_report::CurveFamilyQuery;
+use yew::prelude::*;
+
+use crate::common::components::determination_table::TaggedInput;
+
+#[derive(Properties, PartialEq)]
+pub struct CurveFamilyInputProps {
+    pub on_change: Callback<Option<CurveFamilyQuery>>,
+}
+
+#[function_component]
+pub fn CurveFamilyInput(CurveFamilyInputProps { on_change }: &CurveFamilyInputProps) -> Html {
+    let entered_min_rpm_state: UseStateHandle<String> = use_state(|| "".to_string());
+    let entered_count_state: UseStateHandle<String> = use_state(|| "".to_string());
+
+    let parsed_min_rpm: Rc<Result<f64, Vec<String>>> = use_memo(
+        |entered_min_rpm: &String| parse_min_rpm(entered_min_rpm),
+        entered_min_rpm_state.deref().clone(),
+    );
+    let parsed_count: Rc<Result<usize, Vec<String>>> = use_memo(
+        |entered_count: &String| parse_count(entered_count),
+        entered_count_state.deref().clone(),
+    );
+
+    use_effect_with_deps(
+        {
+            let on_change = on_change.clone();
+            move |(parsed_min_rpm, parsed_count): &(
+                Rc<Result<f64, Vec<String>>>,
+                Rc<Result<usize, Vec<String>>>,
+            )| {
+                match (parsed_min_rpm.as_ref(), parsed_count.as_ref()) {
+                    (Ok(min_rpm), Ok(count)) => on_change.emit(Some(CurveFamilyQuery {
+                        min_rpm: Some(*min_rpm),
+                        count: Some(*count),
+                        ..CurveFamilyQuery::default()
+                    })),
+                    _ => on_change.emit(None),
+                };
+                || {}
+            }
+        },
+        (Rc::clone(&parsed_min_rpm), Rc::clone(&parsed_count)),
+    );
+
+    let on_min_rpm_change = {
+        let entered_min_rpm_setter = entered_min_rpm_state.setter();
+        use_callback(
+            move |(_index, min_rpm), _deps| entered_min_rpm_setter.set(min_rpm),
+            (),
+        )
+    };
+
+    let on_count_change = {
+        let entered_count_setter = entered_count_state.setter();
+        use_callback(
+            move |(_index, count), _deps| entered_count_setter.set(count),
+            (),
+        )
+    };
+
+    html! {
+        <>
+            <h3>{"Speed Family"}</h3>
+            <div style="display: grid; grid-template-columns: auto auto; width: fit-content; column-gap: 8px; row-gap: 4px;">
+                <label>{"Lowest Speed (RPM)"}</label>
+                <TaggedInput<()>
+                    errs={Rc::new(parsed_min_rpm.as_ref().clone().err().unwrap_or_default())}
+                    value={(*entered_min_rpm_state).clone()}
+                    tag={()}
+                    onchange={on_min_rpm_change}
+                />
+                <label>{"Number of Curves"}</label>
+                <TaggedInput<()>
+                    errs={Rc::new(parsed_count.as_ref().clone().err().unwrap_or_default())}
+                    value={(*entered_count_state).clone()}
+                    tag={()}
+                    onchange={on_count_change}
+                />
+            </div>
+        </>
+    }
+}
+
+// Blank is fine, it just means there's no family to draw
+fn parse_min_rpm(entered: &str) -> Result<f64, Vec<String>> {
+    if entered.is_empty() {
+        return Err(vec![]);
+    }
+    match entered.parse::<f64>() {
+        Ok(value) if value > 0.0 => Ok(value),
+        Ok(_) => Err(vec!["The speed must be positive".to_string()]),
+        Err(_) => Err(vec!["You must enter a valid number".to_string()]),
+    }
+}
+
+fn parse_count(entered: &str) -> Result<usize, Vec<String>> {
+    if entered.is_empty() {
+        return Err(vec![]);
+    }
+    match entered.parse::<usize>() {
+        Ok(value) if (2..=20).contains(&value) => Ok(value),
+        Ok(_) => Err(vec!["Draw between 2 and 20 curves".to_string()]),
+        Err(_) => Err(vec!["You must enter a whole number".to_string()]),
+    }
+}
@@ -0,0 +1,109 @@
+use std::ops::Deref;
+use std::rc::Rc;
+
+use loquat_common::{
+    calculations::{
+        core::SystemCurve,
+        units::{InletAirflow, StaticPressure},
+    },
+    display::{FromMeasurement, Measure, UnitSystem},
+};
+use yew::prelude::*;
+
+use crate::common::components::determination_table::TaggedInput;
+use crate::store::{select_unit_system, use_app_store_selector};
+
+#[derive(Properties, PartialEq)]
+pub struct SystemCurveInputProps {
+    pub on_change: Callback<Option<SystemCurve>>,
+}
+
+#[function_component]
+pub fn SystemCurveInput(SystemCurveInputProps { on_change }: &SystemCurveInputProps) -> Html {
+    let entered_cfm_state: UseStateHandle<String> = use_state(|| "".to_string());
+    let entered_static_pressure_state: UseStateHandle<String> = use_state(|| "".to_string());
+    let units: UnitSystem = *use_app_store_selector(select_unit_system);
+
+    let parsed_cfm: Rc<Result<f64, Vec<String>>> = use_memo(
+        |entered_cfm: &String| parse_design_value(entered_cfm),
+        entered_cfm_state.deref().clone(),
+    );
+    let parsed_static_pressure: Rc<Result<f64, Vec<String>>> = use_memo(
+        |entered_static_pressure: &String| parse_design_value(entered_static_pressure),
+        entered_static_pressure_state.deref().clone(),
+    );
+
+    use_effect_with_deps(
+        {
+            let on_change = on_change.clone();
+            move |(parsed_cfm, parsed_static_pressure, units): &(
+                Rc<Result<f64, Vec<String>>>,
+                Rc<Result<f64, Vec<String>>>,
+                UnitSystem,
+            )| {
+                match (parsed_cfm.as_ref(), parsed_static_pressure.as_ref()) {
+                    (Ok(cfm), Ok(static_pressure)) => on_change.emit(Some(SystemCurve::through(
+                        &InletAirflow::from_measurement(*cfm, *units),
+                        &StaticPressure::from_measurement(*static_pressure, *units),
+                    ))),
+                    _ => on_change.emit(None),
+                };
+                || {}
+            }
+        },
+        (
+            Rc::clone(&parsed_cfm),
+            Rc::clone(&parsed_static_pressure),
+            units,
+        ),
+    );
+
+    let on_cfm_change = {
+        let entered_cfm_setter = entered_cfm_state.setter();
+        use_callback(move |(_index, cfm), _deps| entered_cfm_setter.set(cfm), ())
+    };
+
+    let on_static_pressure_change = {
+        let entered_static_pressure_setter = entered_static_pressure_state.setter();
+        use_callback(
+            move |(_index, static_pressure), _deps| {
+                entered_static_pressure_setter.set(static_pressure)
+            },
+            (),
+        )
+    };
+
+    html! {
+        <>
+            <h3>{"System Curve"}</h3>
+            <div style="display: grid; grid-template-columns: auto auto; width: fit-content; column-gap: 8px; row-gap: 4px;">
+                <label>{format!("Design Airflow ({})", InletAirflow::symbol(units))}</label>
+                <TaggedInput<()>
+                    errs={Rc::new(parsed_cfm.as_ref().clone().err().unwrap_or_default())}
+                    value={(*entered_cfm_state).clone()}
+                    tag={()}
+                    onchange={on_cfm_change}
+                />
+                <label>{format!("Design Static Pressure ({})", StaticPressure::symbol(units))}</label>
+                <TaggedInput<()>
+                    errs={Rc::new(parsed_static_pressure.as_ref().clone().err().unwrap_or_default())}
+                    value={(*entered_static_pressure_state).clone()}
+                    tag={()}
+                    onchange={on_static_pressure_change}
+                />
+            </div>
+        </>
+    }
+}
+
+// Blank is fine, it just means there's no system curve to draw
+fn parse_design_value(entered: &str) -> Result<f64, Vec<String>> {
+    if entered.is_empty() {
+        return Err(vec![]);
+    }
+    match entered.parse::<f64>() {
+        Ok(value) if value > 0.0 => Ok(value),
+        Ok(_) => Err(vec!["The design point must be positive".to_string()]),
+        Err(_) => Err(vec!["You must enter a valid number".to_string()]),
+    }
+}
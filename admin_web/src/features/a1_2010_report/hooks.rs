@@ -19,6 +19,7 @@ pub struct A1FormHookRes {
     pub on_valid_entry: Callback<UpdateBody>,
     pub maybe_report: Rc<Option<A1Standard2010Report<FanSize<FanSeries<()>>>>>,
     pub maybe_points_to_render: Rc<Option<Vec<A1Standard2010Determination>>>,
+    pub maybe_rpm_to_render: Rc<Option<f64>>,
     pub on_submit_click: Callback<MouseEvent>,
 }
 
@@ -40,12 +41,22 @@ pub fn use_a1_form_controller(maybe_report_id: Option<String>) -> A1FormHookRes
         |parsed_update_body| {
             parsed_update_body
                 .as_ref()
-                .map(|u| Some(u.clone().determinations))
+                .map(|u| Some(u.to_imperial().determinations))
                 .unwrap_or(maybe_report.as_ref().clone().map(|r| r.determinations))
         },
         last_valid_entry.deref().clone(),
     );
 
+    let maybe_rpm_to_render = use_memo(
+        |parsed_update_body| {
+            parsed_update_body
+                .as_ref()
+                .map(|u| Some(u.fan_rpm))
+                .unwrap_or(maybe_report.as_ref().clone().map(|r| r.parameters.rpm))
+        },
+        last_valid_entry.deref().clone(),
+    );
+
     let on_submit_click = {
         use_callback(
             |_evt: MouseEvent, (dispatch, parsed_update_body_ref)| {
@@ -86,6 +97,7 @@ pub fn use_a1_form_controller(maybe_report_id: Option<String>) -> A1FormHookRes
         on_valid_entry,
         maybe_report,
         maybe_points_to_render,
+        maybe_rpm_to_render,
         on_submit_click,
     }
 }
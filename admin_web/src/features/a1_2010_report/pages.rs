@@ -1,4 +1,6 @@
 mod edit;
 pub use edit::EditA1Page;
+mod index;
+pub use index::IndexA1Page;
 mod new;
 pub use new::NewA1Page;
@@ -1,7 +1,16 @@
+use loquat_common::{
+    api::a1_2010_report::{CurveFamilyQuery, PdfQuery},
+    calculations::core::SystemCurve,
+};
 use yew::prelude::*;
+use yew_router::prelude::Link;
 
-use crate::features::a1_2010_report::components::{A1FanPlot, A1Form};
+use crate::api::a1_report;
+use crate::features::a1_2010_report::components::{
+    A1FanPlot, A1Form, A1RevisionHistory, CurveFamilyInput, SystemCurveInput,
+};
 use crate::features::a1_2010_report::hooks::{use_a1_form_controller, A1FormHookRes};
+use crate::route::Route;
 
 #[derive(Properties, PartialEq)]
 pub struct EditA1PageProps {
@@ -16,26 +25,66 @@ pub fn EditA1Page(props: &EditA1PageProps) -> Html {
         on_valid_entry,
         maybe_report,
         maybe_points_to_render,
+        maybe_rpm_to_render,
         on_submit_click,
     } = use_a1_form_controller(Some(report_id.clone()));
 
+    let system_curve_state: UseStateHandle<Option<SystemCurve>> = use_state(|| None);
+    let on_system_curve_change = {
+        let system_curve_setter = system_curve_state.setter();
+        use_callback(
+            move |system_curve, _deps| system_curve_setter.set(system_curve),
+            (),
+        )
+    };
+
+    let curve_family_state: UseStateHandle<Option<CurveFamilyQuery>> = use_state(|| None);
+    let on_curve_family_change = {
+        let curve_family_setter = curve_family_state.setter();
+        use_callback(
+            move |curve_family, _deps| curve_family_setter.set(curve_family),
+            (),
+        )
+    };
+
     let plot_html = match maybe_points_to_render.as_ref() {
-        Some(fc) => html! { <A1FanPlot points={fc.clone()} /> },
+        Some(fc) => html! {
+            <A1FanPlot
+                points={fc.clone()}
+                outlet_area={maybe_report.as_ref().as_ref().map(|report| report.fan_size.outlet_area)}
+                rpm={*maybe_rpm_to_render}
+                system_curve={*system_curve_state}
+                curve_family={(*curve_family_state).clone()}
+            />
+        },
         None => html! { <p>{"Once you correct the fan curve you'll see it here"}</p> },
     };
 
     html! {
         <>
-            <h1>{"Test No. "}{ report_id.clone() }</h1>
+            <h1>
+                {"Test No. "}{ report_id.clone() }
+                <a href={a1_report::pdf_url(&report_id, &PdfQuery::default())} target="_blank">
+                    {'\u{2002}'} // En-space
+                    {"PDF"}
+                </a>
+                <Link<Route> to={Route::NewA2Report}>
+                    {'\u{2002}'} // En-space
+                    {"New A2 Report"}
+                </Link<Route>>
+            </h1>
             <div style="display: flex; flex-direction: row;">
                 <A1Form
-                    {report_id}
+                    report_id={report_id.clone()}
                     {maybe_report}
                     {on_valid_entry}
                     {on_submit_click}
                 />
                 <div style="flex-grow: 1">
                     {plot_html}
+                    <SystemCurveInput on_change={on_system_curve_change} />
+                    <CurveFamilyInput on_change={on_curve_family_change} />
+                    <A1RevisionHistory {report_id} />
                 </div>
             </div>
         </>
@@ -0,0 +1,208 @@
+use std::rc::Rc;
+
+use loquat_common::{
+    api::{a1_2010_report::ReportSummary, ListQuery, Page},
+    models::FanSeries,
+};
+use yew::prelude::*;
+use yew_router::prelude::Link;
+use yewdux::prelude::{use_selector_with_deps, use_store};
+
+use crate::{
+    api::store::{ApiRequestAction, GetParameters, Gettable, RequestStatuses, Store as ApiStore},
+    common::components::{determination_table::TaggedInput, Pager},
+    features::{a1_2010_report::components::A1ReportSummaryTable, fan_series::FanSeriesPicker},
+    route::Route,
+    store::{
+        select_a1_report_page, select_all_fan_series, use_app_store_selector,
+        use_app_store_selector_with_deps,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RpmBound {
+    Min,
+    Max,
+}
+
+#[function_component]
+pub fn IndexA1Page() -> Html {
+    let query_state: UseStateHandle<ListQuery> = use_state(ListQuery::default);
+    let entered_min_rpm_state: UseStateHandle<String> = use_state(|| "".to_string());
+    let entered_max_rpm_state: UseStateHandle<String> = use_state(|| "".to_string());
+    let gettable = Gettable::A1ReportsIndex {
+        query: (*query_state).clone(),
+    };
+    let (_state, dispatch) = use_store::<ApiStore>();
+    let maybe_page: Rc<Option<Page<ReportSummary>>> =
+        use_app_store_selector_with_deps(select_a1_report_page, (*query_state).clone());
+    let fan_serieses: Rc<Vec<FanSeries<()>>> = use_app_store_selector(select_all_fan_series);
+    let request_status = use_selector_with_deps(
+        |store: &ApiStore, dep_gettable| {
+            store
+                .get_status
+                .get(dep_gettable)
+                .cloned()
+                .unwrap_or_default()
+        },
+        gettable.clone(),
+    );
+
+    // Changing a filter starts over from the first page
+    let on_search_change = {
+        let query_setter = query_state.setter();
+        use_callback(
+            move |(_index, search): ((), String), query: &ListQuery| {
+                query_setter.set(ListQuery {
+                    search: Some(search),
+                    offset: None,
+                    ..query.clone()
+                })
+            },
+            (*query_state).clone(),
+        )
+    };
+    let on_fan_series_select = {
+        let query_setter = query_state.setter();
+        use_callback(
+            move |fan_series: Option<FanSeries<()>>, query: &ListQuery| {
+                query_setter.set(ListQuery {
+                    fan_series_id: fan_series.map(|fan_series| fan_series.id),
+                    offset: None,
+                    ..query.clone()
+                })
+            },
+            (*query_state).clone(),
+        )
+    };
+    let on_rpm_change = {
+        let query_setter = query_state.setter();
+        let min_rpm_setter = entered_min_rpm_state.setter();
+        let max_rpm_setter = entered_max_rpm_state.setter();
+        use_callback(
+            move |(bound, entered): (RpmBound, String), query: &ListQuery| {
+                // Bad entries are shown as errors and leave the last good filter in place
+                let parsed = parse_rpm(&entered);
+                match bound {
+                    RpmBound::Min => min_rpm_setter.set(entered),
+                    RpmBound::Max => max_rpm_setter.set(entered),
+                }
+                if let Ok(rpm) = parsed {
+                    query_setter.set(match bound {
+                        RpmBound::Min => ListQuery {
+                            min_rpm: rpm,
+                            offset: None,
+                            ..query.clone()
+                        },
+                        RpmBound::Max => ListQuery {
+                            max_rpm: rpm,
+                            offset: None,
+                            ..query.clone()
+                        },
+                    })
+                }
+            },
+            (*query_state).clone(),
+        )
+    };
+    let on_page = {
+        let query_setter = query_state.setter();
+        use_callback(
+            move |offset, query: &ListQuery| query_setter.set(query.with_offset(offset)),
+            (*query_state).clone(),
+        )
+    };
+
+    use_effect_with_deps(
+        move |dep_gettable| {
+            dispatch.apply(ApiRequestAction::Get(
+                GetParameters {
+                    ignore_cache: false,
+                },
+                dep_gettable.clone(),
+            ));
+            || {}
+        },
+        gettable,
+    );
+
+    let picked_fan_series = query_state.fan_series_id.as_ref().and_then(|id| {
+        fan_serieses
+            .iter()
+            .find(|fan_series| &fan_series.id == id)
+            .cloned()
+    });
+    let min_rpm_errs = parse_rpm(&entered_min_rpm_state).err().unwrap_or_default();
+    let max_rpm_errs = parse_rpm(&entered_max_rpm_state).err().unwrap_or_default();
+
+    let results = match (request_status.as_ref(), maybe_page.as_ref()) {
+        (RequestStatuses::Error(_error_at, err), _) => {
+            html! { <p>{"Error: "}{err.to_string()}</p> }
+        }
+        (_, Some(page)) if page.items.is_empty() => {
+            html! { <p>{"No A1 reports match these filters"}</p> }
+        }
+        (_, Some(page)) => html! {
+            <>
+                <A1ReportSummaryTable summaries={Rc::new(page.items.clone())} />
+                <Pager offset={page.offset} limit={page.limit} total={page.total} on_page={on_page} />
+            </>
+        },
+        (_, None) => html! { <p>{"Loading..."}</p> },
+    };
+
+    html! {
+        <div>
+            <h1>
+                {"A1 Reports"}
+                <Link<Route> to={Route::NewA1Report}>
+                    {'\u{2002}'} // En-space
+                    {"New"}
+                </Link<Route>>
+            </h1>
+            <form>
+                <div style="display: grid; grid-template-columns: auto auto; width: fit-content; column-gap: 8px; row-gap: 4px;">
+                    <label>{"Search"}</label>
+                    <TaggedInput<()>
+                        value={query_state.search.clone().unwrap_or_default()}
+                        tag={()}
+                        onchange={on_search_change}
+                    />
+                    <label>{"Fan Series"}</label>
+                    <FanSeriesPicker
+                        no_selection_label={"Any"}
+                        selection={picked_fan_series}
+                        on_select={on_fan_series_select}
+                    />
+                    <label>{"Min. Speed (RPM)"}</label>
+                    <TaggedInput<RpmBound>
+                        errs={Rc::new(min_rpm_errs)}
+                        value={(*entered_min_rpm_state).clone()}
+                        tag={RpmBound::Min}
+                        onchange={on_rpm_change.clone()}
+                    />
+                    <label>{"Max. Speed (RPM)"}</label>
+                    <TaggedInput<RpmBound>
+                        errs={Rc::new(max_rpm_errs)}
+                        value={(*entered_max_rpm_state).clone()}
+                        tag={RpmBound::Max}
+                        onchange={on_rpm_change}
+                    />
+                </div>
+            </form>
+            {results}
+        </div>
+    }
+}
+
+// Leaving a bound empty removes it
+fn parse_rpm(entered: &str) -> Result<Option<f64>, Vec<String>> {
+    if entered.trim().is_empty() {
+        return Ok(None);
+    }
+    match entered.trim().parse::<f64>() {
+        Ok(value) if value >= 0.0 => Ok(Some(value)),
+        Ok(_) => Err(vec!["The speed can't be negative".to_string()]),
+        Err(_) => Err(vec!["You must enter a valid number".to_string()]),
+    }
+}
@@ -1,6 +1,9 @@
+use loquat_common::{api::a1_2010_report::CurveFamilyQuery, calculations::core::SystemCurve};
 use yew::prelude::*;
 
-use crate::features::a1_2010_report::components::{A1FanPlot, A1Form};
+use crate::features::a1_2010_report::components::{
+    A1FanPlot, A1Form, CurveFamilyInput, SystemCurveInput,
+};
 use crate::features::a1_2010_report::hooks::{use_a1_form_controller, A1FormHookRes};
 
 #[function_component]
@@ -9,11 +12,37 @@ pub fn NewA1Page() -> Html {
         on_valid_entry,
         maybe_report: _,
         maybe_points_to_render,
+        maybe_rpm_to_render,
         on_submit_click,
     } = use_a1_form_controller(None);
 
+    let system_curve_state: UseStateHandle<Option<SystemCurve>> = use_state(|| None);
+    let on_system_curve_change = {
+        let system_curve_setter = system_curve_state.setter();
+        use_callback(
+            move |system_curve, _deps| system_curve_setter.set(system_curve),
+            (),
+        )
+    };
+
+    let curve_family_state: UseStateHandle<Option<CurveFamilyQuery>> = use_state(|| None);
+    let on_curve_family_change = {
+        let curve_family_setter = curve_family_state.setter();
+        use_callback(
+            move |curve_family, _deps| curve_family_setter.set(curve_family),
+            (),
+        )
+    };
+
     let plot_html = match maybe_points_to_render.as_ref() {
-        Some(fc) => html! { <A1FanPlot points={fc.clone()} /> },
+        Some(fc) => html! {
+            <A1FanPlot
+                points={fc.clone()}
+                rpm={*maybe_rpm_to_render}
+                system_curve={*system_curve_state}
+                curve_family={(*curve_family_state).clone()}
+            />
+        },
         None => html! { <p>{"Once you enter a complete fan curve, you'll see it here"}</p> },
     };
 
@@ -27,6 +56,8 @@ pub fn NewA1Page() -> Html {
                 />
                 <div style="flex-grow: 1">
                     {plot_html}
+                    <SystemCurveInput on_change={on_system_curve_change} />
+                    <CurveFamilyInput on_change={on_curve_family_change} />
                 </div>
             </div>
         </>
@@ -1,6 +1,12 @@
 use std::{collections::HashMap, rc::Rc};
 
-use loquat_common::models::A1Standard2010Report;
+use loquat_common::{
+    api::{
+        a1_2010_report::{ReportSummary, Revision},
+        ListQuery, Page,
+    },
+    models::A1Standard2010Report,
+};
 use yewdux::{prelude, store::Reducer};
 
 use crate::api::store::ApiResponseAction;
@@ -8,6 +14,9 @@ use crate::api::store::ApiResponseAction;
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct Store {
     pub reports: HashMap<String, A1Standard2010Report<()>>,
+    pub revisions: HashMap<String, Vec<Revision>>,
+    // Summaries are only ever listed, so pages keep them whole rather than by id
+    pub pages: HashMap<ListQuery, Page<ReportSummary>>,
 }
 
 impl prelude::Store for Store {
@@ -25,12 +34,29 @@ impl Reducer<Store> for ApiResponseAction {
         let state: &mut Store = Rc::make_mut(&mut og_state);
 
         match self {
+            ApiResponseAction::RecieveA1Reports(query, page) => {
+                state.pages.insert(query, page);
+
+                og_state
+            }
             ApiResponseAction::RecieveA1Report(report) => {
                 let (report, _fan_size) = report.into();
                 state.reports.insert(report.id.clone(), report);
 
                 og_state
             }
+            ApiResponseAction::RecieveA1ReportRevisions(id, revisions) => {
+                state.revisions.insert(id, revisions);
+
+                og_state
+            }
+            ApiResponseAction::RecieveA2Report(a2_report) => {
+                let (_a2_report, a1_report, _induced_flow_fan_size) = a2_report.into();
+                let (a1_report, _fan_size) = a1_report.into();
+                state.reports.insert(a1_report.id.clone(), a1_report);
+
+                og_state
+            }
             _ => og_state,
         }
     }
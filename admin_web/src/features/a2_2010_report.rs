@@ -0,0 +1,5 @@
+pub mod components;
+pub mod hooks;
+pub mod pages;
+mod store;
+pub use store::Store;
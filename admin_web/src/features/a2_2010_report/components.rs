@@ -0,0 +1,4 @@
+pub mod a2_determination_table;
+pub use a2_determination_table::A22010DeterminationTable;
+pub mod a2_form;
+pub use a2_form::A2Form;
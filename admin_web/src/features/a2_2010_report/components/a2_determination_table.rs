@@ -0,0 +1,52 @@
+use std::{fmt::Debug, iter, rc::Rc};
+
+use yew::{function_component, html, use_callback, Callback, Html, Properties};
+
+use crate::common::components::DeterminationTable;
+
+#[derive(Properties, PartialEq)]
+pub struct A22010DeterminationTableProps {
+    pub fields: Vec<[String; 2]>,
+    pub child_errs: Rc<Vec<[Rc<Vec<String>>; 2]>>,
+    pub onchange: Callback<[[String; 2]; 10]>,
+}
+
+fn to_filled_table<T: Clone + Debug>(given_rows: &[[T; 2]], empty: impl Fn() -> T) -> [[T; 2]; 10] {
+    given_rows
+        .iter()
+        .chain(iter::repeat(&[empty(), empty()]))
+        .take(10)
+        .cloned()
+        .collect::<Vec<[T; 2]>>()
+        .try_into()
+        .unwrap()
+}
+
+#[function_component]
+pub fn A22010DeterminationTable(props: &A22010DeterminationTableProps) -> Html {
+    let rows = to_filled_table(&props.fields, || "".to_string());
+    let child_errs = to_filled_table(&props.child_errs, || Rc::new(Vec::new()));
+    let on_determination_value_change: Callback<(usize, usize, String)> = use_callback(
+        move |(row_index, col_index, value): (usize, usize, String), (rows, onchange)| {
+            let mut rows = rows.clone();
+            rows[row_index][col_index] = value;
+
+            onchange.emit(rows);
+        },
+        (rows.clone(), props.onchange.clone()),
+    );
+
+    let headers_lables: [String; 2] = [
+        "Static Pressure (in. wg)".to_string(),
+        "Outlet Flow Rate (cfm)".to_string(),
+    ];
+
+    html! {
+        <DeterminationTable<2>
+            headers={headers_lables}
+            onchange={on_determination_value_change}
+            rows={rows.to_vec()}
+            child_errs={child_errs.to_vec()}
+        />
+    }
+}
@@ -0,0 +1,366 @@
+use std::ops::Deref;
+use std::rc::Rc;
+
+use loquat_common::api::a2_2010_report::{GetResponse, UpdateBody};
+
+use yew::prelude::*;
+
+use loquat_common::models::A2Standard2010Determination;
+
+use crate::common::components::determination_table::TaggedInput;
+use crate::common::components::DeterminationsPasteTextArea;
+use crate::features::a2_2010_report::components::A22010DeterminationTable;
+
+#[derive(Debug, Properties, PartialEq)]
+pub struct A2FormProps {
+    pub report_id: Option<AttrValue>,
+    #[prop_or_else(|| Rc::new(None))]
+    pub maybe_report: Rc<Option<GetResponse>>,
+    pub on_valid_entry: Callback<UpdateBody>,
+    pub on_submit_click: Callback<MouseEvent>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct UpdateBodyErrors {
+    a1_report_errs: Vec<String>,
+    induced_flow_fan_size_errs: Vec<String>,
+    rpm_errs: Vec<String>,
+    determination_errs: Vec<[Rc<Vec<String>>; 2]>,
+}
+
+#[function_component]
+pub fn A2Form(
+    A2FormProps {
+        report_id,
+        maybe_report,
+        on_valid_entry,
+        on_submit_click,
+    }: &A2FormProps,
+) -> Html {
+    let report_id_state: UseStateHandle<String> = {
+        let report_id = report_id.clone();
+        use_state(move || report_id.map_or("".to_string(), |id| id.to_string()))
+    };
+    let entered_a1_report_id_state: UseStateHandle<String> = use_state(|| "".to_string());
+    let entered_induced_flow_fan_size_id_state: UseStateHandle<String> =
+        use_state(|| "".to_string());
+    let entered_rpm_state: UseStateHandle<String> = use_state(|| "".to_string());
+    let determinations_state: UseStateHandle<Vec<[String; 2]>> = use_state(Vec::new);
+
+    let parsed_a1_report_id: Rc<Result<String, Vec<String>>> = use_memo(
+        |entered_a1_report_id: &String| {
+            if entered_a1_report_id.is_empty() {
+                Err(vec![
+                    "You must enter the A1 report this test extends".to_string()
+                ])
+            } else {
+                Ok(entered_a1_report_id.clone())
+            }
+        },
+        entered_a1_report_id_state.deref().clone(),
+    );
+
+    let parsed_induced_flow_fan_size_id: Rc<Result<String, Vec<String>>> = use_memo(
+        |entered_induced_flow_fan_size_id: &String| {
+            if entered_induced_flow_fan_size_id.is_empty() {
+                Err(vec![
+                    "You must enter the induced flow fan size that was tested".to_string(),
+                ])
+            } else {
+                Ok(entered_induced_flow_fan_size_id.clone())
+            }
+        },
+        entered_induced_flow_fan_size_id_state.deref().clone(),
+    );
+
+    let parsed_rpm: Rc<Result<f64, Vec<String>>> = use_memo(
+        |entered_rpm: &String| match entered_rpm.deref().parse::<f64>() {
+            Ok(value) => {
+                if value <= 0.0 {
+                    Err(vec!["The fan speed must be positive".to_string()])
+                } else {
+                    Ok(value)
+                }
+            }
+            Err(_) => {
+                if entered_rpm.is_empty() {
+                    Err(vec!["You must enter a fan RPM for the test".to_string()])
+                } else {
+                    Err(vec!["You must enter a valid number".to_string()])
+                }
+            }
+        },
+        entered_rpm_state.deref().clone(),
+    );
+
+    let parsed_determinations =
+        use_memo(parse_determenations, determinations_state.deref().clone());
+
+    let parsed_update_body: Rc<Result<UpdateBody, UpdateBodyErrors>> = use_memo(
+        |(
+            report_id,
+            parsed_a1_report_id,
+            parsed_induced_flow_fan_size_id,
+            parsed_rpm,
+            parsed_determinations,
+        )| {
+            let parses = (
+                parsed_a1_report_id.as_ref(),
+                parsed_induced_flow_fan_size_id.as_ref(),
+                parsed_rpm.as_ref(),
+                parsed_determinations.as_ref(),
+            );
+            if let (
+                Ok(a1_report_id),
+                Ok(induced_flow_fan_size_id),
+                Ok(fan_rpm),
+                Ok(determinations),
+            ) = parses
+            {
+                Ok(UpdateBody {
+                    id: report_id.clone(),
+                    fan_rpm: *fan_rpm,
+                    a1_report_id: a1_report_id.clone(),
+                    induced_flow_fan_size_id: induced_flow_fan_size_id.clone(),
+                    determinations: determinations.clone(),
+                })
+            } else {
+                Err(UpdateBodyErrors {
+                    a1_report_errs: parsed_a1_report_id
+                        .as_ref()
+                        .clone()
+                        .err()
+                        .unwrap_or_default(),
+                    induced_flow_fan_size_errs: parsed_induced_flow_fan_size_id
+                        .as_ref()
+                        .clone()
+                        .err()
+                        .unwrap_or_default(),
+                    rpm_errs: parsed_rpm.as_ref().clone().err().unwrap_or_default(),
+                    determination_errs: parsed_determinations
+                        .as_ref()
+                        .clone()
+                        .err()
+                        .unwrap_or_default(),
+                })
+            }
+        },
+        (
+            report_id_state.deref().clone(),
+            Rc::clone(&parsed_a1_report_id),
+            Rc::clone(&parsed_induced_flow_fan_size_id),
+            Rc::clone(&parsed_rpm),
+            Rc::clone(&parsed_determinations),
+        ),
+    );
+
+    use_effect_with_deps(
+        {
+            let on_valid_entry = on_valid_entry.clone();
+            move |parsed_update_body: &Rc<Result<UpdateBody, UpdateBodyErrors>>| {
+                if let Ok(value) = parsed_update_body.as_ref() {
+                    on_valid_entry.emit(value.clone())
+                };
+                || {}
+            }
+        },
+        Rc::clone(&parsed_update_body),
+    );
+
+    // Reset fields for new report
+    use_effect_with_deps(
+        {
+            let a1_report_id_setter = entered_a1_report_id_state.setter();
+            let induced_flow_fan_size_id_setter = entered_induced_flow_fan_size_id_state.setter();
+            let rpm_string_setter = entered_rpm_state.setter();
+            let determinations_setter = determinations_state.setter();
+            move |report_option: &Option<GetResponse>| {
+                if let Some(report) = report_option {
+                    a1_report_id_setter.set(report.a1_report_id.clone());
+                    induced_flow_fan_size_id_setter.set(report.induced_flow_fan_size_id.clone());
+                    rpm_string_setter.set(report.parameters.rpm.to_string());
+                    determinations_setter.set(
+                        report
+                            .determinations
+                            .clone()
+                            .into_iter()
+                            .map(|det| [det.static_pressure.to_string(), det.cfm.to_string()])
+                            .collect(),
+                    );
+                } else {
+                    a1_report_id_setter.set("".to_string());
+                    induced_flow_fan_size_id_setter.set("".to_string());
+                    rpm_string_setter.set("".to_string());
+                    determinations_setter.set(vec![])
+                }
+            }
+        },
+        maybe_report.as_ref().clone(),
+    );
+
+    let on_report_id_change = {
+        let report_id_setter = report_id_state.setter();
+        use_callback(
+            move |(_index, report_id), _deps| report_id_setter.set(report_id),
+            (),
+        )
+    };
+
+    let on_a1_report_id_change = {
+        let a1_report_id_setter = entered_a1_report_id_state.setter();
+        use_callback(
+            move |(_index, a1_report_id), _deps| a1_report_id_setter.set(a1_report_id),
+            (),
+        )
+    };
+
+    let on_induced_flow_fan_size_id_change = {
+        let induced_flow_fan_size_id_setter = entered_induced_flow_fan_size_id_state.setter();
+        use_callback(
+            move |(_index, induced_flow_fan_size_id), _deps| {
+                induced_flow_fan_size_id_setter.set(induced_flow_fan_size_id)
+            },
+            (),
+        )
+    };
+
+    let on_rpm_input_change = {
+        let entered_rpm_setter = entered_rpm_state.setter();
+        use_callback(
+            move |(_index, rpm_option), _deps| entered_rpm_setter.set(rpm_option),
+            (),
+        )
+    };
+
+    let on_dets_input_change = {
+        let determinations_table_setter = determinations_state.setter();
+        use_callback(
+            move |data: [[String; 2]; 10], _dets| determinations_table_setter.set(data.to_vec()),
+            (),
+        )
+    };
+
+    let on_determinations_extracted = {
+        let determinations_setter = determinations_state.setter();
+        use_callback(
+            move |dets: Vec<[String; 2]>, _deps| determinations_setter.set(dets),
+            (),
+        )
+    };
+
+    let UpdateBodyErrors {
+        a1_report_errs,
+        induced_flow_fan_size_errs,
+        rpm_errs,
+        determination_errs,
+    } = match parsed_update_body.as_ref() {
+        Ok(_) => UpdateBodyErrors {
+            a1_report_errs: vec![],
+            induced_flow_fan_size_errs: vec![],
+            rpm_errs: vec![],
+            determination_errs: vec![],
+        },
+        Err(errs) => errs.clone(),
+    };
+
+    html! {
+
+                <form>
+                    <h2>{"Test Details"}</h2>
+                    <div style="display: grid; grid-template-columns: auto auto; width: fit-content; column-gap: 8px; row-gap: 4px;">
+                        <label>{"Report ID"}</label>
+                        <TaggedInput<()>
+                            value={(*report_id_state).clone()}
+                            tag={()}
+                            onchange={on_report_id_change}
+                            disabled={report_id.is_some()}
+                        />
+                        <label>{"A1 Report ID"}</label>
+                        <TaggedInput<()>
+                            errs={Rc::new(a1_report_errs)}
+                            value={(*entered_a1_report_id_state).clone()}
+                            tag={()}
+                            onchange={on_a1_report_id_change}
+                        />
+                        <label>{"Induced Flow Fan Size ID"}</label>
+                        <TaggedInput<()>
+                            errs={Rc::new(induced_flow_fan_size_errs)}
+                            value={(*entered_induced_flow_fan_size_id_state).clone()}
+                            tag={()}
+                            onchange={on_induced_flow_fan_size_id_change}
+                        />
+                        <label>{"Test RPM"}</label>
+                        <TaggedInput<()>
+                            errs={Rc::new(rpm_errs)}
+                            value={(*entered_rpm_state).clone()}
+                            tag={()}
+                            onchange={on_rpm_input_change}
+                        />
+                    </div>
+                    <label><h2>{"Determination Points"}</h2></label>
+                    <A22010DeterminationTable
+                        fields={(*determinations_state).clone()}
+                        child_errs={Rc::new(determination_errs)}
+                        onchange={on_dets_input_change}
+                    />
+                    <label><h3>{"Quick Paste Determination Points"}</h3></label>
+                    <DeterminationsPasteTextArea<2>
+                        on_extracted={on_determinations_extracted}
+                        cols_to_extract={[3,4]}
+                        expected_row_length={9}
+                        expected_row_count={10}
+                        expected_headers={vec![
+                            "Det. No. P t P v P s Q H K p η t η s",
+                            "(in. wg) (in. wg) (in. wg) (cfm) (hp) - (%) (%)"
+                        ]}
+                    />
+                    <button onclick={on_submit_click}>
+                        {"Save"}
+                    </button>
+                </form>
+
+    }
+}
+type DeterminationErrors = [Rc<Vec<String>>; 2];
+fn parse_determenations(
+    determinations_state: &Vec<[String; 2]>,
+) -> Result<Vec<A2Standard2010Determination>, Vec<DeterminationErrors>> {
+    let parsed_rows: Vec<Result<[f64; 2], DeterminationErrors>> = determinations_state
+        .deref()
+        .iter()
+        .map(|det| {
+            let det_attempt: [Result<f64, Rc<Vec<String>>>; 2] = det.clone().map(|x| {
+                x.parse::<f64>().map_err(|_| {
+                    Rc::new(if x.is_empty() {
+                        vec!["All determination point values must be entered".to_string()]
+                    } else {
+                        vec!["You must enter a valid number".to_string()]
+                    })
+                })
+            });
+            if det_attempt.iter().all(|d| d.is_ok()) {
+                Ok(det_attempt.map(|d| d.ok().unwrap()))
+            } else {
+                Err(det_attempt.map(|a| a.err().unwrap_or_default()))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if parsed_rows.iter().all(|d| d.is_ok()) {
+        Ok(parsed_rows
+            .into_iter()
+            .map(|d| {
+                let [static_pressure, cfm] = d.ok().unwrap();
+                A2Standard2010Determination {
+                    static_pressure,
+                    cfm,
+                }
+            })
+            .collect())
+    } else {
+        Err(parsed_rows
+            .into_iter()
+            .map(|row| row.err().unwrap_or_default())
+            .collect::<Vec<_>>())
+    }
+}
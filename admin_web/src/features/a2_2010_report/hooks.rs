@@ -0,0 +1,79 @@
+use std::ops::Deref;
+use std::rc::Rc;
+
+use loquat_common::api::a2_2010_report::{GetResponse, UpdateBody};
+use web_sys::MouseEvent;
+use yew::{hook, use_callback, use_effect_with_deps, use_state, Callback, UseStateHandle};
+use yewdux::prelude::use_store;
+
+use crate::api::store::Store as ApiStore;
+use crate::api::store::{ApiRequestAction, GetParameters, Gettable};
+use crate::store::{select_a2_report, use_app_store_selector_with_deps};
+
+pub struct A2FormHookRes {
+    pub on_valid_entry: Callback<UpdateBody>,
+    pub maybe_report: Rc<Option<GetResponse>>,
+    pub on_submit_click: Callback<MouseEvent>,
+}
+
+#[hook]
+pub fn use_a2_form_controller(maybe_report_id: Option<String>) -> A2FormHookRes {
+    let (_state, api_dispatch) = use_store::<ApiStore>();
+
+    let last_valid_entry: UseStateHandle<Option<UpdateBody>> = use_state(|| None);
+
+    let maybe_report: Rc<Option<GetResponse>> =
+        use_app_store_selector_with_deps(select_a2_report, maybe_report_id.clone());
+
+    let on_valid_entry = {
+        let set_last_valid_entry = last_valid_entry.setter();
+        use_callback(move |entry, _| set_last_valid_entry.set(Some(entry)), ())
+    };
+
+    let on_submit_click = {
+        use_callback(
+            |evt: MouseEvent, (dispatch, parsed_update_body_ref, is_existing_report)| {
+                evt.prevent_default();
+                if let Some(update_body) = (*parsed_update_body_ref).as_ref() {
+                    let body = update_body.clone();
+                    dispatch.apply(ApiRequestAction::Get(
+                        GetParameters { ignore_cache: true },
+                        if *is_existing_report {
+                            Gettable::PutA22010Report { body }
+                        } else {
+                            Gettable::PostA22010Report { body }
+                        },
+                    ))
+                }
+            },
+            (
+                api_dispatch.clone(),
+                last_valid_entry.deref().clone(),
+                maybe_report_id.is_some(),
+            ),
+        )
+    };
+
+    use_effect_with_deps(
+        move |maybe_report_id: &Option<String>| {
+            if let Some(report_id) = maybe_report_id {
+                api_dispatch.apply(ApiRequestAction::Get(
+                    GetParameters {
+                        ignore_cache: false,
+                    },
+                    Gettable::A2Report {
+                        id: report_id.clone(),
+                    },
+                ));
+            }
+            || {}
+        },
+        maybe_report_id,
+    );
+
+    A2FormHookRes {
+        on_valid_entry,
+        maybe_report,
+        on_submit_click,
+    }
+}
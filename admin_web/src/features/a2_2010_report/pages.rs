@@ -0,0 +1,4 @@
+mod edit;
+pub use edit::EditA2Page;
+mod new;
+pub use new::NewA2Page;
@@ -0,0 +1,34 @@
+use yew::prelude::*;
+
+use crate::features::a2_2010_report::components::A2Form;
+use crate::features::a2_2010_report::hooks::{use_a2_form_controller, A2FormHookRes};
+
+#[derive(Properties, PartialEq)]
+pub struct EditA2PageProps {
+    pub id: AttrValue,
+}
+
+#[function_component]
+pub fn EditA2Page(props: &EditA2PageProps) -> Html {
+    let report_id = props.id.replace("%20", " ");
+
+    let A2FormHookRes {
+        on_valid_entry,
+        maybe_report,
+        on_submit_click,
+    } = use_a2_form_controller(Some(report_id.clone()));
+
+    html! {
+        <>
+            <h1>{"Test No. "}{ report_id.clone() }</h1>
+            <div style="display: flex; flex-direction: row;">
+                <A2Form
+                    {report_id}
+                    {maybe_report}
+                    {on_valid_entry}
+                    {on_submit_click}
+                />
+            </div>
+        </>
+    }
+}
@@ -0,0 +1,25 @@
+use yew::prelude::*;
+
+use crate::features::a2_2010_report::components::A2Form;
+use crate::features::a2_2010_report::hooks::{use_a2_form_controller, A2FormHookRes};
+
+#[function_component]
+pub fn NewA2Page() -> Html {
+    let A2FormHookRes {
+        on_valid_entry,
+        maybe_report: _,
+        on_submit_click,
+    } = use_a2_form_controller(None);
+
+    html! {
+        <>
+            <h1>{"New A2 Report"}</h1>
+            <div style="display: flex; flex-direction: row;">
+                <A2Form
+                    {on_valid_entry}
+                    {on_submit_click}
+                />
+            </div>
+        </>
+    }
+}
@@ -0,0 +1,55 @@
+use std::{collections::HashMap, rc::Rc};
+
+use loquat_common::models::{A2Standard2010Report, InducedFlowFanSize, Nozzle};
+use yewdux::{prelude, store::Reducer};
+
+use crate::api::store::ApiResponseAction;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Store {
+    pub reports: HashMap<String, A2Standard2010Report<(), ()>>,
+    pub induced_flow_fan_sizes: HashMap<String, InducedFlowFanSize<(), Nozzle>>,
+}
+
+impl prelude::Store for Store {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn should_notify(&self, old: &Self) -> bool {
+        !self.eq(old)
+    }
+}
+
+impl Reducer<Store> for ApiResponseAction {
+    fn apply(self, mut og_state: Rc<Store>) -> Rc<Store> {
+        let state: &mut Store = Rc::make_mut(&mut og_state);
+
+        match self {
+            ApiResponseAction::RecieveA2Report(report) => {
+                let (report, _a1_report, induced_flow_fan_size) = report.into();
+                let InducedFlowFanSize {
+                    id,
+                    fan_size_id,
+                    fan_size: _,
+                    nozzle_id,
+                    nozzle,
+                } = induced_flow_fan_size;
+                state.induced_flow_fan_sizes.insert(
+                    id.clone(),
+                    InducedFlowFanSize {
+                        id,
+                        fan_size_id,
+                        fan_size: (),
+                        nozzle_id,
+                        nozzle,
+                    },
+                );
+                state.reports.insert(report.id.clone(), report);
+
+                og_state
+            }
+            _ => og_state,
+        }
+    }
+}
@@ -93,8 +93,16 @@ pub fn FanSeriesForm(
         type_errs: parsed_type.err().unwrap_or_default(),
     })
     };
-    
-    
+
+    use_effect_with_deps({
+      let on_valid_entry = on_valid_entry.clone();
+      move |parsed_update_result: &Result<UpdateBody, UpdateBodyErrors>| {
+        if let Ok(update_body) = parsed_update_result {
+          on_valid_entry.emit(update_body.clone());
+        }
+      }
+    }, parsed_update_result.clone());
+
     let UpdateBodyErrors {
       id_errs, type_errs
     } = parsed_update_result.err().unwrap_or_default();
@@ -1,6 +1,6 @@
 use std::rc::Rc;
 
-use loquat_common::models::FanSeries;
+use loquat_common::{api::ListQuery, models::FanSeries};
 use yew::{function_component, html, use_effect_with_deps, Callback, Html, Properties};
 use yewdux::prelude::{use_selector, use_selector_with_deps, use_store};
 
@@ -11,6 +11,8 @@ use crate::features::fan_series::Store;
 
 #[derive(Properties, PartialEq)]
 pub struct FanSeriesPickerProps {
+    #[prop_or(Rc::new(vec![]))]
+    pub errs: Rc<Vec<String>>,
     pub no_selection_label: String,
     pub selection: Option<FanSeries<()>>,
     pub on_select: Callback<Option<FanSeries<()>>, ()>,
@@ -19,12 +21,19 @@ pub struct FanSeriesPickerProps {
 #[function_component]
 pub fn FanSeriesPicker(
     FanSeriesPickerProps {
+        errs,
         no_selection_label,
         selection,
         on_select,
     }: &FanSeriesPickerProps,
 ) -> Html {
-    let gettable = Gettable::FanSeriesesIndex;
+    // Pickers offer everything they can, rather than paging
+    let gettable = Gettable::FanSeriesesIndex {
+        query: ListQuery {
+            limit: Some(ListQuery::MAX_LIMIT),
+            ..Default::default()
+        },
+    };
     let (_state, dispatch) = use_store::<ApiStore>();
     let fan_serieses: Rc<Vec<FanSeries<()>>> =
         use_selector(|state: &Store| state.fan_serieses.values().cloned().collect::<Vec<_>>());
@@ -55,6 +64,7 @@ pub fn FanSeriesPicker(
 
     html! {
       <Select<FanSeries<()>>
+        errs={Rc::clone(errs)}
         no_selection_label={no_selection_label.clone()}
         selection={selection.clone()}
         on_select={on_select}
@@ -36,17 +36,25 @@ pub fn use_fan_series_form_controller(maybe_fan_series_id: Option<String>) -> Fa
     };
     let on_submit_click = {
         use_callback(
-            |_evt: MouseEvent, (dispatch, parsed_update_body_ref)| {
+            |evt: MouseEvent, (dispatch, parsed_update_body_ref, is_existing)| {
+                evt.prevent_default();
                 if let Some(update_body) = (*parsed_update_body_ref).as_ref() {
+                    let body = update_body.clone();
                     dispatch.apply(ApiRequestAction::Get(
                         GetParameters { ignore_cache: true },
-                        Gettable::PutFanSeriesReport {
-                            body: update_body.clone(),
+                        if *is_existing {
+                            Gettable::PutFanSeriesReport { body }
+                        } else {
+                            Gettable::PostFanSeriesReport { body }
                         },
                     ))
                 }
             },
-            (api_dispatch.clone(), last_valid_entry.deref().clone()),
+            (
+                api_dispatch.clone(),
+                last_valid_entry.deref().clone(),
+                maybe_fan_series_id.is_some(),
+            ),
         )
     };
 
@@ -32,6 +32,7 @@ pub fn EditFanSeriesPage(EditFanSeriesPageProps { id }: &EditFanSeriesPageProps)
             </h1>
             <div style="display: flex; flex-direction: row;">
                 <FanSeriesForm
+                    fan_series_id={fan_series_id.clone()}
                     {maybe_fan_series}
                     {on_valid_entry}
                     {on_submit_click}
@@ -3,22 +3,32 @@ use std::rc::Rc;
 use crate::api::store::{RequestStatuses, Store as ApiStore};
 use instant::Instant;
 
-use loquat_common::models::FanSeries;
-use yew::{function_component, html, use_effect_with_deps, Html};
+use loquat_common::{
+    api::{ListQuery, Page},
+    models::{FanSeries, FanType},
+};
+use yew::{
+    function_component, html, use_callback, use_effect_with_deps, use_state, Html, UseStateHandle,
+};
 use yew_router::prelude::Link;
 use yewdux::prelude::{use_selector_with_deps, use_store};
 
 use crate::{
     api::store::{ApiRequestAction, GetParameters, Gettable},
+    common::components::{determination_table::TaggedInput, Pager, Select},
     route::Route,
-    store::{select_all_fan_series, use_app_store_selector},
+    store::{select_fan_series_page, use_app_store_selector_with_deps},
 };
 
 #[function_component]
 pub fn IndexFanSeriesPage() -> Html {
-    let gettable = Gettable::FanSeriesesIndex;
+    let query_state: UseStateHandle<ListQuery> = use_state(ListQuery::default);
+    let gettable = Gettable::FanSeriesesIndex {
+        query: (*query_state).clone(),
+    };
     let (_state, dispatch) = use_store::<ApiStore>();
-    let fan_serieses: Rc<Vec<FanSeries<()>>> = use_app_store_selector(select_all_fan_series);
+    let maybe_page: Rc<Option<Page<FanSeries<()>>>> =
+        use_app_store_selector_with_deps(select_fan_series_page, (*query_state).clone());
     let request_status = use_selector_with_deps(
         |store: &ApiStore, dep_gettable| {
             store
@@ -37,6 +47,41 @@ pub fn IndexFanSeriesPage() -> Html {
         })
     };
 
+    // Changing a filter starts over from the first page
+    let on_search_change = {
+        let query_setter = query_state.setter();
+        use_callback(
+            move |(_index, search): ((), String), query: &ListQuery| {
+                query_setter.set(ListQuery {
+                    search: Some(search),
+                    offset: None,
+                    ..query.clone()
+                })
+            },
+            (*query_state).clone(),
+        )
+    };
+    let on_fan_type_select = {
+        let query_setter = query_state.setter();
+        use_callback(
+            move |fan_type: Option<FanType>, query: &ListQuery| {
+                query_setter.set(ListQuery {
+                    fan_type,
+                    offset: None,
+                    ..query.clone()
+                })
+            },
+            (*query_state).clone(),
+        )
+    };
+    let on_page = {
+        let query_setter = query_state.setter();
+        use_callback(
+            move |offset, query: &ListQuery| query_setter.set(query.with_offset(offset)),
+            (*query_state).clone(),
+        )
+    };
+
     use_effect_with_deps(
         move |dep_gettable| {
             dispatch.apply(ApiRequestAction::Get(
@@ -50,75 +95,71 @@ pub fn IndexFanSeriesPage() -> Html {
         },
         gettable,
     );
-    match request_status.as_ref() {
-        RequestStatuses::Error(_error_at, msg) => {
-            html! {
-                <div>
-                    <h1>{"Fan List"} <button onclick={reload_callback}>{"Refresh"}</button></h1>
 
-                    {"Error: "}{msg}
-                </div>
-            }
-        }
-        RequestStatuses::Unfetched => {
+    let results = match (request_status.as_ref(), maybe_page.as_ref()) {
+        (RequestStatuses::Error(_error_at, err), _) => {
             html! {
-                <div>
-                    <h1>{"Fan List"}</h1>
-
-                    {"Click to fetch"}
-                </div>
+                <p>
+                    {"Error: "}{err.to_string()}
+                    <button onclick={reload_callback}>{"Refresh"}</button>
+                </p>
             }
         }
-        RequestStatuses::Fetching(_fetched_at) => {
+        (_, Some(page)) if page.items.is_empty() => html! { {"No Serieses!"} },
+        (_, Some(page)) => {
             html! {
-                <div>
-                    <h1>{"Fan List"}</h1>
-
-                    {"Loading..."}
-                </div>
+                <>
+                    <ul>
+                        { page.items.iter().map(|fan| html! {
+                            <li>
+                                <Link<Route> to={Route::GetFanSeries { id: fan.id.clone() }}>
+                                    {fan.id.clone()}
+                                </Link<Route>>
+                            </li>
+                            } ).collect::<Vec<_>>() }
+                    </ul>
+                    <Pager offset={page.offset} limit={page.limit} total={page.total} on_page={on_page} />
+                </>
             }
         }
-        RequestStatuses::Refetching(_fetched_at, _last_status) => {
-            html! {
-                <div>
-                    <h1>{"Fan List"}</h1>
+        (_, None) => html! { {"Loading..."} },
+    };
 
-                    {"Loading..."}
+    html! {
+        <div>
+            <h1>
+            {"Fan Series"}
+            <Link<Route> to={Route::NewFanSeriesPage}>
+                {'\u{2002}'} // En-space
+                {"New"}
+            </Link<Route>>
+            </h1>
+            <p>
+                <Link<Route> to={Route::IndexFanSizes}>{"All Fan Sizes"}</Link<Route>>
+                {" | "}
+                <Link<Route> to={Route::IndexA1Reports}>{"All A1 Reports"}</Link<Route>>
+                {" | "}
+                <Link<Route> to={Route::Search}>{"Search Everything"}</Link<Route>>
+            </p>
+            <form>
+                <div style="display: grid; grid-template-columns: auto auto; width: fit-content; column-gap: 8px; row-gap: 4px;">
+                    <label>{"Search"}</label>
+                    <TaggedInput<()>
+                        value={query_state.search.clone().unwrap_or_default()}
+                        tag={()}
+                        onchange={on_search_change}
+                    />
+                    <label>{"Fan Type"}</label>
+                    <Select<FanType>
+                        no_selection_label={"Any"}
+                        selection={query_state.fan_type.clone()}
+                        on_select={on_fan_type_select}
+                        request_status={Rc::new(RequestStatuses::Fetched(Instant::now()))}
+                        selectables={Rc::new(FanType::all_options())}
+                    />
                 </div>
-            }
-        }
-
-        RequestStatuses::Fetched(fetched_at) => {
-            if fan_serieses.is_empty() {
-                html! {
-                    <div>
-                    <h1>{"Fan List"}</h1>
-
-                    {"No Serieses!"}
-                    </div>
-                }
-            } else {
-                html! {
-                    <div>
-                        <h1>
-                        {"Fan Series"}
-                        <Link<Route> to={Route::NewFanSeriesPage}>
-                            {'\u{2002}'} // En-space
-                            {"New"}
-                        </Link<Route>>
-                        </h1>
-                        <ul>
-                            { fan_serieses.iter().map(|fan| html! {
-                                <li>
-                                    <Link<Route> to={Route::GetFanSeries { id: fan.id.clone() }}>
-                                        {fan.id.clone()}
-                                    </Link<Route>>
-                                </li>
-                                } ).collect::<Vec<_>>() }
-                        </ul>
-                    </div>
-                }
-            }
-        }
+            </form>
+            {results}
+        </div>
     }
 }
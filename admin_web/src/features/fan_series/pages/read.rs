@@ -4,9 +4,16 @@ use crate::api::store::Store as ApiStore;
 use crate::{
     api::store::{ApiRequestAction, GetParameters, Gettable},
     route::Route,
-    store::{select_fan_series_by_id, use_app_store_selector_with_deps},
+    store::{
+        select_fan_series_by_id, select_unit_system, use_app_store_selector,
+        use_app_store_selector_with_deps,
+    },
+};
+use loquat_common::{
+    calculations::units::FanDiameter,
+    display::{Measure, UnitSystem},
+    models::{FanSeries, FanSize},
 };
-use loquat_common::models::{FanSeries, FanSize};
 use yew::prelude::*;
 use yew_router::prelude::Link;
 use yewdux::prelude::use_store;
@@ -19,6 +26,7 @@ pub struct ReadFanSeriesPageProps {
 #[function_component]
 pub fn ReadFanSeriesPage(props: &ReadFanSeriesPageProps) -> Html {
     let (_state, dispatch) = use_store::<ApiStore>();
+    let units: UnitSystem = *use_app_store_selector(select_unit_system);
     let id = props.id.clone();
 
     let format_id = id.replace("%20", " ");
@@ -84,10 +92,13 @@ pub fn ReadFanSeriesPage(props: &ReadFanSeriesPageProps) -> Html {
                                 { data.fan_sizes.iter().map(|fan_size| html! {
                                     <li>
                                         <Link<Route> to={Route::GetFanSize { id: fan_size.id.clone() }}>
-                                            {fan_size.id.clone()}{" Diameter: "}{fan_size.diameter}
+                                            {fan_size.id.clone()}{" Diameter: "}{FanDiameter::from_inches(fan_size.diameter).format(units)}
                                         </Link<Route>>
                                     </li>
                                 } ).collect::<Html>() }
+                                <li>
+                                    <Link<Route> to={Route::NewFanSize}>{"Add a size"}</Link<Route>>
+                                </li>
                         </ul>
                             </td>
                         </tr>
@@ -1,6 +1,9 @@
 use std::{collections::HashMap, rc::Rc};
 
-use loquat_common::models::FanSeries;
+use loquat_common::{
+    api::{ListQuery, Page},
+    models::FanSeries,
+};
 use yewdux::{prelude, store::Reducer};
 
 use crate::api::store::ApiResponseAction;
@@ -8,6 +11,8 @@ use crate::api::store::ApiResponseAction;
 #[derive(Debug, Default, Clone, PartialEq, Eq, prelude::Store)]
 pub struct Store {
     pub fan_serieses: HashMap<String, FanSeries<()>>,
+    // The ids on each page of the index, in order
+    pub pages: HashMap<ListQuery, Page<String>>,
 }
 
 impl Reducer<Store> for ApiResponseAction {
@@ -15,10 +20,29 @@ impl Reducer<Store> for ApiResponseAction {
         let state: &mut Store = Rc::make_mut(&mut og_state);
 
         match self {
-            ApiResponseAction::RecieveFanSerieses(fan_serieses) => {
-                for fan_series in fan_serieses {
+            ApiResponseAction::RecieveFanSerieses(query, page) => {
+                let Page {
+                    items,
+                    offset,
+                    limit,
+                    total,
+                } = page;
+                let ids = items
+                    .iter()
+                    .map(|fan_series| fan_series.id.clone())
+                    .collect();
+                for fan_series in items {
                     state.fan_serieses.insert(fan_series.id.clone(), fan_series);
                 }
+                state.pages.insert(
+                    query,
+                    Page {
+                        items: ids,
+                        offset,
+                        limit,
+                        total,
+                    },
+                );
                 og_state
             }
             ApiResponseAction::RecieveFanSeries(fan_series) => {
@@ -42,6 +66,18 @@ impl Reducer<Store> for ApiResponseAction {
                     .insert(flat_series.id.clone(), flat_series);
                 og_state
             }
+            ApiResponseAction::RecieveA2Report(a2_report) => {
+                let (_a2_report, a1_report, induced_flow_fan_size) = a2_report.into();
+                for flat_series in [
+                    a1_report.fan_size.fan_series,
+                    induced_flow_fan_size.fan_size.fan_series,
+                ] {
+                    state
+                        .fan_serieses
+                        .insert(flat_series.id.clone(), flat_series);
+                }
+                og_state
+            }
             _ => og_state,
         }
     }
@@ -1,5 +1,6 @@
 mod components;
 pub use components::FanSizePicker;
+pub mod hooks;
 pub mod pages;
 mod store;
 pub use store::Store;
@@ -1,2 +1,4 @@
+mod fan_size_form;
+pub use fan_size_form::FanSizeForm;
 mod fan_size_picker;
 pub use fan_size_picker::FanSizePicker;
@@ -0,0 +1,205 @@
+use std::ops::Deref;
+use std::rc::Rc;
+
+use loquat_common::{
+    api::fan_size::UpdateBody,
+    models::{FanSeries, FanSize},
+};
+use yew::prelude::*;
+
+use crate::common::components::determination_table::TaggedInput;
+use crate::features::fan_series::FanSeriesPicker;
+
+#[derive(Debug, Properties, PartialEq)]
+pub struct FanSizeFormProps {
+    pub fan_size_id: Option<AttrValue>,
+    #[prop_or_else(|| Rc::new(None))]
+    pub maybe_fan_size: Rc<Option<FanSize<FanSeries<()>>>>,
+    pub on_valid_entry: Callback<UpdateBody>,
+    pub on_submit_click: Callback<MouseEvent>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+struct UpdateBodyErrors {
+    id_errs: Vec<String>,
+    fan_series_errs: Vec<String>,
+    diameter_errs: Vec<String>,
+    outlet_area_errs: Vec<String>,
+}
+
+#[function_component]
+pub fn FanSizeForm(
+    FanSizeFormProps {
+        fan_size_id,
+        maybe_fan_size,
+        on_valid_entry,
+        on_submit_click,
+    }: &FanSizeFormProps,
+) -> Html {
+    let fan_size_id_state: UseStateHandle<String> = {
+        let fan_size_id = fan_size_id.clone();
+        use_state(move || fan_size_id.map_or("".to_string(), |id| id.to_string()))
+    };
+    let picked_fan_series_state: UseStateHandle<Option<FanSeries<()>>> = use_state(|| None);
+    let entered_diameter_state: UseStateHandle<String> = use_state(|| "".to_string());
+    let entered_outlet_area_state: UseStateHandle<String> = use_state(|| "".to_string());
+
+    // Reset fields when the fan size loads
+    use_effect_with_deps(
+        {
+            let fan_size_id_setter = fan_size_id_state.setter();
+            let picked_fan_series_setter = picked_fan_series_state.setter();
+            let diameter_setter = entered_diameter_state.setter();
+            let outlet_area_setter = entered_outlet_area_state.setter();
+            move |maybe_fan_size: &Rc<Option<FanSize<FanSeries<()>>>>| {
+                if let Some(fan_size) = maybe_fan_size.as_ref() {
+                    let (fan_size, fan_series): (FanSize<()>, FanSeries<()>) =
+                        fan_size.clone().into();
+                    fan_size_id_setter.set(fan_size.id);
+                    picked_fan_series_setter.set(Some(fan_series));
+                    diameter_setter.set(fan_size.diameter.to_string());
+                    outlet_area_setter.set(fan_size.outlet_area.to_string());
+                }
+                || {}
+            }
+        },
+        Rc::clone(maybe_fan_size),
+    );
+
+    let parsed_update_body: Rc<Result<UpdateBody, UpdateBodyErrors>> = use_memo(
+        |(id, picked_fan_series, entered_diameter, entered_outlet_area)| {
+            let parsed_id = if id.is_empty() {
+                Err(vec!["Fan Size ID must be set".to_string()])
+            } else {
+                Ok(id.clone())
+            };
+            let parsed_fan_series_id = match picked_fan_series {
+                Some(fan_series) => Ok(fan_series.id.clone()),
+                None => Err(vec!["You must select a fan series".to_string()]),
+            };
+            match (
+                parsed_id,
+                parsed_fan_series_id,
+                parse_dimension(entered_diameter, "diameter"),
+                parse_dimension(entered_outlet_area, "outlet area"),
+            ) {
+                (Ok(id), Ok(fan_series_id), Ok(diameter), Ok(outlet_area)) => Ok(UpdateBody {
+                    id,
+                    fan_series_id,
+                    diameter,
+                    outlet_area,
+                }),
+                (id, fan_series_id, diameter, outlet_area) => Err(UpdateBodyErrors {
+                    id_errs: id.err().unwrap_or_default(),
+                    fan_series_errs: fan_series_id.err().unwrap_or_default(),
+                    diameter_errs: diameter.err().unwrap_or_default(),
+                    outlet_area_errs: outlet_area.err().unwrap_or_default(),
+                }),
+            }
+        },
+        (
+            fan_size_id_state.deref().clone(),
+            picked_fan_series_state.deref().clone(),
+            entered_diameter_state.deref().clone(),
+            entered_outlet_area_state.deref().clone(),
+        ),
+    );
+
+    use_effect_with_deps(
+        {
+            let on_valid_entry = on_valid_entry.clone();
+            move |parsed_update_body: &Rc<Result<UpdateBody, UpdateBodyErrors>>| {
+                if let Ok(update_body) = parsed_update_body.as_ref() {
+                    on_valid_entry.emit(update_body.clone());
+                }
+                || {}
+            }
+        },
+        Rc::clone(&parsed_update_body),
+    );
+
+    let on_fan_size_id_change = {
+        let fan_size_id_setter = fan_size_id_state.setter();
+        use_callback(move |(_index, id), _deps| fan_size_id_setter.set(id), ())
+    };
+    let on_fan_series_select = {
+        let picked_fan_series_setter = picked_fan_series_state.setter();
+        use_callback(
+            move |fan_series, _deps| picked_fan_series_setter.set(fan_series),
+            (),
+        )
+    };
+    let on_diameter_change = {
+        let diameter_setter = entered_diameter_state.setter();
+        use_callback(
+            move |(_index, diameter), _deps| diameter_setter.set(diameter),
+            (),
+        )
+    };
+    let on_outlet_area_change = {
+        let outlet_area_setter = entered_outlet_area_state.setter();
+        use_callback(
+            move |(_index, outlet_area), _deps| outlet_area_setter.set(outlet_area),
+            (),
+        )
+    };
+
+    let UpdateBodyErrors {
+        id_errs,
+        fan_series_errs,
+        diameter_errs,
+        outlet_area_errs,
+    } = parsed_update_body
+        .as_ref()
+        .clone()
+        .err()
+        .unwrap_or_default();
+
+    html! {
+        <form>
+            <div style="display: grid; grid-template-columns: auto auto; width: fit-content; column-gap: 8px; row-gap: 4px;">
+                <label>{"Fan Size ID"}</label>
+                <TaggedInput<()>
+                    errs={Rc::new(id_errs)}
+                    value={(*fan_size_id_state).clone()}
+                    tag={()}
+                    onchange={on_fan_size_id_change}
+                    disabled={fan_size_id.is_some()}
+                />
+                <label>{"Fan Series"}</label>
+                <FanSeriesPicker
+                    errs={Rc::new(fan_series_errs)}
+                    no_selection_label={"--"}
+                    selection={(*picked_fan_series_state).clone()}
+                    on_select={on_fan_series_select}
+                />
+                <label>{"Diameter (in)"}</label>
+                <TaggedInput<()>
+                    errs={Rc::new(diameter_errs)}
+                    value={(*entered_diameter_state).clone()}
+                    tag={()}
+                    onchange={on_diameter_change}
+                />
+                <label>{"Outlet Area (sq. in)"}</label>
+                <TaggedInput<()>
+                    errs={Rc::new(outlet_area_errs)}
+                    value={(*entered_outlet_area_state).clone()}
+                    tag={()}
+                    onchange={on_outlet_area_change}
+                />
+            </div>
+            <button onclick={on_submit_click}>
+                {"Save"}
+            </button>
+        </form>
+    }
+}
+
+fn parse_dimension(entered: &str, name: &str) -> Result<f64, Vec<String>> {
+    match entered.parse::<f64>() {
+        Ok(value) if value > 0.0 => Ok(value),
+        Ok(_) => Err(vec![format!("The {} must be positive", name)]),
+        Err(_) if entered.is_empty() => Err(vec![format!("You must enter the {}", name)]),
+        Err(_) => Err(vec!["You must enter a valid number".to_string()]),
+    }
+}
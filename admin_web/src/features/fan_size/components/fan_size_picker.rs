@@ -1,6 +1,6 @@
 use std::rc::Rc;
 
-use loquat_common::models::FanSize;
+use loquat_common::{api::ListQuery, models::FanSize};
 use yew::{function_component, html, use_effect_with_deps, AttrValue, Callback, Html, Properties};
 use yewdux::prelude::{use_selector_with_deps, use_store};
 
@@ -29,7 +29,13 @@ pub fn FanSizePicker(
         on_select,
     }: &FanSizePickerProps,
 ) -> Html {
-    let gettable = Gettable::FanSizesIndex;
+    // Pickers offer everything they can, rather than paging
+    let gettable = Gettable::FanSizesIndex {
+        query: ListQuery {
+            limit: Some(ListQuery::MAX_LIMIT),
+            ..Default::default()
+        },
+    };
 
     let (_state, dispatch) = use_store::<ApiStore>();
     let fan_sizes: Rc<Vec<FanSize<()>>> = {
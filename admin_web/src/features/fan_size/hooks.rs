@@ -0,0 +1,87 @@
+use std::ops::Deref;
+use std::rc::Rc;
+
+use loquat_common::{
+    api::fan_size::UpdateBody,
+    models::{FanSeries, FanSize},
+};
+use web_sys::MouseEvent;
+use yew::{hook, use_callback, use_effect_with_deps, use_state, Callback, UseStateHandle};
+use yewdux::prelude::use_store;
+
+use crate::api::store::Store as ApiStore;
+use crate::api::store::{ApiRequestAction, GetParameters, Gettable};
+use crate::store::{select_fan_size_by_id, use_app_store_selector_with_deps, AppStore};
+
+pub struct FanSizeFormHookRes {
+    pub maybe_fan_size: Rc<Option<FanSize<FanSeries<()>>>>,
+    pub on_valid_entry: Callback<UpdateBody>,
+    pub on_submit_click: Callback<MouseEvent>,
+}
+
+#[hook]
+pub fn use_fan_size_form_controller(maybe_fan_size_id: Option<String>) -> FanSizeFormHookRes {
+    let (_state, api_dispatch) = use_store::<ApiStore>();
+
+    let last_valid_entry: UseStateHandle<Option<UpdateBody>> = use_state(|| None);
+
+    let maybe_fan_size: Rc<Option<FanSize<FanSeries<()>>>> = use_app_store_selector_with_deps(
+        |state: &AppStore, maybe_id: &Option<String>| {
+            maybe_id
+                .as_ref()
+                .and_then(|id| select_fan_size_by_id(state, id))
+        },
+        maybe_fan_size_id.clone(),
+    );
+
+    let on_valid_entry = {
+        let set_last_valid_entry = last_valid_entry.setter();
+        use_callback(move |entry, _| set_last_valid_entry.set(Some(entry)), ())
+    };
+    let on_submit_click = {
+        use_callback(
+            |evt: MouseEvent, (dispatch, parsed_update_body_ref, is_existing)| {
+                evt.prevent_default();
+                if let Some(update_body) = (*parsed_update_body_ref).as_ref() {
+                    let body = update_body.clone();
+                    dispatch.apply(ApiRequestAction::Get(
+                        GetParameters { ignore_cache: true },
+                        if *is_existing {
+                            Gettable::PutFanSize { body }
+                        } else {
+                            Gettable::PostFanSize { body }
+                        },
+                    ))
+                }
+            },
+            (
+                api_dispatch.clone(),
+                last_valid_entry.deref().clone(),
+                maybe_fan_size_id.is_some(),
+            ),
+        )
+    };
+
+    use_effect_with_deps(
+        move |maybe_fan_size_id: &Option<String>| {
+            if let Some(fan_size_id) = maybe_fan_size_id {
+                api_dispatch.apply(ApiRequestAction::Get(
+                    GetParameters {
+                        ignore_cache: false,
+                    },
+                    Gettable::FanSize {
+                        id: fan_size_id.clone(),
+                    },
+                ));
+            }
+            || {}
+        },
+        maybe_fan_size_id,
+    );
+
+    FanSizeFormHookRes {
+        maybe_fan_size,
+        on_valid_entry,
+        on_submit_click,
+    }
+}
@@ -1,7 +1,254 @@
+pub mod index {
+    use std::rc::Rc;
+
+    use loquat_common::{
+        api::{ListQuery, Page},
+        calculations::units::{FanDiameter, OutletArea},
+        display::{Measure, UnitSystem},
+        models::{FanSeries, FanSize},
+    };
+    use yew::prelude::*;
+    use yew_router::prelude::Link;
+    use yewdux::prelude::{use_selector_with_deps, use_store};
+
+    use crate::api::store::Store as ApiStore;
+    use crate::{
+        api::store::{ApiRequestAction, GetParameters, Gettable, RequestStatuses},
+        common::components::{determination_table::TaggedInput, Pager},
+        features::fan_series::FanSeriesPicker,
+        route::Route,
+        store::{
+            select_all_fan_series, select_fan_size_page, select_unit_system,
+            use_app_store_selector, use_app_store_selector_with_deps,
+        },
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum DiameterBound {
+        Min,
+        Max,
+    }
+
+    #[function_component]
+    pub fn IndexFanSizesPage() -> Html {
+        let units: UnitSystem = *use_app_store_selector(select_unit_system);
+        let query_state: UseStateHandle<ListQuery> = use_state(ListQuery::default);
+        let entered_min_diameter_state: UseStateHandle<String> = use_state(|| "".to_string());
+        let entered_max_diameter_state: UseStateHandle<String> = use_state(|| "".to_string());
+        let gettable = Gettable::FanSizesIndex {
+            query: (*query_state).clone(),
+        };
+        let (_state, dispatch) = use_store::<ApiStore>();
+        let maybe_page: Rc<Option<Page<FanSize<()>>>> =
+            use_app_store_selector_with_deps(select_fan_size_page, (*query_state).clone());
+        let fan_serieses: Rc<Vec<FanSeries<()>>> = use_app_store_selector(select_all_fan_series);
+        let request_status = use_selector_with_deps(
+            |store: &ApiStore, dep_gettable| {
+                store
+                    .get_status
+                    .get(dep_gettable)
+                    .cloned()
+                    .unwrap_or_default()
+            },
+            gettable.clone(),
+        );
+
+        // Changing a filter starts over from the first page
+        let on_search_change = {
+            let query_setter = query_state.setter();
+            use_callback(
+                move |(_index, search): ((), String), query: &ListQuery| {
+                    query_setter.set(ListQuery {
+                        search: Some(search),
+                        offset: None,
+                        ..query.clone()
+                    })
+                },
+                (*query_state).clone(),
+            )
+        };
+        let on_fan_series_select = {
+            let query_setter = query_state.setter();
+            use_callback(
+                move |fan_series: Option<FanSeries<()>>, query: &ListQuery| {
+                    query_setter.set(ListQuery {
+                        fan_series_id: fan_series.map(|fan_series| fan_series.id),
+                        offset: None,
+                        ..query.clone()
+                    })
+                },
+                (*query_state).clone(),
+            )
+        };
+        let on_diameter_change = {
+            let query_setter = query_state.setter();
+            let min_diameter_setter = entered_min_diameter_state.setter();
+            let max_diameter_setter = entered_max_diameter_state.setter();
+            use_callback(
+                move |(bound, entered): (DiameterBound, String), query: &ListQuery| {
+                    // Bad entries are shown as errors and leave the last good filter in place
+                    let parsed = parse_diameter(&entered);
+                    match bound {
+                        DiameterBound::Min => min_diameter_setter.set(entered),
+                        DiameterBound::Max => max_diameter_setter.set(entered),
+                    }
+                    if let Ok(diameter) = parsed {
+                        query_setter.set(match bound {
+                            DiameterBound::Min => ListQuery {
+                                min_diameter: diameter,
+                                offset: None,
+                                ..query.clone()
+                            },
+                            DiameterBound::Max => ListQuery {
+                                max_diameter: diameter,
+                                offset: None,
+                                ..query.clone()
+                            },
+                        })
+                    }
+                },
+                (*query_state).clone(),
+            )
+        };
+        let on_page = {
+            let query_setter = query_state.setter();
+            use_callback(
+                move |offset, query: &ListQuery| query_setter.set(query.with_offset(offset)),
+                (*query_state).clone(),
+            )
+        };
+
+        use_effect_with_deps(
+            move |dep_gettable| {
+                dispatch.apply(ApiRequestAction::Get(
+                    GetParameters {
+                        ignore_cache: false,
+                    },
+                    dep_gettable.clone(),
+                ));
+                || {}
+            },
+            gettable,
+        );
+
+        let picked_fan_series = query_state.fan_series_id.as_ref().and_then(|id| {
+            fan_serieses
+                .iter()
+                .find(|fan_series| &fan_series.id == id)
+                .cloned()
+        });
+        let min_diameter_errs = parse_diameter(&entered_min_diameter_state)
+            .err()
+            .unwrap_or_default();
+        let max_diameter_errs = parse_diameter(&entered_max_diameter_state)
+            .err()
+            .unwrap_or_default();
+
+        let results = match (request_status.as_ref(), maybe_page.as_ref()) {
+            (RequestStatuses::Error(_error_at, err), _) => {
+                html! { <p>{"Error: "}{err.to_string()}</p> }
+            }
+            (_, Some(page)) if page.items.is_empty() => {
+                html! { <p>{"No fan sizes match these filters"}</p> }
+            }
+            (_, Some(page)) => html! {
+                <>
+                    <table>
+                        <tr>
+                            <th>{"Fan Size"}</th>
+                            <th>{"Fan Series"}</th>
+                            <th>{format!("Diameter ({})", FanDiameter::symbol(units))}</th>
+                            <th>{format!("Outlet Area ({})", OutletArea::symbol(units))}</th>
+                        </tr>
+                        { page.items.iter().map(|fan_size| html! {
+                            <tr>
+                                <td>
+                                    <Link<Route> to={Route::GetFanSize { id: fan_size.id.clone() }}>
+                                        {fan_size.id.clone()}
+                                    </Link<Route>>
+                                </td>
+                                <td>
+                                    <Link<Route> to={Route::GetFanSeries { id: fan_size.fan_series_id.clone() }}>
+                                        {fan_size.fan_series_id.clone()}
+                                    </Link<Route>>
+                                </td>
+                                <td>{FanDiameter::from_inches(fan_size.diameter).measurement(units).value_string()}</td>
+                                <td>{OutletArea::from_square_inches(fan_size.outlet_area).measurement(units).value_string()}</td>
+                            </tr>
+                        }).collect::<Html>() }
+                    </table>
+                    <Pager offset={page.offset} limit={page.limit} total={page.total} on_page={on_page} />
+                </>
+            },
+            (_, None) => html! { <p>{"Loading..."}</p> },
+        };
+
+        html! {
+            <div>
+                <h1>
+                    {"Fan Sizes"}
+                    <Link<Route> to={Route::NewFanSize}>
+                        {'\u{2002}'} // En-space
+                        {"New"}
+                    </Link<Route>>
+                </h1>
+                <form>
+                    <div style="display: grid; grid-template-columns: auto auto; width: fit-content; column-gap: 8px; row-gap: 4px;">
+                        <label>{"Search"}</label>
+                        <TaggedInput<()>
+                            value={query_state.search.clone().unwrap_or_default()}
+                            tag={()}
+                            onchange={on_search_change}
+                        />
+                        <label>{"Fan Series"}</label>
+                        <FanSeriesPicker
+                            no_selection_label={"Any"}
+                            selection={picked_fan_series}
+                            on_select={on_fan_series_select}
+                        />
+                        <label>{"Min. Diameter (in)"}</label>
+                        <TaggedInput<DiameterBound>
+                            errs={Rc::new(min_diameter_errs)}
+                            value={(*entered_min_diameter_state).clone()}
+                            tag={DiameterBound::Min}
+                            onchange={on_diameter_change.clone()}
+                        />
+                        <label>{"Max. Diameter (in)"}</label>
+                        <TaggedInput<DiameterBound>
+                            errs={Rc::new(max_diameter_errs)}
+                            value={(*entered_max_diameter_state).clone()}
+                            tag={DiameterBound::Max}
+                            onchange={on_diameter_change}
+                        />
+                    </div>
+                </form>
+                {results}
+            </div>
+        }
+    }
+
+    // Leaving a bound empty removes it
+    fn parse_diameter(entered: &str) -> Result<Option<f64>, Vec<String>> {
+        if entered.trim().is_empty() {
+            return Ok(None);
+        }
+        match entered.trim().parse::<f64>() {
+            Ok(value) if value >= 0.0 => Ok(Some(value)),
+            Ok(_) => Err(vec!["The diameter can't be negative".to_string()]),
+            Err(_) => Err(vec!["You must enter a valid number".to_string()]),
+        }
+    }
+}
+pub use index::IndexFanSizesPage;
+
 pub mod read {
     use std::rc::Rc;
 
-    use loquat_common::models::{FanSeries, FanSize};
+    use loquat_common::{
+        calculations::units::{FanDiameter, OutletArea},
+        display::{Measure, UnitSystem},
+        models::{FanSeries, FanSize},
+    };
     use yew::prelude::*;
     use yew_router::prelude::Link;
     use yewdux::prelude::use_store;
@@ -9,8 +256,12 @@ pub mod read {
     use crate::api::store::Store as ApiStore;
     use crate::{
         api::store::{ApiRequestAction, GetParameters, Gettable},
+        features::a1_2010_report::components::A1FanSizeReports,
         route::Route,
-        store::{select_fan_size_by_id, use_app_store_selector_with_deps},
+        store::{
+            select_fan_size_by_id, select_unit_system, use_app_store_selector,
+            use_app_store_selector_with_deps,
+        },
     };
 
     #[derive(Properties, PartialEq)]
@@ -21,6 +272,7 @@ pub mod read {
     #[function_component]
     pub fn ReadFanSizePage(props: &ReadFanSizePageProps) -> Html {
         let (_state, dispatch) = use_store::<ApiStore>();
+        let units: UnitSystem = *use_app_store_selector(select_unit_system);
         let id = props.id.clone();
 
         let format_id = id.replace("%20", " ");
@@ -54,11 +306,23 @@ pub mod read {
                             {'\u{2002}'} // en-space
                         </Link<Route>>
                         { fan_size.id.to_owned() }
+                        <Link<Route> to={Route::EditFanSize { id: fan_size.id.clone() }}>
+                            {'\u{2002}'} // En-space
+                            {"Edit"}
+                        </Link<Route>>
+                        <Link<Route> to={Route::FanSizeRatingTable { id: fan_size.id.clone() }}>
+                            {'\u{2002}'} // En-space
+                            {"Rating Table"}
+                        </Link<Route>>
                     </h1>
                     <table>
                         <tr>
                             <td>{"Diameter: "}</td>
-                            <td> {fan_size.diameter}</td>
+                            <td> {FanDiameter::from_inches(fan_size.diameter).format(units)}</td>
+                        </tr>
+                        <tr>
+                            <td>{"Outlet Area: "}</td>
+                            <td> {OutletArea::from_square_inches(fan_size.outlet_area).format(units)}</td>
                         </tr>
                         <tr>
                             <td>{"Fan Type: "}</td>
@@ -73,7 +337,7 @@ pub mod read {
                             </td>
                         </tr>
                     </table>
-                    
+                    <A1FanSizeReports fan_size_id={fan_size.id.clone()} />
                     </div>
                 }
             }
@@ -82,3 +346,301 @@ pub mod read {
 }
 
 pub use read::ReadFanSizePage;
+
+pub mod rating_table {
+    use std::rc::Rc;
+
+    use loquat_common::{
+        api::rating_table::{RatingTableQuery, RatingTableResponse},
+        calculations::units::{BrakeHorsepower, InletAirflow, StaticPressure},
+        display::{FromMeasurement, Measure, UnitSystem},
+    };
+    use yew::prelude::*;
+    use yew_router::prelude::Link;
+    use yewdux::prelude::{use_selector_with_deps, use_store};
+
+    use crate::api::store::Store as ApiStore;
+    use crate::{
+        api::store::{ApiRequestAction, GetParameters, Gettable, RequestStatuses},
+        common::components::determination_table::TaggedInput,
+        route::Route,
+        store::{
+            select_rating_table, select_unit_system, use_app_store_selector,
+            use_app_store_selector_with_deps,
+        },
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Limit {
+        Rpm,
+        BrakeHorsepower,
+    }
+
+    #[derive(Properties, PartialEq)]
+    pub struct RatingTablePageProps {
+        pub id: String,
+    }
+
+    // Laid out to be printed for a catalogue submittal, the controls aren't printed
+    #[function_component]
+    pub fn RatingTablePage(RatingTablePageProps { id }: &RatingTablePageProps) -> Html {
+        let units: UnitSystem = *use_app_store_selector(select_unit_system);
+        let id = id.replace("%20", " ");
+        let query_state: UseStateHandle<RatingTableQuery> = use_state(RatingTableQuery::default);
+        // Limits are entered, and the table comes back, in the user's units
+        let query = RatingTableQuery {
+            units: Some(units),
+            ..(*query_state).clone()
+        };
+        let entered_max_rpm_state: UseStateHandle<String> = use_state(|| "".to_string());
+        let entered_max_brake_horsepower_state: UseStateHandle<String> =
+            use_state(|| "".to_string());
+        let gettable = Gettable::RatingTable {
+            id: id.clone(),
+            query: query.clone(),
+        };
+        let (_state, dispatch) = use_store::<ApiStore>();
+        let maybe_table: Rc<Option<RatingTableResponse>> =
+            use_app_store_selector_with_deps(select_rating_table, (id.clone(), query));
+        let request_status = use_selector_with_deps(
+            |store: &ApiStore, dep_gettable| {
+                store
+                    .get_status
+                    .get(dep_gettable)
+                    .cloned()
+                    .unwrap_or_default()
+            },
+            gettable.clone(),
+        );
+
+        let on_limit_change = {
+            let query_setter = query_state.setter();
+            let max_rpm_setter = entered_max_rpm_state.setter();
+            let max_brake_horsepower_setter = entered_max_brake_horsepower_state.setter();
+            use_callback(
+                move |(limit, entered): (Limit, String), query| {
+                    // Bad entries are shown as errors and leave the last good limit in place
+                    let parsed = parse_limit(&entered);
+                    match limit {
+                        Limit::Rpm => max_rpm_setter.set(entered),
+                        Limit::BrakeHorsepower => max_brake_horsepower_setter.set(entered),
+                    }
+                    if let Ok(value) = parsed {
+                        query_setter.set(match limit {
+                            Limit::Rpm => RatingTableQuery {
+                                max_rpm: value,
+                                ..query.clone()
+                            },
+                            Limit::BrakeHorsepower => RatingTableQuery {
+                                max_brake_horsepower: value,
+                                ..query.clone()
+                            },
+                        })
+                    }
+                },
+                (*query_state).clone(),
+            )
+        };
+        let on_print_click = use_callback(
+            |evt: MouseEvent, _| {
+                evt.prevent_default();
+                if let Some(window) = web_sys::window() {
+                    let _ = window.print();
+                }
+            },
+            (),
+        );
+
+        use_effect_with_deps(
+            move |dep_gettable| {
+                dispatch.apply(ApiRequestAction::Get(
+                    GetParameters {
+                        ignore_cache: false,
+                    },
+                    dep_gettable.clone(),
+                ));
+                || {}
+            },
+            gettable,
+        );
+
+        let max_rpm_errs = parse_limit(&entered_max_rpm_state).err().unwrap_or_default();
+        let max_brake_horsepower_errs = parse_limit(&entered_max_brake_horsepower_state)
+            .err()
+            .unwrap_or_default();
+
+        let table = match (request_status.as_ref(), maybe_table.as_ref()) {
+            (RequestStatuses::Error(_error_at, err), _) => {
+                html! { <p>{"Error: "}{err.to_string()}</p> }
+            }
+            (_, Some(table)) => html! {
+                <>
+                    <p>
+                        {"Rated from A1 report "}
+                        <Link<Route> to={Route::EditA1Report { id: table.a1_report_id.clone() }}>
+                            {table.a1_report_id.clone()}
+                        </Link<Route>>
+                        {", in standard air. Each cell is RPM over "}
+                        {BrakeHorsepower::symbol(units)}
+                        {"."}
+                    </p>
+                    <table class="rating-table">
+                        <tr>
+                            <th>{InletAirflow::symbol(units)}</th>
+                            { table.static_pressures.iter().map(|static_pressure| html! {
+                                <th>{StaticPressure::from_measurement(*static_pressure, table.units).format(units)}</th>
+                            }).collect::<Html>() }
+                        </tr>
+                        { table.cfms.iter().zip(table.cells.iter()).map(|(cfm, row)| html! {
+                            <tr>
+                                <th>{InletAirflow::from_measurement(*cfm, table.units).measurement(units).value_string()}</th>
+                                { row.iter().map(|cell| match cell {
+                                    Some(cell) => html! {
+                                        <td>
+                                            {format!("{:.0}", cell.rpm)}
+                                            <br />
+                                            {BrakeHorsepower::from_measurement(cell.brake_horsepower, table.units).measurement(units).value_string()}
+                                        </td>
+                                    },
+                                    None => html! { <td></td> },
+                                }).collect::<Html>() }
+                            </tr>
+                        }).collect::<Html>() }
+                    </table>
+                </>
+            },
+            (_, None) => html! { <p>{"Loading..."}</p> },
+        };
+
+        html! {
+            <div>
+                <style>
+                    {".rating-table th, .rating-table td { border: 1px solid #999; padding: 2px 6px; text-align: right; }
+                    .rating-table { border-collapse: collapse; }
+                    @media print { .no-print { display: none; } }"}
+                </style>
+                <h1>
+                    <Link<Route> to={Route::GetFanSize { id: id.clone() }}>
+                        {'\u{2b05}'} // Fat Left Arrow
+                        {'\u{2002}'} // en-space
+                    </Link<Route>>
+                    {id.clone()}{" Rating Table"}
+                </h1>
+                <form class="no-print">
+                    <div style="display: grid; grid-template-columns: auto auto; width: fit-content; column-gap: 8px; row-gap: 4px;">
+                        <label>{"Max. Speed (RPM)"}</label>
+                        <TaggedInput<Limit>
+                            errs={Rc::new(max_rpm_errs)}
+                            value={(*entered_max_rpm_state).clone()}
+                            tag={Limit::Rpm}
+                            onchange={on_limit_change.clone()}
+                        />
+                        <label>{format!("Max. Brake Horsepower ({})", BrakeHorsepower::symbol(units))}</label>
+                        <TaggedInput<Limit>
+                            errs={Rc::new(max_brake_horsepower_errs)}
+                            value={(*entered_max_brake_horsepower_state).clone()}
+                            tag={Limit::BrakeHorsepower}
+                            onchange={on_limit_change}
+                        />
+                    </div>
+                    <button onclick={on_print_click}>{"Print"}</button>
+                </form>
+                {table}
+            </div>
+        }
+    }
+
+    // Leaving a limit empty removes it
+    fn parse_limit(entered: &str) -> Result<Option<f64>, Vec<String>> {
+        if entered.trim().is_empty() {
+            return Ok(None);
+        }
+        match entered.trim().parse::<f64>() {
+            Ok(value) if value > 0.0 => Ok(Some(value)),
+            Ok(_) => Err(vec!["The limit must be positive".to_string()]),
+            Err(_) => Err(vec!["You must enter a valid number".to_string()]),
+        }
+    }
+}
+
+pub use rating_table::RatingTablePage;
+
+pub mod new {
+    use yew::prelude::*;
+
+    use crate::features::fan_size::{
+        components::FanSizeForm,
+        hooks::{use_fan_size_form_controller, FanSizeFormHookRes},
+    };
+
+    #[function_component]
+    pub fn NewFanSizePage() -> Html {
+        let FanSizeFormHookRes {
+            maybe_fan_size,
+            on_valid_entry,
+            on_submit_click,
+        } = use_fan_size_form_controller(None);
+
+        html! {
+            <>
+                <h1>{"New Fan Size"}</h1>
+                <FanSizeForm
+                    {maybe_fan_size}
+                    {on_valid_entry}
+                    {on_submit_click}
+                />
+            </>
+        }
+    }
+}
+
+pub use new::NewFanSizePage;
+
+pub mod edit {
+    use yew::prelude::*;
+    use yew_router::prelude::Link;
+
+    use crate::{
+        features::fan_size::{
+            components::FanSizeForm,
+            hooks::{use_fan_size_form_controller, FanSizeFormHookRes},
+        },
+        route::Route,
+    };
+
+    #[derive(Properties, PartialEq)]
+    pub struct EditFanSizePageProps {
+        pub id: String,
+    }
+
+    #[function_component]
+    pub fn EditFanSizePage(EditFanSizePageProps { id }: &EditFanSizePageProps) -> Html {
+        let fan_size_id = id.replace("%20", " ");
+
+        let FanSizeFormHookRes {
+            maybe_fan_size,
+            on_valid_entry,
+            on_submit_click,
+        } = use_fan_size_form_controller(Some(fan_size_id.clone()));
+
+        html! {
+            <>
+                <h1>
+                    <Link<Route> to={Route::GetFanSize { id: id.clone() }}>
+                        {'\u{2b05}'} // Fat Left Arrow
+                        {'\u{2002}'} // en-space
+                    </Link<Route>>
+                    {format!("Fan Size {}", fan_size_id)}
+                </h1>
+                <FanSizeForm
+                    fan_size_id={fan_size_id}
+                    {maybe_fan_size}
+                    {on_valid_entry}
+                    {on_submit_click}
+                />
+            </>
+        }
+    }
+}
+
+pub use edit::EditFanSizePage;
@@ -1,13 +1,32 @@
 use std::{collections::HashMap, rc::Rc};
 
-use loquat_common::models::FanSize;
+use loquat_common::{
+    api::{
+        rating_table::{RatingTableQuery, RatingTableResponse},
+        ListQuery, Page,
+    },
+    models::FanSize,
+};
 use yewdux::{prelude, store::Reducer};
 
 use crate::api::store::ApiResponseAction;
 
-#[derive(Debug, Default, Clone, PartialEq, Eq, prelude::Store)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct Store {
     pub fan_sizes: HashMap<String, FanSize<()>>,
+    // The ids on each page of the index, in order
+    pub pages: HashMap<ListQuery, Page<String>>,
+    pub rating_tables: HashMap<(String, RatingTableQuery), RatingTableResponse>,
+}
+
+impl prelude::Store for Store {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn should_notify(&self, old: &Self) -> bool {
+        !self.eq(old)
+    }
 }
 
 impl Reducer<Store> for ApiResponseAction {
@@ -31,10 +50,42 @@ impl Reducer<Store> for ApiResponseAction {
                 state.fan_sizes.insert(size.id.clone(), size);
                 og_state
             }
-            ApiResponseAction::RecieveFanSizes(fan_sizes) => {
-                for fan_size in fan_sizes {
+            ApiResponseAction::RecieveA2Report(a2_report) => {
+                let (_a2_report, a1_report, induced_flow_fan_size) = a2_report.into();
+                let (a1_size, _series) = a1_report.fan_size.into();
+                state.fan_sizes.insert(a1_size.id.clone(), a1_size);
+                let (induced_size, _series) = induced_flow_fan_size.fan_size.into();
+                state
+                    .fan_sizes
+                    .insert(induced_size.id.clone(), induced_size);
+                og_state
+            }
+            ApiResponseAction::RecieveFanSizes(query, page) => {
+                let Page {
+                    items,
+                    offset,
+                    limit,
+                    total,
+                } = page;
+                let ids = items.iter().map(|fan_size| fan_size.id.clone()).collect();
+                for fan_size in items {
                     state.fan_sizes.insert(fan_size.id.clone(), fan_size);
                 }
+                state.pages.insert(
+                    query,
+                    Page {
+                        items: ids,
+                        offset,
+                        limit,
+                        total,
+                    },
+                );
+                og_state
+            }
+            ApiResponseAction::RecieveRatingTable(id, query, table) => {
+                let (size, _series) = table.fan_size.clone().into();
+                state.fan_sizes.insert(size.id.clone(), size);
+                state.rating_tables.insert((id, query), table);
                 og_state
             }
             _ => og_state,
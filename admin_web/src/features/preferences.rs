@@ -0,0 +1,4 @@
+mod components;
+pub use components::UnitSystemPicker;
+mod store;
+pub use store::Store;
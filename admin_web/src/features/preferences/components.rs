@@ -0,0 +1,2 @@
+mod unit_system_picker;
+pub use unit_system_picker::UnitSystemPicker;
@@ -0,0 +1,59 @@
+use loquat_common::{api::user::PreferencesBody, display::UnitSystem};
+use web_sys::HtmlSelectElement;
+use yew::{function_component, html, use_callback, use_effect_with_deps, Html, TargetCast};
+use yewdux::prelude::use_store;
+
+use crate::api::store::Store as ApiStore;
+use crate::api::store::{ApiRequestAction, GetParameters, Gettable};
+use crate::store::{select_unit_system, use_app_store_selector};
+
+// Saved to the signed in user, so every page shows values in the units they spec in
+#[function_component]
+pub fn UnitSystemPicker() -> Html {
+    let (_state, dispatch) = use_store::<ApiStore>();
+    let unit_system = use_app_store_selector(select_unit_system);
+
+    {
+        let dispatch = dispatch.clone();
+        use_effect_with_deps(
+            move |_| {
+                dispatch.apply(ApiRequestAction::Get(
+                    GetParameters {
+                        ignore_cache: false,
+                    },
+                    Gettable::CurrentUser,
+                ));
+                || {}
+            },
+            (),
+        );
+    }
+
+    let on_change = use_callback(
+        |evt: web_sys::Event, dispatch| {
+            let value = evt.target_unchecked_into::<HtmlSelectElement>().value();
+            if let Ok(unit_system) = UnitSystem::try_from(&value[..]) {
+                dispatch.apply(ApiRequestAction::Get(
+                    GetParameters { ignore_cache: true },
+                    Gettable::PutPreferences {
+                        body: PreferencesBody { unit_system },
+                    },
+                ));
+            }
+        },
+        dispatch,
+    );
+
+    html! {
+      <label>
+        {"Units "}
+        <select onchange={on_change}>
+          {UnitSystem::all_options().into_iter().map(|option| html! {
+            <option value={option.as_str()} selected={option == *unit_system}>
+              {option.label()}
+            </option>
+          }).collect::<Html>()}
+        </select>
+      </label>
+    }
+}
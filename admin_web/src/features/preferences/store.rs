@@ -0,0 +1,25 @@
+use std::rc::Rc;
+
+use loquat_common::models::User;
+use yewdux::{prelude, store::Reducer};
+
+use crate::api::store::ApiResponseAction;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, prelude::Store)]
+pub struct Store {
+    pub current_user: Option<User>,
+}
+
+impl Reducer<Store> for ApiResponseAction {
+    fn apply(self, mut og_state: Rc<Store>) -> Rc<Store> {
+        let state: &mut Store = Rc::make_mut(&mut og_state);
+
+        match self {
+            ApiResponseAction::RecieveCurrentUser(user) => {
+                state.current_user = Some(user);
+                og_state
+            }
+            _ => og_state,
+        }
+    }
+}
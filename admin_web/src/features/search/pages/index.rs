@@ -0,0 +1,129 @@
+use std::ops::Deref;
+use std::rc::Rc;
+
+use loquat_common::api::{
+    search::{SearchResult, SearchResultKind},
+    ListQuery,
+};
+use yew::prelude::*;
+use yew_router::prelude::Link;
+use yewdux::prelude::{use_selector_with_deps, use_store};
+
+use crate::{
+    api::store::{ApiRequestAction, GetParameters, Gettable, RequestStatuses, Store as ApiStore},
+    common::components::determination_table::TaggedInput,
+    route::Route,
+    store::{select_search_results, use_app_store_selector_with_deps},
+};
+
+#[function_component]
+pub fn IndexSearchPage() -> Html {
+    let (_state, dispatch) = use_store::<ApiStore>();
+
+    let entered_search_state: UseStateHandle<String> = use_state(|| "".to_string());
+    let searched_query_state: UseStateHandle<Option<ListQuery>> = use_state(|| None);
+
+    let results: Rc<Option<Vec<SearchResult>>> = use_app_store_selector_with_deps(
+        select_search_results,
+        searched_query_state.deref().clone(),
+    );
+
+    let request_status = use_selector_with_deps(
+        |store: &ApiStore, maybe_query| {
+            maybe_query
+                .as_ref()
+                .and_then(|query: &ListQuery| {
+                    store
+                        .get_status
+                        .get(&Gettable::Search {
+                            query: query.clone(),
+                        })
+                        .cloned()
+                })
+                .unwrap_or_default()
+        },
+        searched_query_state.deref().clone(),
+    );
+
+    let on_search_change = {
+        let entered_search_setter = entered_search_state.setter();
+        use_callback(
+            move |(_index, search), _deps| entered_search_setter.set(search),
+            (),
+        )
+    };
+
+    let on_search_click = {
+        let searched_query_setter = searched_query_state.setter();
+        use_callback(
+            move |evt: MouseEvent, (dispatch, entered_search)| {
+                evt.prevent_default();
+                let query = ListQuery {
+                    search: Some(entered_search.clone()),
+                    ..Default::default()
+                };
+                searched_query_setter.set(Some(query.clone()));
+                dispatch.apply(ApiRequestAction::Get(
+                    GetParameters {
+                        ignore_cache: false,
+                    },
+                    Gettable::Search { query },
+                ));
+            },
+            (dispatch, entered_search_state.deref().clone()),
+        )
+    };
+
+    let results = match (request_status.as_ref(), results.as_ref()) {
+        (RequestStatuses::Error(_error_at, err), _) => html! { <>{"Error: "}{err.to_string()}</> },
+        (RequestStatuses::Unfetched, _) => {
+            html! { {"Search for a fan series, fan size or report by its id"} }
+        }
+        (_, Some(results)) if results.is_empty() => html! { {"Nothing matches this search"} },
+        (_, Some(results)) => html! {
+            <table>
+                <tr>
+                    <th>{"Kind"}</th>
+                    <th>{"Id"}</th>
+                </tr>
+                { results.iter().map(|result| html! {
+                    <tr>
+                        <td>{result.kind.label()}</td>
+                        <td>
+                            <Link<Route> to={result_route(result)}>{result.id.clone()}</Link<Route>>
+                        </td>
+                    </tr>
+                }).collect::<Html>() }
+            </table>
+        },
+        (_, None) => html! { {"Loading..."} },
+    };
+
+    html! {
+        <div>
+            <h1>{"Search"}</h1>
+            <form>
+                <div style="display: grid; grid-template-columns: auto auto; width: fit-content; column-gap: 8px; row-gap: 4px;">
+                    <label>{"Id"}</label>
+                    <TaggedInput<()>
+                        value={entered_search_state.deref().clone()}
+                        tag={()}
+                        onchange={on_search_change}
+                    />
+                </div>
+                <button onclick={on_search_click}>{"Search"}</button>
+            </form>
+            {results}
+        </div>
+    }
+}
+
+fn result_route(SearchResult { kind, id }: &SearchResult) -> Route {
+    let id = id.clone();
+    match kind {
+        SearchResultKind::FanSeries => Route::GetFanSeries { id },
+        SearchResultKind::FanSize => Route::GetFanSize { id },
+        SearchResultKind::A1Report => Route::EditA1Report { id },
+        SearchResultKind::A2Report => Route::EditA2Report { id },
+    }
+}
@@ -0,0 +1,25 @@
+use std::{collections::HashMap, rc::Rc};
+
+use loquat_common::api::{search::SearchResult, ListQuery};
+use yewdux::{prelude, store::Reducer};
+
+use crate::api::store::ApiResponseAction;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, prelude::Store)]
+pub struct Store {
+    pub results: HashMap<ListQuery, Vec<SearchResult>>,
+}
+
+impl Reducer<Store> for ApiResponseAction {
+    fn apply(self, mut og_state: Rc<Store>) -> Rc<Store> {
+        let state: &mut Store = Rc::make_mut(&mut og_state);
+
+        match self {
+            ApiResponseAction::RecieveSearchResults(query, results) => {
+                state.results.insert(query, results);
+                og_state
+            }
+            _ => og_state,
+        }
+    }
+}
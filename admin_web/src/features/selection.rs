@@ -0,0 +1,3 @@
+pub mod pages;
+mod store;
+pub use store::Store;
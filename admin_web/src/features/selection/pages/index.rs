@@ -0,0 +1,287 @@
+use std::ops::Deref;
+use std::rc::Rc;
+
+use loquat_common::{
+    api::{
+        a1_2010_report::PdfQuery,
+        selection::{IndexQuery, Selection},
+    },
+    calculations::{
+        fitting::FitStrategy,
+        units::{BrakeHorsepower, InletAirflow, StaticPressure},
+    },
+    display::{FromMeasurement, Measure, UnitSystem},
+};
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+use yew_router::prelude::Link;
+use yewdux::prelude::{use_selector_with_deps, use_store};
+
+use crate::{
+    api::{
+        a1_report,
+        store::{ApiRequestAction, GetParameters, Gettable, RequestStatuses, Store as ApiStore},
+    },
+    common::components::determination_table::TaggedInput,
+    route::Route,
+    store::{
+        select_selections, select_unit_system, use_app_store_selector,
+        use_app_store_selector_with_deps,
+    },
+};
+
+#[derive(Debug, Clone, PartialEq)]
+struct QueryErrors {
+    cfm_errs: Vec<String>,
+    static_pressure_errs: Vec<String>,
+}
+
+#[function_component]
+pub fn IndexSelectionPage() -> Html {
+    let (_state, dispatch) = use_store::<ApiStore>();
+    let units: UnitSystem = *use_app_store_selector(select_unit_system);
+
+    let entered_cfm_state: UseStateHandle<String> = use_state(|| "".to_string());
+    let entered_static_pressure_state: UseStateHandle<String> = use_state(|| "".to_string());
+    let fit_state: UseStateHandle<FitStrategy> = use_state(FitStrategy::default);
+    let searched_query_state: UseStateHandle<Option<IndexQuery>> = use_state(|| None);
+
+    let parsed_cfm: Rc<Result<f64, Vec<String>>> = use_memo(
+        |entered_cfm: &String| parse_positive(entered_cfm, "airflow"),
+        entered_cfm_state.deref().clone(),
+    );
+    let parsed_static_pressure: Rc<Result<f64, Vec<String>>> = use_memo(
+        |entered_static_pressure: &String| {
+            parse_positive(entered_static_pressure, "static pressure")
+        },
+        entered_static_pressure_state.deref().clone(),
+    );
+
+    // Duty points are searched in the units they were entered in
+    let parsed_query: Rc<Result<IndexQuery, QueryErrors>> = use_memo(
+        |(parsed_cfm, parsed_static_pressure, units, fit)| {
+            if let (Ok(airflow), Ok(static_pressure)) =
+                (parsed_cfm.as_ref(), parsed_static_pressure.as_ref())
+            {
+                Ok(IndexQuery {
+                    cfm: *airflow,
+                    static_pressure: *static_pressure,
+                    fit: *fit,
+                    units: *units,
+                })
+            } else {
+                Err(QueryErrors {
+                    cfm_errs: parsed_cfm.as_ref().clone().err().unwrap_or_default(),
+                    static_pressure_errs: parsed_static_pressure
+                        .as_ref()
+                        .clone()
+                        .err()
+                        .unwrap_or_default(),
+                })
+            }
+        },
+        (
+            Rc::clone(&parsed_cfm),
+            Rc::clone(&parsed_static_pressure),
+            units,
+            *fit_state,
+        ),
+    );
+
+    let selections: Rc<Option<Vec<Selection>>> =
+        use_app_store_selector_with_deps(select_selections, searched_query_state.deref().clone());
+
+    let request_status = use_selector_with_deps(
+        |store: &ApiStore, maybe_query| {
+            maybe_query
+                .as_ref()
+                .and_then(|query: &IndexQuery| {
+                    store
+                        .get_status
+                        .get(&Gettable::Selections {
+                            query: query.clone(),
+                        })
+                        .cloned()
+                })
+                .unwrap_or_default()
+        },
+        searched_query_state.deref().clone(),
+    );
+
+    let on_cfm_change = {
+        let entered_cfm_setter = entered_cfm_state.setter();
+        use_callback(move |(_index, cfm), _deps| entered_cfm_setter.set(cfm), ())
+    };
+
+    let on_static_pressure_change = {
+        let entered_static_pressure_setter = entered_static_pressure_state.setter();
+        use_callback(
+            move |(_index, static_pressure), _deps| {
+                entered_static_pressure_setter.set(static_pressure)
+            },
+            (),
+        )
+    };
+
+    let on_fit_change = {
+        let fit_setter = fit_state.setter();
+        use_callback(
+            move |evt: Event, _deps| {
+                let value = evt.target_unchecked_into::<HtmlSelectElement>().value();
+                if let Ok(fit) = FitStrategy::try_from(&value[..]) {
+                    fit_setter.set(fit);
+                }
+            },
+            (),
+        )
+    };
+
+    let on_search_click = {
+        let searched_query_setter = searched_query_state.setter();
+        use_callback(
+            move |evt: MouseEvent, (dispatch, parsed_query)| {
+                evt.prevent_default();
+                if let Ok(query) = parsed_query.as_ref() {
+                    searched_query_setter.set(Some(query.clone()));
+                    dispatch.apply(ApiRequestAction::Get(
+                        GetParameters {
+                            ignore_cache: false,
+                        },
+                        Gettable::Selections {
+                            query: query.clone(),
+                        },
+                    ));
+                }
+            },
+            (dispatch, Rc::clone(&parsed_query)),
+        )
+    };
+
+    let QueryErrors {
+        cfm_errs,
+        static_pressure_errs,
+    } = match parsed_query.as_ref() {
+        Ok(_) => QueryErrors {
+            cfm_errs: vec![],
+            static_pressure_errs: vec![],
+        },
+        Err(errs) => errs.clone(),
+    };
+
+    let results = match (request_status.as_ref(), selections.as_ref()) {
+        (RequestStatuses::Error(_error_at, err), _) => html! { <>{"Error: "}{err.to_string()}</> },
+        (RequestStatuses::Unfetched, _) => html! { {"Enter a duty point to search"} },
+        (_, Some(selections)) if selections.is_empty() => {
+            html! { {"No fans in the catalogue can meet this duty point"} }
+        }
+        (_, Some(selections)) => html! {
+            <table>
+                <tr>
+                    <th>{"Fan Size"}</th>
+                    <th>{"Fan Series"}</th>
+                    <th>{"Speed (RPM)"}</th>
+                    <th>{format!("Brake Horsepower ({})", BrakeHorsepower::symbol(units))}</th>
+                    <th>{"Static Pressure Margin"}</th>
+                    <th>{"Rated From"}</th>
+                    <th>{"Performance"}</th>
+                </tr>
+                { selections.iter().map(|selection| html! {
+                    <tr>
+                        <td>
+                            <Link<Route> to={Route::GetFanSize { id: selection.fan_size.id.clone() }}>
+                                {selection.fan_size.id.clone()}
+                            </Link<Route>>
+                        </td>
+                        <td>{selection.fan_size.fan_series_id.clone()}</td>
+                        <td>{format!("{:.0}", selection.rpm)}</td>
+                        <td>
+                            { searched_query_state.deref().as_ref().map(|query| {
+                                BrakeHorsepower::from_measurement(selection.brake_horsepower, query.units)
+                                    .measurement(units)
+                                    .value_string()
+                            }) }
+                        </td>
+                        <td>{format!("{:.1}%", selection.static_pressure_margin * 100.0)}</td>
+                        <td>
+                            <Link<Route> to={Route::EditA1Report { id: selection.a1_report_id.clone() }}>
+                                {selection.a1_report_id.clone()}
+                            </Link<Route>>
+                        </td>
+                        <td>
+                            { searched_query_state.deref().as_ref().map(|query| html! {
+                                <a
+                                    href={a1_report::pdf_url(&selection.a1_report_id, &PdfQuery {
+                                        fan_size_id: Some(selection.fan_size.id.clone()),
+                                        cfm: Some(query.cfm),
+                                        static_pressure: Some(query.static_pressure),
+                                        fit: Some(query.fit),
+                                        units: Some(query.units),
+                                    })}
+                                    target="_blank"
+                                >
+                                    {"PDF"}
+                                </a>
+                            }) }
+                        </td>
+                    </tr>
+                }).collect::<Vec<_>>() }
+            </table>
+        },
+        (_, None) => html! { {"Loading..."} },
+    };
+
+    html! {
+        <div>
+            <h1>{"Fan Selection"}</h1>
+            <form>
+                <div style="display: grid; grid-template-columns: auto auto; width: fit-content; column-gap: 8px; row-gap: 4px;">
+                    <label>{format!("Airflow ({})", InletAirflow::symbol(units))}</label>
+                    <TaggedInput<()>
+                        errs={Rc::new(cfm_errs)}
+                        value={(*entered_cfm_state).clone()}
+                        tag={()}
+                        onchange={on_cfm_change}
+                    />
+                    <label>{format!("Static Pressure ({})", StaticPressure::symbol(units))}</label>
+                    <TaggedInput<()>
+                        errs={Rc::new(static_pressure_errs)}
+                        value={(*entered_static_pressure_state).clone()}
+                        tag={()}
+                        onchange={on_static_pressure_change}
+                    />
+                    <label>{"Curve Fit"}</label>
+                    <select onchange={on_fit_change}>
+                        {FitStrategy::all_options().into_iter().map(|option| html! {
+                            <option value={option.as_str()} selected={option == *fit_state}>
+                                {option.label()}
+                            </option>
+                        }).collect::<Html>()}
+                    </select>
+                </div>
+                <button onclick={on_search_click}>
+                    {"Search"}
+                </button>
+            </form>
+            {results}
+        </div>
+    }
+}
+
+fn parse_positive(entered: &str, quantity: &str) -> Result<f64, Vec<String>> {
+    match entered.parse::<f64>() {
+        Ok(value) => {
+            if value <= 0.0 {
+                Err(vec![format!("The {} must be positive", quantity)])
+            } else {
+                Ok(value)
+            }
+        }
+        Err(_) => {
+            if entered.is_empty() {
+                Err(vec![format!("You must enter the required {}", quantity)])
+            } else {
+                Err(vec!["You must enter a valid number".to_string()])
+            }
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::{collections::HashMap, rc::Rc};
+
+use loquat_common::api::selection::{IndexQuery, Selection};
+use yewdux::{prelude, store::Reducer};
+
+use crate::api::store::ApiResponseAction;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Store {
+    pub selections: HashMap<IndexQuery, Vec<Selection>>,
+}
+
+impl prelude::Store for Store {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn should_notify(&self, old: &Self) -> bool {
+        !self.eq(old)
+    }
+}
+
+impl Reducer<Store> for ApiResponseAction {
+    fn apply(self, mut og_state: Rc<Store>) -> Rc<Store> {
+        let state: &mut Store = Rc::make_mut(&mut og_state);
+
+        match self {
+            ApiResponseAction::RecieveSelections(query, selections) => {
+                state.selections.insert(query, selections);
+                og_state
+            }
+            _ => og_state,
+        }
+    }
+}
@@ -4,8 +4,12 @@ use yew_router::prelude::*;
 use loquat_admin_web::{
     features::fan_series::pages::{IndexFanSeriesPage, ReadFanSeriesPage},
     features::{
-        a1_2010_report::pages::{EditA1Page, NewA1Page},
-        fan_size::pages::ReadFanSizePage, fan_series::pages::{NewFanSeriesPage, EditFanSeriesPage},
+        a1_2010_report::pages::{EditA1Page, IndexA1Page, NewA1Page},
+        a2_2010_report::pages::{EditA2Page, NewA2Page},
+        preferences::UnitSystemPicker,
+        selection::pages::IndexSelectionPage,
+        search::pages::IndexSearchPage,
+        fan_size::pages::{EditFanSizePage, IndexFanSizesPage, NewFanSizePage, RatingTablePage, ReadFanSizePage}, fan_series::pages::{NewFanSeriesPage, EditFanSeriesPage},
     },
     route::Route,
 };
@@ -17,9 +21,18 @@ fn switch(routes: Route) -> Html {
         Route::NewFanSeriesPage => html! { <NewFanSeriesPage /> },
         Route::GetFanSeries { id } => html! { <ReadFanSeriesPage id={id} /> },
         Route::EditFanSeries { id } => html! { <EditFanSeriesPage id={id} /> },
+        Route::IndexFanSizes => html! { <IndexFanSizesPage /> },
+        Route::NewFanSize => html! { <NewFanSizePage /> },
         Route::GetFanSize { id } => html! { <ReadFanSizePage id={id} /> },
+        Route::EditFanSize { id } => html! { <EditFanSizePage id={id} /> },
+        Route::FanSizeRatingTable { id } => html! { <RatingTablePage id={id} /> },
+        Route::IndexA1Reports => html! { <IndexA1Page /> },
         Route::EditA1Report { id } => html! { <EditA1Page id={id} /> },
         Route::NewA1Report => html! { <NewA1Page /> },
+        Route::EditA2Report { id } => html! { <EditA2Page id={id} /> },
+        Route::NewA2Report => html! { <NewA2Page /> },
+        Route::Search => html! { <IndexSearchPage /> },
+        Route::Selections => html! { <IndexSelectionPage /> },
     }
 }
 
@@ -27,6 +40,7 @@ fn switch(routes: Route) -> Html {
 fn app() -> Html {
     html! {
         <BrowserRouter>
+            <UnitSystemPicker />
             <Switch<Route> render={switch} />
         </BrowserRouter>
     }
@@ -14,10 +14,31 @@ pub enum Route {
     #[at("/fan_series/:id/edit")]
     EditFanSeries { id: String },
     // Fan Sizes
+    #[at("/fan_size")]
+    IndexFanSizes,
+    #[at("/fan_size/new")]
+    NewFanSize,
     #[at("/fan_size/:id")]
     GetFanSize { id: String },
+    #[at("/fan_size/:id/edit")]
+    EditFanSize { id: String },
+    #[at("/fan_size/:id/rating_table")]
+    FanSizeRatingTable { id: String },
+    // A1 Reports
+    #[at("/a1_report")]
+    IndexA1Reports,
     #[at("/a1_report/new")]
     NewA1Report,
     #[at("/a1_report/:id/edit")]
     EditA1Report { id: String },
+    #[at("/a2_report/new")]
+    NewA2Report,
+    #[at("/a2_report/:id/edit")]
+    EditA2Report { id: String },
+    // Search
+    #[at("/search")]
+    Search,
+    // Selection
+    #[at("/selections")]
+    Selections,
 }
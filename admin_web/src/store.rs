@@ -1,6 +1,16 @@
 use std::rc::Rc;
 
-use loquat_common::models::{A1Standard2010Report, FanSeries, FanSize};
+use loquat_common::{
+    api::{
+        a1_2010_report::{ReportSummary, Revision},
+        a2_2010_report,
+        rating_table::{RatingTableQuery, RatingTableResponse},
+        search::SearchResult,
+        selection, ListQuery, Page,
+    },
+    display::UnitSystem,
+    models::{A1Standard2010Report, FanSeries, FanSize, InducedFlowFanSize},
+};
 use yew::use_memo;
 use yewdux::prelude::{use_store, Dispatch};
 
@@ -11,20 +21,32 @@ use crate::api::store::ApiResponseAction;
 pub struct AppStore {
     api: Rc<crate::api::store::Store>,
     a1_report: Rc<crate::features::a1_2010_report::Store>,
+    a2_report: Rc<crate::features::a2_2010_report::Store>,
     fan_series: Rc<crate::features::fan_series::Store>,
     fan_size: Rc<crate::features::fan_size::Store>,
+    preferences: Rc<crate::features::preferences::Store>,
+    search: Rc<crate::features::search::Store>,
+    selection: Rc<crate::features::selection::Store>,
 }
 
 pub fn app_dispatch(action: ApiResponseAction) {
     log::info!("{:#?}", action);
 
     let a1_dispatch = Dispatch::<crate::features::a1_2010_report::Store>::new();
+    let a2_dispatch = Dispatch::<crate::features::a2_2010_report::Store>::new();
     let fan_series_dispatch = Dispatch::<crate::features::fan_series::Store>::new();
     let fan_size_dispatch = Dispatch::<crate::features::fan_size::Store>::new();
+    let preferences_dispatch = Dispatch::<crate::features::preferences::Store>::new();
+    let search_dispatch = Dispatch::<crate::features::search::Store>::new();
+    let selection_dispatch = Dispatch::<crate::features::selection::Store>::new();
 
     a1_dispatch.apply(action.clone());
+    a2_dispatch.apply(action.clone());
     fan_series_dispatch.apply(action.clone());
-    fan_size_dispatch.apply(action);
+    fan_size_dispatch.apply(action.clone());
+    preferences_dispatch.apply(action.clone());
+    search_dispatch.apply(action.clone());
+    selection_dispatch.apply(action);
 }
 
 #[yew::hook]
@@ -36,14 +58,22 @@ where
 {
     let (api_store, _) = use_store::<crate::api::store::Store>();
     let (a1_store, _) = use_store::<crate::features::a1_2010_report::Store>();
+    let (a2_store, _) = use_store::<crate::features::a2_2010_report::Store>();
     let (fan_series, _) = use_store::<crate::features::fan_series::Store>();
     let (fan_size, _) = use_store::<crate::features::fan_size::Store>();
+    let (preferences, _) = use_store::<crate::features::preferences::Store>();
+    let (search, _) = use_store::<crate::features::search::Store>();
+    let (selection, _) = use_store::<crate::features::selection::Store>();
 
     let store = AppStore {
         api: Rc::clone(&api_store),
         a1_report: Rc::clone(&a1_store),
+        a2_report: Rc::clone(&a2_store),
         fan_series: Rc::clone(&fan_series),
         fan_size: Rc::clone(&fan_size),
+        preferences: Rc::clone(&preferences),
+        search: Rc::clone(&search),
+        selection: Rc::clone(&selection),
     };
     use_memo(|(store, deps)| func(store, deps), (store, deps))
 }
@@ -96,6 +126,47 @@ pub fn select_all_fan_series(state: &AppStore) -> Vec<FanSeries<()>> {
         .collect()
 }
 
+pub fn select_fan_series_page(state: &AppStore, query: &ListQuery) -> Option<Page<FanSeries<()>>> {
+    let page = state.fan_series.pages.get(query)?;
+    let items = page
+        .items
+        .iter()
+        .map(|id| state.fan_series.fan_serieses.get(id).cloned())
+        .collect::<Option<Vec<_>>>()?;
+    Some(Page {
+        items,
+        offset: page.offset,
+        limit: page.limit,
+        total: page.total,
+    })
+}
+
+pub fn select_fan_size_page(state: &AppStore, query: &ListQuery) -> Option<Page<FanSize<()>>> {
+    let page = state.fan_size.pages.get(query)?;
+    let items = page
+        .items
+        .iter()
+        .map(|id| state.fan_size.fan_sizes.get(id).cloned())
+        .collect::<Option<Vec<_>>>()?;
+    Some(Page {
+        items,
+        offset: page.offset,
+        limit: page.limit,
+        total: page.total,
+    })
+}
+
+pub fn select_rating_table(
+    state: &AppStore,
+    (id, query): &(String, RatingTableQuery),
+) -> Option<RatingTableResponse> {
+    state
+        .fan_size
+        .rating_tables
+        .get(&(id.clone(), query.clone()))
+        .cloned()
+}
+
 pub fn select_a1_report(
     state: &AppStore,
     maybe_id: &Option<String>,
@@ -111,3 +182,77 @@ pub fn select_a1_report(
     }
     None
 }
+
+pub fn select_a1_report_page(state: &AppStore, query: &ListQuery) -> Option<Page<ReportSummary>> {
+    state.a1_report.pages.get(query).cloned()
+}
+
+pub fn select_a1_report_revisions(
+    state: &AppStore,
+    maybe_id: &Option<String>,
+) -> Option<Vec<Revision>> {
+    state.a1_report.revisions.get(maybe_id.as_ref()?).cloned()
+}
+
+pub fn select_a2_report(
+    state: &AppStore,
+    maybe_id: &Option<String>,
+) -> Option<a2_2010_report::GetResponse> {
+    let report = state.a2_report.reports.get(maybe_id.as_ref()?)?.clone();
+    let a1_report = select_a1_report(state, &Some(report.a1_report_id.clone()))?;
+    let InducedFlowFanSize {
+        id,
+        fan_size_id,
+        fan_size: _,
+        nozzle_id,
+        nozzle,
+    } = state
+        .a2_report
+        .induced_flow_fan_sizes
+        .get(&report.induced_flow_fan_size_id)?
+        .clone();
+    let fan_size = select_fan_size_by_id(state, &fan_size_id)?;
+
+    Some(
+        (
+            report,
+            a1_report,
+            InducedFlowFanSize {
+                id,
+                fan_size_id,
+                fan_size,
+                nozzle_id,
+                nozzle,
+            },
+        )
+            .into(),
+    )
+}
+
+pub fn select_selections(
+    state: &AppStore,
+    maybe_query: &Option<selection::IndexQuery>,
+) -> Option<selection::IndexResponse> {
+    state
+        .selection
+        .selections
+        .get(maybe_query.as_ref()?)
+        .cloned()
+}
+
+pub fn select_search_results(
+    state: &AppStore,
+    maybe_query: &Option<ListQuery>,
+) -> Option<Vec<SearchResult>> {
+    state.search.results.get(maybe_query.as_ref()?).cloned()
+}
+
+// Imperial until the signed in user has loaded
+pub fn select_unit_system(state: &AppStore) -> UnitSystem {
+    state
+        .preferences
+        .current_user
+        .as_ref()
+        .map(|user| user.unit_system)
+        .unwrap_or_default()
+}
@@ -1,3 +1,13 @@
+mod api_error;
+pub use api_error::{ApiError, ApiErrorCode, FieldError};
+mod list_query;
+pub use list_query::{ListQuery, Page};
 pub mod a1_2010_report;
+pub mod a2_2010_report;
 pub mod fan_series;
 pub mod fan_size;
+pub mod import;
+pub mod rating_table;
+pub mod search;
+pub mod selection;
+pub mod user;
@@ -2,16 +2,249 @@ use core::hash::Hash;
 
 use serde::{Deserialize, Serialize};
 
-use crate::models::{A1Standard2010Determination, A1Standard2010Report, FanSeries, FanSize};
+use crate::{
+    api::{ApiError, Page},
+    calculations::{
+        core::FanCurve,
+        fitting::FitStrategy,
+        standards::{fan_speeds_between, A1CurveFamily, A1OperatingPoint},
+        units::{
+            AirDensity, AirHorsepower, BrakeHorsepower, FanSpeed, InletAirflow, OutletArea,
+            StaticPressure,
+        },
+    },
+    display::{FromMeasurement, Measure, UnitSystem},
+    models::{A1Standard2010Determination, A1Standard2010Report, FanSeries, FanSize},
+};
 
-pub type GetResponse = A1Standard2010Report<FanSize<FanSeries<()>>>;
+// A report as listed in the index, with the figures that tell reports apart at a glance
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReportSummary {
+    pub id: String,
+    pub fan_size_id: String,
+    pub fan_series_id: String,
+    pub rpm: f64,
+    // None when the report has no determinations yet
+    pub max_cfm: Option<f64>,
+    // Static pressure at the lowest airflow tested
+    pub shutoff_static_pressure: Option<f64>,
+    pub peak_brake_horsepower: Option<f64>,
+}
+
+impl Eq for ReportSummary {}
+
+impl Hash for ReportSummary {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.fan_size_id.hash(state);
+        self.fan_series_id.hash(state);
+        self.rpm.to_bits().hash(state);
+        for stat in [
+            self.max_cfm,
+            self.shutoff_static_pressure,
+            self.peak_brake_horsepower,
+        ] {
+            stat.map(f64::to_bits).hash(state);
+        }
+    }
+}
+
+impl<R> From<A1Standard2010Report<FanSize<R>>> for ReportSummary {
+    fn from(report: A1Standard2010Report<FanSize<R>>) -> Self {
+        let determinations = &report.determinations;
+        ReportSummary {
+            max_cfm: determinations
+                .iter()
+                .map(|determination| determination.cfm)
+                .reduce(f64::max),
+            shutoff_static_pressure: determinations
+                .iter()
+                .min_by(|a, b| a.cfm.total_cmp(&b.cfm))
+                .map(|determination| determination.static_pressure),
+            peak_brake_horsepower: determinations
+                .iter()
+                .map(|determination| determination.brake_horsepower)
+                .reduce(f64::max),
+            rpm: report.parameters.rpm,
+            fan_series_id: report.fan_size.fan_series_id,
+            fan_size_id: report.fan_size_id,
+            id: report.id,
+        }
+    }
+}
+
+pub type IndexResponse = Page<ReportSummary>;
+
+// Derived from each determination in the same order
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeterminationPerformance {
+    pub outlet_velocity: f64,
+    pub velocity_pressure: f64,
+    pub total_pressure: f64,
+    pub air_horsepower: f64,
+    pub static_efficiency: f64,
+    pub total_efficiency: f64,
+    // Left of peak pressure, where the fan can't be rated reliably
+    #[serde(default)]
+    pub stalled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GetResponse {
+    #[serde(flatten)]
+    pub report: A1Standard2010Report<FanSize<FanSeries<()>>>,
+    pub performance: Vec<DeterminationPerformance>,
+    pub peak_static_efficiency: Option<A1Standard2010Determination>,
+    pub peak_total_efficiency: Option<A1Standard2010Determination>,
+}
+
+impl From<A1Standard2010Report<FanSize<FanSeries<()>>>> for GetResponse {
+    fn from(report: A1Standard2010Report<FanSize<FanSeries<()>>>) -> Self {
+        let outlet_area = OutletArea::from_square_inches(report.fan_size.outlet_area);
+        let fan_curve: FanCurve<A1OperatingPoint> = report.clone().into();
+
+        let performance = fan_curve
+            .as_ref()
+            .iter()
+            .map(|op| DeterminationPerformance {
+                outlet_velocity: op.outlet_velocity(&outlet_area).fpm(),
+                velocity_pressure: op.velocity_pressure(&outlet_area).inches(),
+                total_pressure: op.total_pressure(&outlet_area).inches(),
+                air_horsepower: op.static_air_horsepower().hp(),
+                static_efficiency: op.static_efficiency().fraction(),
+                total_efficiency: op.total_efficiency(&outlet_area).fraction(),
+                stalled: fan_curve.is_stalled_at(op.as_ref()),
+            })
+            .collect();
+
+        GetResponse {
+            performance,
+            peak_static_efficiency: fan_curve.peak_static_efficiency().map(as_determination),
+            peak_total_efficiency: fan_curve
+                .peak_total_efficiency(&outlet_area)
+                .map(as_determination),
+            report,
+        }
+    }
+}
+
+fn as_determination(op: &A1OperatingPoint) -> A1Standard2010Determination {
+    let inlet_airflow: &InletAirflow = op.as_ref();
+    let static_pressure: &StaticPressure = op.as_ref();
+    let brake_horsepower: &BrakeHorsepower = op.as_ref();
+    A1Standard2010Determination {
+        cfm: inlet_airflow.cfm(),
+        static_pressure: static_pressure.inches(),
+        brake_horsepower: brake_horsepower.hp(),
+    }
+}
+
+// Speeds to draw, either listed as comma separated rpms or spread evenly
+// between a min and max. Defaults to five speeds from half to full test rpm.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CurveFamilyQuery {
+    pub min_rpm: Option<f64>,
+    pub max_rpm: Option<f64>,
+    pub count: Option<usize>,
+    pub rpms: Option<String>,
+}
+
+impl CurveFamilyQuery {
+    pub fn fan_speeds(&self, test_rpm: f64) -> Result<Vec<FanSpeed>, ApiError> {
+        if let Some(rpms) = &self.rpms {
+            return rpms
+                .split(',')
+                .map(|rpm| {
+                    rpm.trim()
+                        .parse::<f64>()
+                        .map(FanSpeed::from_rpm)
+                        .map_err(|err| {
+                            ApiError::validation("Invalid fan speeds").with_field_error(
+                                "rpms",
+                                format!("Could not parse rpm '{}': {}", rpm, err),
+                            )
+                        })
+                })
+                .collect();
+        }
+        let max_rpm = self.max_rpm.unwrap_or(test_rpm);
+        let min_rpm = self.min_rpm.unwrap_or(max_rpm / 2.0);
+        Ok(fan_speeds_between(
+            &FanSpeed::from_rpm(min_rpm),
+            &FanSpeed::from_rpm(max_rpm),
+            self.count.unwrap_or(5),
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SpeedCurve {
+    pub rpm: f64,
+    pub points: Vec<A1Standard2010Determination>,
+}
+
+// A line of constant brake horsepower, or a contour of constant static
+// efficiency as a fraction, crossing the speed curves
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsoLine {
+    pub value: f64,
+    pub points: Vec<A1Standard2010Determination>,
+}
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CurveFamilyResponse {
+    pub curves: Vec<SpeedCurve>,
+    pub brake_horsepower_lines: Vec<IsoLine>,
+    pub static_efficiency_contours: Vec<IsoLine>,
+}
+
+impl From<A1CurveFamily> for CurveFamilyResponse {
+    fn from(family: A1CurveFamily) -> Self {
+        CurveFamilyResponse {
+            curves: family
+                .curves
+                .iter()
+                .map(|curve| SpeedCurve {
+                    rpm: curve
+                        .as_ref()
+                        .first()
+                        .map(|op| (op.as_ref() as &FanSpeed).rpm())
+                        .unwrap_or_default(),
+                    points: curve.as_ref().iter().map(as_determination).collect(),
+                })
+                .collect(),
+            brake_horsepower_lines: family
+                .brake_horsepower_lines
+                .iter()
+                .map(|(brake_horsepower, points)| IsoLine {
+                    value: brake_horsepower.hp(),
+                    points: points.iter().map(as_determination).collect(),
+                })
+                .collect(),
+            static_efficiency_contours: family
+                .static_efficiency_contours
+                .iter()
+                .map(|(efficiency, points)| IsoLine {
+                    value: efficiency.fraction(),
+                    points: points.iter().map(as_determination).collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+// Written in whichever units it says, imperial unless given. Reports are saved,
+// and come back, in imperial.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct UpdateBody {
     pub id: String,
     pub fan_rpm: f64,
     pub fan_size_id: String,
+    #[serde(default)]
+    pub air_density: Option<f64>,
     pub determinations: Vec<A1Standard2010Determination>,
+    #[serde(default)]
+    pub units: UnitSystem,
 }
 
 impl Eq for UpdateBody {}
@@ -21,6 +254,701 @@ impl Hash for UpdateBody {
         self.id.hash(state);
         self.fan_rpm.to_bits().hash(state);
         self.fan_size_id.hash(state);
+        self.air_density.map(f64::to_bits).hash(state);
         self.determinations.hash(state);
+        self.units.hash(state);
+    }
+}
+
+impl UpdateBody {
+    // The same report in rpm, lb/ft³, cfm, in. wg and hp, as it's saved
+    pub fn to_imperial(&self) -> UpdateBody {
+        let units = self.units;
+        UpdateBody {
+            air_density: self
+                .air_density
+                .map(|density| AirDensity::from_measurement(density, units).lb_per_cubic_foot()),
+            determinations: self
+                .determinations
+                .iter()
+                .map(|determination| A1Standard2010Determination {
+                    cfm: InletAirflow::from_measurement(determination.cfm, units).cfm(),
+                    static_pressure: StaticPressure::from_measurement(
+                        determination.static_pressure,
+                        units,
+                    )
+                    .inches(),
+                    brake_horsepower: BrakeHorsepower::from_measurement(
+                        determination.brake_horsepower,
+                        units,
+                    )
+                    .hp(),
+                })
+                .collect(),
+            units: UnitSystem::Imperial,
+            ..self.clone()
+        }
+    }
+
+    // Every field that differs between the two, with determinations compared row by row
+    pub fn changes_to(&self, other: &UpdateBody) -> Vec<FieldChange> {
+        let mut changes: Vec<FieldChange> = [
+            ("id", Some(self.id.clone()), Some(other.id.clone())),
+            (
+                "fan_rpm",
+                Some(self.fan_rpm.to_string()),
+                Some(other.fan_rpm.to_string()),
+            ),
+            (
+                "fan_size_id",
+                Some(self.fan_size_id.clone()),
+                Some(other.fan_size_id.clone()),
+            ),
+            (
+                "air_density",
+                self.air_density.map(|density| density.to_string()),
+                other.air_density.map(|density| density.to_string()),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, before, after)| before != after)
+        .map(|(field, before, after)| FieldChange {
+            field: field.to_string(),
+            before,
+            after,
+        })
+        .collect();
+
+        let row_count = self.determinations.len().max(other.determinations.len());
+        for row in 0..row_count {
+            let before = self.determinations.get(row).map(determination_values);
+            let after = other.determinations.get(row).map(determination_values);
+            for (index, name) in ["cfm", "static_pressure", "brake_horsepower"]
+                .into_iter()
+                .enumerate()
+            {
+                let before = before.map(|values| values[index].to_string());
+                let after = after.map(|values| values[index].to_string());
+                if before != after {
+                    changes.push(FieldChange {
+                        field: format!("determinations[{}].{}", row, name),
+                        before,
+                        after,
+                    });
+                }
+            }
+        }
+        changes
+    }
+}
+
+// Checked by the server before saving, and by the form before it offers to save
+impl UpdateBody {
+    // Fewer points than this can't trace a curve from free delivery to shutoff
+    pub const MIN_DETERMINATIONS: usize = 5;
+    // Share of the highest airflow, or static pressure, that still counts as none
+    pub const END_POINT_TOLERANCE: f64 = 0.05;
+
+    // Any values in the messages are in the units the body was written in
+    pub fn validate(&self) -> Result<(), ApiError> {
+        self.validate_in(self.units)
+    }
+
+    // Same checks, with any values in the messages shown in the user's units
+    pub fn validate_in(&self, units: UnitSystem) -> Result<(), ApiError> {
+        let body = self.to_imperial();
+        let mut err = ApiError::validation("Invalid A1 report");
+        if body.id.trim().is_empty() {
+            err = err.with_field_error("id", "Must be entered");
+        }
+        if body.fan_size_id.trim().is_empty() {
+            err = err.with_field_error("fan_size_id", "Must be entered");
+        }
+        if !(body.fan_rpm.is_finite() && body.fan_rpm > 0.0) {
+            err = err.with_field_error("fan_rpm", "Must be positive");
+        }
+        if body
+            .air_density
+            .is_some_and(|density| !(density.is_finite() && density > 0.0))
+        {
+            err = err.with_field_error("air_density", "Must be positive");
+        }
+
+        let determinations = &body.determinations;
+        let field = |row: usize, name: &str| format!("determinations[{}].{}", row, name);
+        let mut all_points_valid = true;
+        for (row, determination) in determinations.iter().enumerate() {
+            let values = determination_values(determination);
+            let mut point_valid = true;
+            for (value, name) in
+                values
+                    .into_iter()
+                    .zip(["cfm", "static_pressure", "brake_horsepower"])
+            {
+                if !(value.is_finite() && value >= 0.0) {
+                    err = err.with_field_error(field(row, name), "Can't be negative");
+                    point_valid = false;
+                }
+            }
+            if let Some(earlier) = determinations[..row]
+                .iter()
+                .position(|earlier| earlier == determination)
+            {
+                err = err
+                    .with_field_error(field(row, "cfm"), format!("Repeats point {}", earlier + 1));
+                point_valid = false;
+            }
+            if point_valid {
+                // A fan can't put more power into the air than it draws
+                let air_horsepower = AirHorsepower::from_static_pressure(
+                    &InletAirflow::from_cfm(determination.cfm),
+                    &StaticPressure::from_inches(determination.static_pressure),
+                );
+                if determination.brake_horsepower <= 0.0
+                    || air_horsepower.hp() > determination.brake_horsepower
+                {
+                    err = err.with_field_error(
+                        field(row, "brake_horsepower"),
+                        format!(
+                            "Must be more than the {} delivered to the air",
+                            air_horsepower.format(units)
+                        ),
+                    );
+                }
+            }
+            all_points_valid &= point_valid;
+        }
+
+        if determinations.len() < Self::MIN_DETERMINATIONS {
+            err = err.with_field_error(
+                "determinations",
+                format!(
+                    "Must have at least {} points, found {}",
+                    Self::MIN_DETERMINATIONS,
+                    determinations.len()
+                ),
+            );
+        } else if all_points_valid {
+            err = check_curve_shape(determinations, units, err);
+        }
+
+        if err.field_errors.is_empty() {
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+}
+
+// Only meaningful once every point has valid, distinct values
+fn check_curve_shape(
+    determinations: &[A1Standard2010Determination],
+    units: UnitSystem,
+    mut err: ApiError,
+) -> ApiError {
+    let field = |row: usize, name: &str| format!("determinations[{}].{}", row, name);
+    let max_cfm = determinations.iter().map(|d| d.cfm).fold(0.0, f64::max);
+    let max_static_pressure = determinations
+        .iter()
+        .map(|d| d.static_pressure)
+        .fold(0.0, f64::max);
+    let tolerance = UpdateBody::END_POINT_TOLERANCE;
+
+    if !determinations
+        .iter()
+        .any(|d| d.static_pressure <= max_static_pressure * tolerance)
+    {
+        err = err.with_field_error(
+            "determinations",
+            format!(
+                "Must include a free delivery point, at or near 0 {}",
+                StaticPressure::symbol(units)
+            ),
+        );
+    }
+    if !determinations.iter().any(|d| d.cfm <= max_cfm * tolerance) {
+        err = err.with_field_error(
+            "determinations",
+            format!(
+                "Must include a shutoff point, at or near 0 {}",
+                InletAirflow::symbol(units)
+            ),
+        );
+    }
+
+    // Points are listed in the order they were run, from either end of the curve
+    let falling = determinations[0].cfm > determinations[determinations.len() - 1].cfm;
+    for (row, pair) in determinations.windows(2).enumerate() {
+        let in_order = if falling {
+            pair[1].cfm < pair[0].cfm
+        } else {
+            pair[1].cfm > pair[0].cfm
+        };
+        if !in_order {
+            err = err.with_field_error(
+                field(row + 1, "cfm"),
+                if falling {
+                    "Must be lower than the point before it"
+                } else {
+                    "Must be higher than the point before it"
+                },
+            );
+        }
+    }
+
+    // Pressure can dip in the stall region, but must fall steadily as airflow rises past the peak
+    let mut by_cfm: Vec<(usize, &A1Standard2010Determination)> =
+        determinations.iter().enumerate().collect();
+    by_cfm.sort_by(|(_, a), (_, b)| a.cfm.total_cmp(&b.cfm));
+    let peak = by_cfm
+        .iter()
+        .enumerate()
+        .max_by(|(_, (_, a)), (_, (_, b))| a.static_pressure.total_cmp(&b.static_pressure))
+        .map_or(0, |(index, _)| index);
+    for pair in by_cfm[peak..].windows(2) {
+        let ((_, lower), (row, higher)) = (pair[0], pair[1]);
+        if higher.static_pressure >= lower.static_pressure {
+            err = err.with_field_error(
+                field(row, "static_pressure"),
+                "Must fall as airflow rises past peak pressure",
+            );
+        }
+    }
+    err
+}
+
+fn determination_values(determination: &A1Standard2010Determination) -> [f64; 3] {
+    [
+        determination.cfm,
+        determination.static_pressure,
+        determination.brake_horsepower,
+    ]
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum RevisionAction {
+    #[serde(rename = "create")]
+    Create,
+    #[serde(rename = "update")]
+    Update,
+    #[serde(rename = "restore")]
+    Restore,
+}
+
+impl RevisionAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Restore => "restore",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseRevisionActionError;
+
+impl TryFrom<&str> for RevisionAction {
+    type Error = ParseRevisionActionError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "create" => Ok(Self::Create),
+            "update" => Ok(Self::Update),
+            "restore" => Ok(Self::Restore),
+            _ => Err(ParseRevisionActionError),
+        }
+    }
+}
+
+// A report as it was saved, revisions are never edited or deleted
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Revision {
+    pub revision_id: i64,
+    pub report_id: String,
+    // None for reports that predate revision tracking
+    pub user_id: Option<String>,
+    pub user_name: Option<String>,
+    pub action: RevisionAction,
+    pub restored_from_revision_id: Option<i64>,
+    // RFC 3339, in UTC
+    pub created_at: String,
+    pub snapshot: UpdateBody,
+}
+
+// Newest first
+pub type RevisionsResponse = Vec<Revision>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct RevisionDiffQuery {
+    pub from: i64,
+    pub to: i64,
+}
+
+// None when the field didn't exist, like a determination that was added
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct RevisionDiffResponse {
+    pub from: Revision,
+    pub to: Revision,
+    pub changes: Vec<FieldChange>,
+}
+
+impl From<(Revision, Revision)> for RevisionDiffResponse {
+    fn from((from, to): (Revision, Revision)) -> Self {
+        let changes = from.snapshot.changes_to(&to.snapshot);
+        RevisionDiffResponse { from, to, changes }
+    }
+}
+
+// With an airflow and static pressure, the sheet is for that operating point,
+// rated on the given fan size or the one the report was tested on
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct PdfQuery {
+    pub fan_size_id: Option<String>,
+    pub cfm: Option<f64>,
+    pub static_pressure: Option<f64>,
+    // Straight lines between the determinations when not given
+    pub fit: Option<FitStrategy>,
+    // What the airflow and static pressure are in, imperial when not given
+    pub units: Option<UnitSystem>,
+}
+
+impl PdfQuery {
+    pub fn operating_point(&self) -> Result<Option<(InletAirflow, StaticPressure)>, ApiError> {
+        let err = ApiError::validation("Invalid operating point");
+        match (self.cfm, self.static_pressure) {
+            (None, None) if self.fan_size_id.is_some() => Err(err.with_field_error(
+                "fan_size_id",
+                "Only an operating point can be rated on another fan size",
+            )),
+            (None, None) => Ok(None),
+            (Some(cfm), Some(static_pressure)) => {
+                let mut err = err;
+                if !(cfm.is_finite() && cfm > 0.0) {
+                    err = err.with_field_error("cfm", "Must be positive");
+                }
+                if !(static_pressure.is_finite() && static_pressure >= 0.0) {
+                    err = err.with_field_error("static_pressure", "Must not be negative");
+                }
+                if err.field_errors.is_empty() {
+                    let units = self.units.unwrap_or_default();
+                    Ok(Some((
+                        InletAirflow::from_measurement(cfm, units),
+                        StaticPressure::from_measurement(static_pressure, units),
+                    )))
+                } else {
+                    Err(err)
+                }
+            }
+            (None, Some(_)) => Err(err.with_field_error("cfm", "Required with a static pressure")),
+            (Some(_), None) => {
+                Err(err.with_field_error("static_pressure", "Required with an airflow"))
+            }
+        }
+    }
+
+    // Only the options that are set, ready to be added to a request's URL
+    pub fn query_params(&self) -> Vec<(&'static str, String)> {
+        [
+            ("fan_size_id", self.fan_size_id.clone()),
+            ("cfm", self.cfm.map(|v| v.to_string())),
+            (
+                "static_pressure",
+                self.static_pressure.map(|v| v.to_string()),
+            ),
+            ("fit", self.fit.map(|v| v.as_str().to_string())),
+            ("units", self.units.map(|v| v.as_str().to_string())),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| Some((name, value?)))
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update_body(determinations: &[(f64, f64, f64)]) -> UpdateBody {
+        UpdateBody {
+            id: "Test Id".to_string(),
+            fan_rpm: 1750.0,
+            fan_size_id: "SKYPLUME G1-ELLV-18 DMF-150".to_string(),
+            air_density: None,
+            units: UnitSystem::Imperial,
+            determinations: determinations
+                .iter()
+                .map(
+                    |&(static_pressure, cfm, brake_horsepower)| A1Standard2010Determination {
+                        cfm,
+                        static_pressure,
+                        brake_horsepower,
+                    },
+                )
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn it_summarizes_a_reports_determinations() {
+        let body = update_body(&[
+            (0.001, 11077.0, 6.320),
+            (1.184, 9981.0, 6.632),
+            (6.646, 0.0, 4.1),
+        ]);
+        let report = A1Standard2010Report {
+            id: body.id,
+            fan_size_id: body.fan_size_id.clone(),
+            fan_size: FanSize {
+                id: body.fan_size_id,
+                fan_series_id: "SKYPLUME G1-ELLV".to_string(),
+                fan_series: (),
+                diameter: 18.25,
+                outlet_area: 1.0,
+            },
+            parameters: crate::models::A1Standard2010Parameters {
+                rpm: body.fan_rpm,
+                air_density: None,
+            },
+            determinations: body.determinations,
+        };
+        let summary: ReportSummary = report.clone().into();
+        assert_eq!(summary.rpm, 1750.0);
+        assert_eq!(summary.max_cfm, Some(11077.0));
+        assert_eq!(summary.shutoff_static_pressure, Some(6.646));
+        assert_eq!(summary.peak_brake_horsepower, Some(6.632));
+        assert_eq!(summary.fan_series_id, "SKYPLUME G1-ELLV");
+
+        let empty: ReportSummary = A1Standard2010Report {
+            determinations: vec![],
+            ..report
+        }
+        .into();
+        assert_eq!(empty.max_cfm, None);
+        assert_eq!(empty.shutoff_static_pressure, None);
+    }
+
+    fn field_errors(body: &UpdateBody) -> Vec<(String, String)> {
+        body.validate()
+            .err()
+            .map(|err| {
+                err.field_errors
+                    .into_iter()
+                    .map(|field_error| (field_error.field, field_error.message))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    const VALID_POINTS: [(f64, f64, f64); 6] = [
+        (0.0, 11077.0, 6.320),
+        (1.184, 9981.0, 6.632),
+        (2.5, 8500.0, 6.9),
+        (4.608, 6659.0, 7.416),
+        (5.5, 4000.0, 6.5),
+        (5.2, 0.0, 4.1),
+    ];
+
+    #[test]
+    fn it_accepts_a_complete_curve_with_a_stall_dip() {
+        assert_eq!(field_errors(&update_body(&VALID_POINTS)), vec![]);
+        let mut reversed = VALID_POINTS;
+        reversed.reverse();
+        assert_eq!(field_errors(&update_body(&reversed)), vec![]);
+    }
+
+    #[test]
+    fn it_reports_bad_points_by_field() {
+        let mut points = VALID_POINTS;
+        points[1] = (1.184, -9981.0, 6.632);
+        points[2] = (2.5, 8500.0, 1.0);
+        points[4] = points[3];
+        let errors = field_errors(&UpdateBody {
+            fan_rpm: 0.0,
+            ..update_body(&points)
+        });
+        let fields: Vec<&str> = errors.iter().map(|(field, _)| field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "fan_rpm",
+                "determinations[1].cfm",
+                "determinations[2].brake_horsepower",
+                "determinations[4].cfm",
+            ]
+        );
+        assert_eq!(errors[3].1, "Repeats point 4");
+    }
+
+    #[test]
+    fn it_checks_the_shape_of_the_curve() {
+        assert_eq!(
+            field_errors(&update_body(&VALID_POINTS[..3])),
+            vec![(
+                "determinations".to_string(),
+                "Must have at least 5 points, found 3".to_string()
+            )]
+        );
+
+        let without_ends = update_body(
+            &VALID_POINTS[1..5]
+                .iter()
+                .chain(&[(5.6, 3000.0, 6.0)])
+                .cloned()
+                .collect::<Vec<_>>(),
+        );
+        let messages: Vec<String> = field_errors(&without_ends)
+            .into_iter()
+            .map(|(_, message)| message)
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Must include a free delivery point, at or near 0 in. wg",
+                "Must include a shutoff point, at or near 0 cfm",
+            ]
+        );
+        let si_messages: Vec<String> = without_ends
+            .validate_in(UnitSystem::Si)
+            .unwrap_err()
+            .field_errors
+            .into_iter()
+            .map(|field_error| field_error.message)
+            .collect();
+        assert_eq!(
+            si_messages,
+            vec![
+                "Must include a free delivery point, at or near 0 Pa",
+                "Must include a shutoff point, at or near 0 m³/s",
+            ]
+        );
+
+        let mut points = VALID_POINTS;
+        points.swap(1, 2);
+        points[4] = (4.0, 4000.0, 6.5);
+        let fields: Vec<String> = field_errors(&update_body(&points))
+            .into_iter()
+            .map(|(field, _)| field)
+            .collect();
+        assert_eq!(
+            fields,
+            vec!["determinations[2].cfm", "determinations[3].static_pressure"]
+        );
+    }
+
+    #[test]
+    fn it_checks_a_body_written_in_si_units() {
+        let imperial = update_body(&VALID_POINTS);
+        let si = UpdateBody {
+            units: UnitSystem::Si,
+            determinations: imperial
+                .determinations
+                .iter()
+                .map(|determination| A1Standard2010Determination {
+                    cfm: InletAirflow::from_cfm(determination.cfm)
+                        .measurement(UnitSystem::Si)
+                        .value,
+                    static_pressure: StaticPressure::from_inches(determination.static_pressure)
+                        .measurement(UnitSystem::Si)
+                        .value,
+                    brake_horsepower: BrakeHorsepower::from_hp(determination.brake_horsepower)
+                        .measurement(UnitSystem::Si)
+                        .value,
+                })
+                .collect(),
+            ..imperial.clone()
+        };
+        assert_eq!(field_errors(&si), vec![]);
+        let saved = si.to_imperial();
+        assert_eq!(saved.units, UnitSystem::Imperial);
+        for (saved, entered) in saved.determinations.iter().zip(&imperial.determinations) {
+            assert!((saved.cfm - entered.cfm).abs() < 1e-6);
+            assert!((saved.static_pressure - entered.static_pressure).abs() < 1e-9);
+            assert!((saved.brake_horsepower - entered.brake_horsepower).abs() < 1e-9);
+        }
+
+        // Too little power for the air moved, in kW as it was entered
+        let mut underpowered = si;
+        underpowered.determinations[2].brake_horsepower = 0.5;
+        let errors = field_errors(&underpowered);
+        assert_eq!(errors[0].0, "determinations[2].brake_horsepower");
+        assert!(
+            errors[0].1.ends_with("kW delivered to the air"),
+            "{}",
+            errors[0].1
+        );
+    }
+
+    #[test]
+    fn it_finds_no_changes_between_identical_reports() {
+        let body = update_body(&[(0.001, 11077.0, 6.320), (1.184, 9981.0, 6.632)]);
+        assert_eq!(body.changes_to(&body.clone()), vec![]);
+    }
+
+    #[test]
+    fn it_lists_changed_fields_and_determinations() {
+        let before = update_body(&[(0.001, 11077.0, 6.320), (1.184, 9981.0, 6.632)]);
+        let after = UpdateBody {
+            fan_rpm: 1760.0,
+            air_density: Some(0.074),
+            ..update_body(&[(0.001, 11080.0, 6.320)])
+        };
+        let change = |field: &str, before: Option<&str>, after: Option<&str>| FieldChange {
+            field: field.to_string(),
+            before: before.map(str::to_string),
+            after: after.map(str::to_string),
+        };
+        assert_eq!(
+            before.changes_to(&after),
+            vec![
+                change("fan_rpm", Some("1750"), Some("1760")),
+                change("air_density", None, Some("0.074")),
+                change("determinations[0].cfm", Some("11077"), Some("11080")),
+                change("determinations[1].cfm", Some("9981"), None),
+                change("determinations[1].static_pressure", Some("1.184"), None),
+                change("determinations[1].brake_horsepower", Some("6.632"), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_needs_both_halves_of_an_operating_point() {
+        assert_eq!(PdfQuery::default().operating_point().unwrap(), None);
+        let query = PdfQuery {
+            cfm: Some(7749.0),
+            static_pressure: Some(3.789),
+            ..PdfQuery::default()
+        };
+        assert!(query.operating_point().unwrap().is_some());
+        let fields = |query: PdfQuery| -> Vec<String> {
+            query
+                .operating_point()
+                .unwrap_err()
+                .field_errors
+                .into_iter()
+                .map(|field_error| field_error.field)
+                .collect()
+        };
+        assert_eq!(
+            fields(PdfQuery {
+                cfm: Some(7749.0),
+                ..PdfQuery::default()
+            }),
+            vec!["static_pressure"]
+        );
+        assert_eq!(
+            fields(PdfQuery {
+                fan_size_id: Some("DMF-250".to_string()),
+                ..PdfQuery::default()
+            }),
+            vec!["fan_size_id"]
+        );
     }
 }
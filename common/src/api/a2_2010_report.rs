@@ -0,0 +1,34 @@
+use core::hash::Hash;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    A1Standard2010Report, A2Standard2010Determination, A2Standard2010Report, FanSeries, FanSize,
+    InducedFlowFanSize, Nozzle,
+};
+
+pub type GetResponse = A2Standard2010Report<
+    A1Standard2010Report<FanSize<FanSeries<()>>>,
+    InducedFlowFanSize<FanSize<FanSeries<()>>, Nozzle>,
+>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UpdateBody {
+    pub id: String,
+    pub fan_rpm: f64,
+    pub a1_report_id: String,
+    pub induced_flow_fan_size_id: String,
+    pub determinations: Vec<A2Standard2010Determination>,
+}
+
+impl Eq for UpdateBody {}
+
+impl Hash for UpdateBody {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.fan_rpm.to_bits().hash(state);
+        self.a1_report_id.hash(state);
+        self.induced_flow_fan_size_id.hash(state);
+        self.determinations.hash(state);
+    }
+}
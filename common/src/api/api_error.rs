@@ -0,0 +1,155 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::calculations::CalculationError;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ApiErrorCode {
+    #[serde(rename = "unauthorized")]
+    Unauthorized,
+    #[serde(rename = "forbidden")]
+    Forbidden,
+    #[serde(rename = "not_found")]
+    NotFound,
+    #[serde(rename = "conflict")]
+    Conflict,
+    #[serde(rename = "validation")]
+    Validation,
+    #[serde(rename = "internal")]
+    Internal,
+}
+
+impl ApiErrorCode {
+    pub fn status(&self) -> u16 {
+        match self {
+            Self::Unauthorized => 401,
+            Self::Forbidden => 403,
+            Self::NotFound => 404,
+            Self::Conflict => 409,
+            Self::Validation => 422,
+            Self::Internal => 500,
+        }
+    }
+
+    // For responses that aren't an ApiError, like a proxy timing out
+    pub fn from_status(status: u16) -> Self {
+        match status {
+            401 => Self::Unauthorized,
+            403 => Self::Forbidden,
+            404 => Self::NotFound,
+            409 => Self::Conflict,
+            400 | 422 => Self::Validation,
+            _ => Self::Internal,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct FieldError {
+    // Matches the field's name in the request body or query
+    pub field: String,
+    pub message: String,
+}
+
+// The body of every unsuccessful API response
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ApiError {
+    pub code: ApiErrorCode,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub field_errors: Vec<FieldError>,
+}
+
+impl ApiError {
+    pub fn new(code: ApiErrorCode, message: impl Into<String>) -> Self {
+        ApiError {
+            code,
+            message: message.into(),
+            field_errors: vec![],
+        }
+    }
+
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self::new(ApiErrorCode::Unauthorized, message)
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::new(ApiErrorCode::Forbidden, message)
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(ApiErrorCode::NotFound, message)
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::new(ApiErrorCode::Conflict, message)
+    }
+
+    pub fn validation(message: impl Into<String>) -> Self {
+        Self::new(ApiErrorCode::Validation, message)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(ApiErrorCode::Internal, message)
+    }
+
+    pub fn with_field_error(
+        mut self,
+        field: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        self.field_errors.push(FieldError {
+            field: field.into(),
+            message: message.into(),
+        });
+        self
+    }
+
+    pub fn status(&self) -> u16 {
+        self.code.status()
+    }
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+        for FieldError { field, message } in &self.field_errors {
+            write!(f, "\n{}: {}", field, message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+// The test data can't produce the requested result, which the client can fix by changing it
+impl From<CalculationError> for ApiError {
+    fn from(err: CalculationError) -> Self {
+        ApiError::validation(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_field_errors_only_when_present() {
+        let not_found = ApiError::not_found("Could not find Fan Size");
+        assert_eq!(
+            serde_json::to_string(&not_found).unwrap(),
+            r#"{"code":"not_found","message":"Could not find Fan Size"}"#
+        );
+
+        let invalid = ApiError::validation("Invalid fan size")
+            .with_field_error("diameter", "Must be positive");
+        let json = serde_json::to_string(&invalid).unwrap();
+        assert_eq!(
+            json,
+            r#"{"code":"validation","message":"Invalid fan size","field_errors":[{"field":"diameter","message":"Must be positive"}]}"#
+        );
+        assert_eq!(serde_json::from_str::<ApiError>(&json).unwrap(), invalid);
+        assert_eq!(invalid.status(), 422);
+    }
+}
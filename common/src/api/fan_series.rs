@@ -2,9 +2,12 @@ use core::hash::Hash;
 
 use serde::{Deserialize, Serialize};
 
-use crate::models::{FanSeries, FanSize, FanType};
+use crate::{
+    api::Page,
+    models::{FanSeries, FanSize, FanType},
+};
 
-pub type IndexResponse = Vec<FanSeries<()>>;
+pub type IndexResponse = Page<FanSeries<()>>;
 
 pub type GetResponse = FanSeries<Vec<FanSize<()>>>;
 
@@ -1,5 +1,31 @@
-use crate::models::{FanSeries, FanSize};
+use core::hash::Hash;
 
-pub type IndexResponse = Vec<FanSize<()>>;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::Page,
+    models::{FanSeries, FanSize},
+};
+
+pub type IndexResponse = Page<FanSize<()>>;
 
 pub type GetResponse = FanSize<FanSeries<()>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UpdateBody {
+    pub id: String,
+    pub fan_series_id: String,
+    pub diameter: f64,
+    pub outlet_area: f64,
+}
+
+impl Eq for UpdateBody {}
+
+impl Hash for UpdateBody {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.fan_series_id.hash(state);
+        self.diameter.to_bits().hash(state);
+        self.outlet_area.to_bits().hash(state);
+    }
+}
@@ -0,0 +1,6 @@
+use crate::import::{Import, ImportOptions};
+
+// The upload is sent as the request body, with the options in the query
+pub type ImportQuery = ImportOptions;
+
+pub type ImportResponse = Import;
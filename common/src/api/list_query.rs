@@ -0,0 +1,203 @@
+use core::hash::Hash;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{api::ApiError, models::FanType};
+
+// Filters shared by the index endpoints, each endpoint ignores the ones that don't apply to it
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ListQuery {
+    // Matches anywhere in the id, ignoring case
+    pub search: Option<String>,
+    pub fan_type: Option<FanType>,
+    pub fan_series_id: Option<String>,
+    pub fan_size_id: Option<String>,
+    // Inches
+    pub min_diameter: Option<f64>,
+    pub max_diameter: Option<f64>,
+    pub min_rpm: Option<f64>,
+    pub max_rpm: Option<f64>,
+    pub offset: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+impl ListQuery {
+    pub const DEFAULT_LIMIT: i64 = 50;
+    pub const MAX_LIMIT: i64 = 200;
+
+    pub fn offset(&self) -> i64 {
+        self.offset.unwrap_or(0).max(0)
+    }
+
+    pub fn limit(&self) -> i64 {
+        self.limit
+            .unwrap_or(Self::DEFAULT_LIMIT)
+            .clamp(1, Self::MAX_LIMIT)
+    }
+
+    // An empty search box shouldn't filter anything out
+    pub fn search(&self) -> Option<&str> {
+        self.search
+            .as_deref()
+            .map(str::trim)
+            .filter(|search| !search.is_empty())
+    }
+
+    pub fn with_offset(&self, offset: i64) -> Self {
+        ListQuery {
+            offset: Some(offset.max(0)),
+            ..self.clone()
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ApiError> {
+        let mut err = ApiError::validation("Invalid list query");
+        if self.offset.is_some_and(|offset| offset < 0) {
+            err = err.with_field_error("offset", "Can't be negative");
+        }
+        if self
+            .limit
+            .is_some_and(|limit| !(1..=Self::MAX_LIMIT).contains(&limit))
+        {
+            err = err.with_field_error(
+                "limit",
+                format!("Must be between 1 and {}", Self::MAX_LIMIT),
+            );
+        }
+        for (field, min, max) in [
+            ("max_diameter", self.min_diameter, self.max_diameter),
+            ("max_rpm", self.min_rpm, self.max_rpm),
+        ] {
+            if let (Some(min), Some(max)) = (min, max) {
+                if min > max {
+                    err = err.with_field_error(field, "Must be at least the minimum");
+                }
+            }
+        }
+        if err.field_errors.is_empty() {
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+
+    // Only the filters that are set, ready to be added to a request's URL
+    pub fn query_params(&self) -> Vec<(&'static str, String)> {
+        [
+            ("search", self.search().map(str::to_string)),
+            ("fan_type", self.fan_type.as_ref().map(FanType::to_string)),
+            ("fan_series_id", self.fan_series_id.clone()),
+            ("fan_size_id", self.fan_size_id.clone()),
+            ("min_diameter", self.min_diameter.map(|v| v.to_string())),
+            ("max_diameter", self.max_diameter.map(|v| v.to_string())),
+            ("min_rpm", self.min_rpm.map(|v| v.to_string())),
+            ("max_rpm", self.max_rpm.map(|v| v.to_string())),
+            ("offset", self.offset.map(|v| v.to_string())),
+            ("limit", self.limit.map(|v| v.to_string())),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| Some((name, value?)))
+        .collect()
+    }
+}
+
+impl Eq for ListQuery {}
+
+impl Hash for ListQuery {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.search.hash(state);
+        self.fan_type.hash(state);
+        self.fan_series_id.hash(state);
+        self.fan_size_id.hash(state);
+        for bound in [
+            self.min_diameter,
+            self.max_diameter,
+            self.min_rpm,
+            self.max_rpm,
+        ] {
+            bound.map(f64::to_bits).hash(state);
+        }
+        self.offset.hash(state);
+        self.limit.hash(state);
+    }
+}
+
+// One page of an index, with the total so clients can page through the rest
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub offset: i64,
+    pub limit: i64,
+    pub total: i64,
+}
+
+impl<T> Page<T> {
+    pub fn has_previous(&self) -> bool {
+        self.offset > 0
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.offset + self.limit < self.total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_only_sends_the_filters_that_are_set() {
+        let query = ListQuery {
+            search: Some("  ".to_string()),
+            fan_type: Some(FanType::MixedFlow),
+            min_diameter: Some(18.25),
+            offset: Some(50),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.query_params(),
+            vec![
+                ("fan_type", "mixed_flow".to_string()),
+                ("min_diameter", "18.25".to_string()),
+                ("offset", "50".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_reports_invalid_ranges_by_field() {
+        let query = ListQuery {
+            min_rpm: Some(1750.0),
+            max_rpm: Some(900.0),
+            limit: Some(1000),
+            ..Default::default()
+        };
+        let fields: Vec<String> = query
+            .validate()
+            .unwrap_err()
+            .field_errors
+            .into_iter()
+            .map(|field_error| field_error.field)
+            .collect();
+        assert_eq!(fields, vec!["limit", "max_rpm"]);
+        assert_eq!(query.limit(), ListQuery::MAX_LIMIT);
+        assert!(ListQuery::default().validate().is_ok());
+    }
+
+    #[test]
+    fn it_knows_when_there_are_more_pages() {
+        let page = Page {
+            items: vec![(); 50],
+            offset: 50,
+            limit: 50,
+            total: 120,
+        };
+        assert!(page.has_previous());
+        assert!(page.has_next());
+        assert!(!Page {
+            offset: 100,
+            ..page
+        }
+        .has_next());
+    }
+}
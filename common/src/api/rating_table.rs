@@ -0,0 +1,179 @@
+use core::hash::Hash;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::ApiError,
+    calculations::{
+        fitting::FitStrategy,
+        rating_table::{RatingLimits, RatingTable},
+        units::{BrakeHorsepower, FanSpeed},
+    },
+    display::{FromMeasurement, Measure, UnitSystem},
+    models::{FanSeries, FanSize},
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RatingTableQuery {
+    // Defaults to a report tested on this size, or failing that, one from its series
+    pub a1_report_id: Option<String>,
+    pub max_rpm: Option<f64>,
+    pub max_brake_horsepower: Option<f64>,
+    pub rows: Option<usize>,
+    pub columns: Option<usize>,
+    // Straight lines between the determinations when not given
+    pub fit: Option<FitStrategy>,
+    // What the power limit is in, and the table comes back in, imperial when not given
+    pub units: Option<UnitSystem>,
+}
+
+impl RatingTableQuery {
+    pub const DEFAULT_ROWS: usize = 12;
+    pub const DEFAULT_COLUMNS: usize = 8;
+    // Any more won't fit on a printed page
+    pub const MAX_ROWS: usize = 40;
+    pub const MAX_COLUMNS: usize = 16;
+
+    pub fn rows(&self) -> usize {
+        self.rows.unwrap_or(Self::DEFAULT_ROWS)
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns.unwrap_or(Self::DEFAULT_COLUMNS)
+    }
+
+    pub fn units(&self) -> UnitSystem {
+        self.units.unwrap_or_default()
+    }
+
+    pub fn limits(&self) -> RatingLimits {
+        RatingLimits {
+            max_fan_speed: self.max_rpm.map(FanSpeed::from_rpm),
+            max_brake_horsepower: self.max_brake_horsepower.map(|max_brake_horsepower| {
+                BrakeHorsepower::from_measurement(max_brake_horsepower, self.units())
+            }),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ApiError> {
+        let mut err = ApiError::validation("Invalid rating table query");
+        for (field, limit) in [
+            ("max_rpm", self.max_rpm),
+            ("max_brake_horsepower", self.max_brake_horsepower),
+        ] {
+            if limit.is_some_and(|limit| !(limit.is_finite() && limit > 0.0)) {
+                err = err.with_field_error(field, "Must be positive");
+            }
+        }
+        for (field, count, max) in [
+            ("rows", self.rows, Self::MAX_ROWS),
+            ("columns", self.columns, Self::MAX_COLUMNS),
+        ] {
+            if count.is_some_and(|count| !(1..=max).contains(&count)) {
+                err = err.with_field_error(field, format!("Must be between 1 and {}", max));
+            }
+        }
+        if err.field_errors.is_empty() {
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+
+    // Only the options that are set, ready to be added to a request's URL
+    pub fn query_params(&self) -> Vec<(&'static str, String)> {
+        [
+            ("a1_report_id", self.a1_report_id.clone()),
+            ("max_rpm", self.max_rpm.map(|v| v.to_string())),
+            (
+                "max_brake_horsepower",
+                self.max_brake_horsepower.map(|v| v.to_string()),
+            ),
+            ("rows", self.rows.map(|v| v.to_string())),
+            ("columns", self.columns.map(|v| v.to_string())),
+            ("fit", self.fit.map(|v| v.as_str().to_string())),
+            ("units", self.units.map(|v| v.as_str().to_string())),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| Some((name, value?)))
+        .collect()
+    }
+}
+
+impl Eq for RatingTableQuery {}
+
+impl Hash for RatingTableQuery {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.a1_report_id.hash(state);
+        self.max_rpm.map(f64::to_bits).hash(state);
+        self.max_brake_horsepower.map(f64::to_bits).hash(state);
+        self.rows.hash(state);
+        self.columns.hash(state);
+        self.fit.hash(state);
+        self.units.hash(state);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RatingCell {
+    pub rpm: f64,
+    pub brake_horsepower: f64,
+}
+
+// cells[row][column] is the rating at cfms[row] and static_pressures[column],
+// in the units the table was asked for
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RatingTableResponse {
+    pub fan_size: FanSize<FanSeries<()>>,
+    pub a1_report_id: String,
+    pub max_rpm: Option<f64>,
+    pub max_brake_horsepower: Option<f64>,
+    pub units: UnitSystem,
+    pub cfms: Vec<f64>,
+    pub static_pressures: Vec<f64>,
+    pub cells: Vec<Vec<Option<RatingCell>>>,
+}
+
+impl RatingTableResponse {
+    pub fn new(
+        fan_size: FanSize<FanSeries<()>>,
+        a1_report_id: String,
+        query: &RatingTableQuery,
+        table: RatingTable,
+    ) -> Self {
+        let units = query.units();
+        RatingTableResponse {
+            fan_size,
+            a1_report_id,
+            max_rpm: query.max_rpm,
+            max_brake_horsepower: query.max_brake_horsepower,
+            units,
+            cfms: table
+                .airflows
+                .iter()
+                .map(|airflow| airflow.measurement(units).value)
+                .collect(),
+            static_pressures: table
+                .static_pressures
+                .iter()
+                .map(|static_pressure| static_pressure.measurement(units).value)
+                .collect(),
+            cells: table
+                .cells
+                .into_iter()
+                .map(|row| {
+                    row.into_iter()
+                        .map(|cell| {
+                            cell.map(|operating_point| RatingCell {
+                                rpm: (operating_point.as_ref() as &FanSpeed).rpm(),
+                                brake_horsepower: (operating_point.as_ref() as &BrakeHorsepower)
+                                    .measurement(units)
+                                    .value,
+                            })
+                        })
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+}
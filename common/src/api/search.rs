@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum SearchResultKind {
+    #[serde(rename = "fan_series")]
+    FanSeries,
+    #[serde(rename = "fan_size")]
+    FanSize,
+    #[serde(rename = "a1_report")]
+    A1Report,
+    #[serde(rename = "a2_report")]
+    A2Report,
+}
+
+impl SearchResultKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::FanSeries => "Fan Series",
+            Self::FanSize => "Fan Size",
+            Self::A1Report => "A1 Report",
+            Self::A2Report => "A2 Report",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseSearchResultKindError;
+
+impl TryFrom<&str> for SearchResultKind {
+    type Error = ParseSearchResultKindError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "fan_series" => Ok(Self::FanSeries),
+            "fan_size" => Ok(Self::FanSize),
+            "a1_report" => Ok(Self::A1Report),
+            "a2_report" => Ok(Self::A2Report),
+            _ => Err(ParseSearchResultKindError),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct SearchResult {
+    pub kind: SearchResultKind,
+    pub id: String,
+}
+
+// Best matches first, the query's search and limit are the only filters used
+pub type IndexResponse = Vec<SearchResult>;
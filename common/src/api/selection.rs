@@ -0,0 +1,136 @@
+use core::hash::Hash;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::ApiError,
+    calculations::{
+        fitting::FitStrategy,
+        selection::FanSelection,
+        units::{BrakeHorsepower, FanSpeed, InletAirflow, StaticPressure},
+    },
+    display::{FromMeasurement, Measure, UnitSystem},
+    models::{FanSeries, FanSize},
+};
+
+// A duty in the units it gives, imperial unless given, and the selections come back in them too
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IndexQuery {
+    pub cfm: f64,
+    pub static_pressure: f64,
+    #[serde(default)]
+    pub fit: FitStrategy,
+    #[serde(default)]
+    pub units: UnitSystem,
+}
+
+impl IndexQuery {
+    pub fn validate(&self) -> Result<(InletAirflow, StaticPressure), ApiError> {
+        let mut err = ApiError::validation("Invalid duty");
+        if !(self.cfm.is_finite() && self.cfm > 0.0) {
+            err = err.with_field_error("cfm", "Must be positive");
+        }
+        if !(self.static_pressure.is_finite() && self.static_pressure >= 0.0) {
+            err = err.with_field_error("static_pressure", "Must not be negative");
+        }
+        if err.field_errors.is_empty() {
+            Ok((
+                InletAirflow::from_measurement(self.cfm, self.units),
+                StaticPressure::from_measurement(self.static_pressure, self.units),
+            ))
+        } else {
+            Err(err)
+        }
+    }
+
+    pub fn query_params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("cfm", self.cfm.to_string()),
+            ("static_pressure", self.static_pressure.to_string()),
+            ("fit", self.fit.as_str().to_string()),
+            ("units", self.units.as_str().to_string()),
+        ]
+    }
+}
+
+impl Eq for IndexQuery {}
+
+impl Hash for IndexQuery {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.cfm.to_bits().hash(state);
+        self.static_pressure.to_bits().hash(state);
+        self.fit.hash(state);
+        self.units.hash(state);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Selection {
+    pub fan_size: FanSize<FanSeries<()>>,
+    pub a1_report_id: String,
+    pub rpm: f64,
+    // In the units the duty was given in
+    pub brake_horsepower: f64,
+    pub static_pressure_margin: f64,
+}
+
+impl From<(FanSelection<FanSeries<()>>, UnitSystem)> for Selection {
+    fn from((value, units): (FanSelection<FanSeries<()>>, UnitSystem)) -> Self {
+        let FanSelection {
+            fan_size,
+            a1_report_id,
+            selection,
+        } = value;
+        let fan_speed: &FanSpeed = selection.operating_point.as_ref();
+        let brake_horsepower: &BrakeHorsepower = selection.operating_point.as_ref();
+        Selection {
+            fan_size,
+            a1_report_id,
+            rpm: fan_speed.rpm(),
+            brake_horsepower: brake_horsepower.measurement(units).value,
+            static_pressure_margin: selection.static_pressure_margin,
+        }
+    }
+}
+
+pub type IndexResponse = Vec<Selection>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_only_selects_for_a_positive_airflow() {
+        let fields = |cfm: f64, static_pressure: f64| -> Vec<String> {
+            IndexQuery {
+                cfm,
+                static_pressure,
+                fit: FitStrategy::default(),
+                units: UnitSystem::Imperial,
+            }
+            .validate()
+            .err()
+            .map(|err| {
+                err.field_errors
+                    .into_iter()
+                    .map(|field_error| field_error.field)
+                    .collect()
+            })
+            .unwrap_or_default()
+        };
+        assert_eq!(fields(7749.0, 0.0), Vec::<String>::new());
+        assert_eq!(fields(-5000.0, 2.0), vec!["cfm"]);
+        assert_eq!(fields(0.0, f64::NAN), vec!["cfm", "static_pressure"]);
+
+        let (inlet_airflow, static_pressure) = IndexQuery {
+            cfm: 1.0,
+            static_pressure: 249.08891,
+            fit: FitStrategy::default(),
+            units: UnitSystem::Si,
+        }
+        .validate()
+        .unwrap();
+        assert!((inlet_airflow.cfm() - 2118.88).abs() < 0.01);
+        assert!((static_pressure.inches() - 1.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    display::UnitSystem,
+    models::{Role, User},
+};
+
+// Users awaiting approval are listed first
+pub type IndexResponse = Vec<User>;
+
+pub type GetResponse = User;
+
+// Invites a colleague ahead of their first sign in, or approves one who has
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct UpdateBody {
+    pub id: String,
+    pub name: Option<String>,
+    pub role: Option<Role>,
+}
+
+// Each user's own settings, which they can change whatever their role
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct PreferencesBody {
+    pub unit_system: UnitSystem,
+}
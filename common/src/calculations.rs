@@ -1,4 +1,9 @@
+mod calculation_error;
+pub use calculation_error::CalculationError;
 pub mod core;
+pub mod fitting;
+pub mod rating_table;
+pub mod selection;
 pub mod standards;
 pub mod traits;
 pub mod units;
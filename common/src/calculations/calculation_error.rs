@@ -0,0 +1,90 @@
+use std::fmt;
+
+use crate::calculations::units::InletAirflow;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CalculationError {
+    // Asked for a value beyond the points the curve was tested at
+    OutOfRange { target: f64, low: f64, high: f64 },
+    EmptyCurve,
+    // Both ends of an interval sit at the same value, so there's nothing to
+    // interpolate across
+    DegenerateInterval { at: f64 },
+    // Points have to be in increasing order with no repeats
+    NonMonotonic,
+    TooFewPoints { required: usize, found: usize },
+    SingularFit,
+    NoIntersection,
+    // A stall dip can meet a system curve more than once
+    AmbiguousIntersection { crossings: usize },
+    // The fan laws can't scale a curve to deliver no airflow
+    NonPositiveAirflow { cfm: f64 },
+}
+
+impl fmt::Display for CalculationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalculationError::OutOfRange { target, low, high } => write!(
+                f,
+                "{} is outside the tested range of {} to {}",
+                target, low, high
+            ),
+            CalculationError::EmptyCurve => write!(f, "The fan curve has no points"),
+            CalculationError::DegenerateInterval { at } => {
+                write!(f, "Two points share the same value of {}", at)
+            }
+            CalculationError::NonMonotonic => {
+                write!(f, "Points must be sorted with no repeated values")
+            }
+            CalculationError::TooFewPoints { required, found } => write!(
+                f,
+                "At least {} points are needed, but only {} were given",
+                required, found
+            ),
+            CalculationError::SingularFit => {
+                write!(f, "The points don't determine a unique polynomial")
+            }
+            CalculationError::NoIntersection => {
+                write!(f, "The system curve does not intersect the fan curve")
+            }
+            CalculationError::AmbiguousIntersection { crossings } => write!(
+                f,
+                "The system curve crosses the fan curve {} times in the surge region",
+                crossings
+            ),
+            CalculationError::NonPositiveAirflow { cfm } => {
+                write!(f, "Airflow must be greater than 0 cfm, but was {}", cfm)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CalculationError {}
+
+impl From<CalculationError> for String {
+    fn from(err: CalculationError) -> Self {
+        err.to_string()
+    }
+}
+
+impl CalculationError {
+    pub fn check_airflow(inlet_airflow: &InletAirflow) -> Result<(), CalculationError> {
+        let cfm = inlet_airflow.cfm();
+        if cfm > 0.0 && cfm.is_finite() {
+            Ok(())
+        } else {
+            Err(CalculationError::NonPositiveAirflow { cfm })
+        }
+    }
+
+    // Interpolation needs low ≤ target ≤ high across an interval with some width
+    pub fn check_interval(low: f64, high: f64, target: f64) -> Result<(), CalculationError> {
+        if !(low <= target && target <= high) {
+            Err(CalculationError::OutOfRange { target, low, high })
+        } else if low == high {
+            Err(CalculationError::DegenerateInterval { at: low })
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -3,3 +3,5 @@ pub use fan_curve::FanCurve;
 pub use fan_curve::InterpolableFanCurve;
 mod operating_point;
 pub use operating_point::OperatingPoint;
+mod system_curve;
+pub use system_curve::SystemCurve;
@@ -1,4 +1,9 @@
-use crate::calculations::traits::{Interpolable, ScalesTo, ScalesWith};
+use crate::calculations::fitting::{FitReport, FitStrategy, MonotoneCubic, NaturalCubicSpline};
+use crate::calculations::traits::{
+    CurveFitStrategy, Fittable, Interpolable, MeanErrorSquareComparable, Scalar, ScalesTo,
+    ScalesWith,
+};
+use crate::calculations::CalculationError;
 use crate::util::pairwise;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -63,26 +68,113 @@ where
 pub trait InterpolableFanCurve<X, Y>
 where
     Y: Interpolable<X, Y>,
-    X: PartialOrd + Clone,
+    X: PartialOrd + Clone + Scalar,
 {
     fn as_interpolation_vec(&self) -> Vec<(X, Y)>;
 
     fn interpolation_pairs(&self) -> Vec<(X, Y)> {
         let mut ops = self.as_interpolation_vec();
-        ops.sort_by(|(a_x, _a_y), (b_x, _b_y)| a_x.partial_cmp(b_x).unwrap());
+        ops.sort_by(|(a_x, _a_y), (b_x, _b_y)| a_x.scalar().total_cmp(&b_x.scalar()));
         ops
     }
 
-    fn interpolate(&self, target: &X) -> Result<Y, String> {
-        let bounds = pairwise(self.interpolation_pairs())
-            .find(|((low_x, _), (high_x, _))| high_x >= target && low_x <= target);
-        //set up variables
-        if let Some((store_low, store_high)) = bounds {
-            Ok(Y::interpolate_between(store_low, store_high, target))
-        } else {
-            Err("Out of bounds".to_string())
+    fn check_in_range(&self, target: &X) -> Result<(), CalculationError> {
+        let pairs = self.interpolation_pairs();
+        match (pairs.first(), pairs.last()) {
+            (Some((low_x, _)), Some((high_x, _))) => {
+                if low_x <= target && target <= high_x {
+                    Ok(())
+                } else {
+                    Err(CalculationError::OutOfRange {
+                        target: target.scalar(),
+                        low: low_x.scalar(),
+                        high: high_x.scalar(),
+                    })
+                }
+            }
+            _ => Err(CalculationError::EmptyCurve),
         }
     }
+
+    fn interpolate(&self, target: &X) -> Result<Y, CalculationError> {
+        self.check_in_range(target)?;
+        // Repeated X values leave zero width intervals, so skip past those when
+        // there's a wider one that also reaches the target
+        let pairs: Vec<((X, Y), (X, Y))> = pairwise(self.interpolation_pairs())
+            .filter(|((low_x, _), (high_x, _))| high_x >= target && low_x <= target)
+            .collect();
+        let bounds = pairs
+            .iter()
+            .position(|((low_x, _), (high_x, _))| low_x < high_x)
+            .unwrap_or(0);
+        match pairs.into_iter().nth(bounds) {
+            Some((store_low, store_high)) => Y::interpolate_between(store_low, store_high, target),
+            None => Err(CalculationError::TooFewPoints {
+                required: 2,
+                found: 1,
+            }),
+        }
+    }
+
+    // Walks the curve in its own order rather than sorting by X, so a curve that
+    // doubles back on itself gives one candidate for each time it reaches the target
+    fn interpolation_candidates(&self, target: &X) -> Result<Vec<Y>, CalculationError> {
+        pairwise(self.as_interpolation_vec())
+            .enumerate()
+            .filter_map(|(index, (start, end))| {
+                // A target landing on a shared point is only counted once
+                let counted_by_previous = index > 0 && &start.0 == target;
+                let (low, high) = if start.0 <= end.0 {
+                    (start, end)
+                } else {
+                    (end, start)
+                };
+                let brackets_target = &low.0 <= target && target <= &high.0;
+                (brackets_target && !counted_by_previous)
+                    .then(|| Y::interpolate_between(low, high, target))
+            })
+            .collect()
+    }
+
+    fn interpolate_with<S: CurveFitStrategy>(
+        &self,
+        strategy: &S,
+        target: &X,
+    ) -> Result<Y, CalculationError>
+    where
+        Y: Fittable<X>,
+    {
+        self.check_in_range(target)?;
+        Y::fit_at(strategy, &self.interpolation_pairs(), target)
+    }
+
+    // Straight lines between the determinations unless a smoother fit is asked for
+    fn interpolate_using(&self, fit: FitStrategy, target: &X) -> Result<Y, CalculationError>
+    where
+        Y: Fittable<X>,
+    {
+        match fit {
+            FitStrategy::PiecewiseLinear => self.interpolate(target),
+            FitStrategy::MonotoneCubic => self.interpolate_with(&MonotoneCubic, target),
+            FitStrategy::NaturalCubicSpline => self.interpolate_with(&NaturalCubicSpline, target),
+        }
+    }
+
+    // Refits the curve and compares it against every point it was fitted through
+    fn fit_report_with<S: CurveFitStrategy>(
+        &self,
+        strategy: &S,
+    ) -> Result<FitReport, CalculationError>
+    where
+        Y: Fittable<X> + MeanErrorSquareComparable,
+    {
+        let pairs = self.interpolation_pairs();
+        let residuals = pairs
+            .iter()
+            .map(|(x, y)| Ok(Y::fit_at(strategy, &pairs, x)?.error_from(y)))
+            .collect::<Result<Vec<f64>, CalculationError>>()?;
+        Ok(FitReport::from_residuals(residuals))
+    }
 }
 
 impl<X, Y, OP> InterpolableFanCurve<X, Y> for FanCurve<OP>
@@ -90,7 +182,7 @@ where
     OP: Clone + AsRef<X>,
     (X, Y): From<OP>,
     Y: Clone + Interpolable<X, Y>,
-    X: Clone + PartialOrd,
+    X: Clone + PartialOrd + Scalar,
 {
     fn as_interpolation_vec(&self) -> Vec<(X, Y)> {
         self.clone()
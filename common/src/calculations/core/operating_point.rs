@@ -1,8 +1,10 @@
 use tuple_list::TupleList;
 
 use crate::calculations::traits::{
-    Interpolable, Lenable, MeanErrorSquareComparable, ScalesTo, ScalesWith,
+    CurveFitStrategy, Fittable, Interpolable, Lenable, MeanErrorSquareComparable, ScalesTo,
+    ScalesWith,
 };
+use crate::calculations::CalculationError;
 
 #[derive(Clone)]
 pub struct OperatingPoint<Tup: TupleList>(pub Tup);
@@ -36,12 +38,12 @@ where
         (low_x, OperatingPoint(low_tup)): (X, OperatingPoint<Tup>),
         (high_x, OperatingPoint(high_tup)): (X, OperatingPoint<Tup>),
         target: &X,
-    ) -> OperatingPoint<Tup> {
-        OperatingPoint(Tup::interpolate_between(
+    ) -> Result<OperatingPoint<Tup>, CalculationError> {
+        Ok(OperatingPoint(Tup::interpolate_between(
             (low_x, low_tup),
             (high_x, high_tup),
             target,
-        ))
+        )?))
     }
 }
 
@@ -56,3 +58,21 @@ impl<Tup: TupleList + Lenable + MeanErrorSquareComparable> MeanErrorSquareCompar
         self.0.error_sum(&other.0)
     }
 }
+
+impl<X, Tup> Fittable<X> for OperatingPoint<Tup>
+where
+    X: Clone,
+    Tup: Fittable<X> + TupleList + Clone,
+{
+    fn fit_at<S: CurveFitStrategy>(
+        strategy: &S,
+        samples: &[(X, OperatingPoint<Tup>)],
+        target: &X,
+    ) -> Result<OperatingPoint<Tup>, CalculationError> {
+        let tup_samples: Vec<(X, Tup)> = samples
+            .iter()
+            .map(|(x, OperatingPoint(tup))| (x.clone(), tup.clone()))
+            .collect();
+        Ok(OperatingPoint(Tup::fit_at(strategy, &tup_samples, target)?))
+    }
+}
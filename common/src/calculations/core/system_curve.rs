@@ -0,0 +1,28 @@
+use crate::calculations::units::{InletAirflow, StaticPressure};
+
+// A duct system's resistance, SP = k·CFM², with k in in. wg per cfm²
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SystemCurve {
+    coefficient: f64,
+}
+
+impl SystemCurve {
+    pub fn new(coefficient: f64) -> Self {
+        SystemCurve { coefficient }
+    }
+
+    // The curve passing through a single design duty point
+    pub fn through(inlet_airflow: &InletAirflow, static_pressure: &StaticPressure) -> Self {
+        SystemCurve {
+            coefficient: static_pressure.inches() / inlet_airflow.cfm().powi(2),
+        }
+    }
+
+    pub fn coefficient(&self) -> f64 {
+        self.coefficient
+    }
+
+    pub fn static_pressure_at(&self, inlet_airflow: &InletAirflow) -> StaticPressure {
+        StaticPressure::from_inches(self.coefficient * inlet_airflow.cfm().powi(2))
+    }
+}
@@ -0,0 +1,112 @@
+mod monotone_cubic;
+pub use monotone_cubic::MonotoneCubic;
+mod natural_cubic_spline;
+pub use natural_cubic_spline::NaturalCubicSpline;
+mod piecewise_cubic;
+pub use piecewise_cubic::PiecewiseCubic;
+mod piecewise_linear;
+pub use piecewise_linear::PiecewiseLinear;
+mod polynomial_least_squares;
+pub use polynomial_least_squares::{Polynomial, PolynomialLeastSquares};
+
+use serde::{Deserialize, Serialize};
+
+use crate::calculations::CalculationError;
+
+// How ratings read between determinations. Least squares fits don't pass through
+// the tested points, so they're only for comparing fit quality, not for rating.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+pub enum FitStrategy {
+    #[default]
+    #[serde(rename = "piecewise_linear")]
+    PiecewiseLinear,
+    #[serde(rename = "monotone_cubic")]
+    MonotoneCubic,
+    #[serde(rename = "natural_cubic_spline")]
+    NaturalCubicSpline,
+}
+
+impl FitStrategy {
+    pub fn all_options() -> Vec<Self> {
+        vec![
+            FitStrategy::PiecewiseLinear,
+            FitStrategy::MonotoneCubic,
+            FitStrategy::NaturalCubicSpline,
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FitStrategy::PiecewiseLinear => "piecewise_linear",
+            FitStrategy::MonotoneCubic => "monotone_cubic",
+            FitStrategy::NaturalCubicSpline => "natural_cubic_spline",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FitStrategy::PiecewiseLinear => "Straight lines",
+            FitStrategy::MonotoneCubic => "Monotone cubic",
+            FitStrategy::NaturalCubicSpline => "Natural cubic spline",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseFitStrategyError;
+
+impl TryFrom<&str> for FitStrategy {
+    type Error = ParseFitStrategyError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        FitStrategy::all_options()
+            .into_iter()
+            .find(|fit| fit.as_str() == value)
+            .ok_or(ParseFitStrategyError)
+    }
+}
+
+// How far a fit strays from the determinations it was built from
+#[derive(Debug, Clone, PartialEq)]
+pub struct FitReport {
+    pub residuals: Vec<f64>,
+    pub mean_square_error: f64,
+}
+
+impl FitReport {
+    pub fn from_residuals(residuals: Vec<f64>) -> Self {
+        let mean_square_error = if residuals.is_empty() {
+            0.0
+        } else {
+            residuals.iter().sum::<f64>() / residuals.len() as f64
+        };
+        FitReport {
+            residuals,
+            mean_square_error,
+        }
+    }
+}
+
+fn validate_samples(samples: &[(f64, f64)], minimum_count: usize) -> Result<(), CalculationError> {
+    if samples.len() < minimum_count {
+        return Err(CalculationError::TooFewPoints {
+            required: minimum_count,
+            found: samples.len(),
+        });
+    }
+    if samples
+        .windows(2)
+        .any(|pair| pair[0].0.partial_cmp(&pair[1].0) != Some(std::cmp::Ordering::Less))
+    {
+        return Err(CalculationError::NonMonotonic);
+    }
+    Ok(())
+}
+
+// Slopes of the straight lines joining each pair of neighbouring samples
+fn secant_slopes(samples: &[(f64, f64)]) -> Vec<f64> {
+    samples
+        .windows(2)
+        .map(|pair| (pair[1].1 - pair[0].1) / (pair[1].0 - pair[0].0))
+        .collect()
+}
@@ -0,0 +1,50 @@
+use crate::calculations::traits::CurveFitStrategy;
+use crate::calculations::CalculationError;
+
+use super::{secant_slopes, validate_samples, PiecewiseCubic};
+
+// Fritsch–Carlson, never overshoots so a rising run of points stays rising
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MonotoneCubic;
+
+impl CurveFitStrategy for MonotoneCubic {
+    type Fitted = PiecewiseCubic;
+
+    fn fit(&self, samples: &[(f64, f64)]) -> Result<PiecewiseCubic, CalculationError> {
+        validate_samples(samples, 2)?;
+        let secants = secant_slopes(samples);
+        let last = secants.len();
+
+        let mut slopes: Vec<f64> = (0..=last)
+            .map(|i| {
+                if i == 0 {
+                    secants[0]
+                } else if i == last {
+                    secants[last - 1]
+                } else if secants[i - 1] * secants[i] <= 0.0 {
+                    0.0
+                } else {
+                    (secants[i - 1] + secants[i]) / 2.0
+                }
+            })
+            .collect();
+
+        for (i, secant) in secants.iter().enumerate() {
+            if *secant == 0.0 {
+                slopes[i] = 0.0;
+                slopes[i + 1] = 0.0;
+                continue;
+            }
+            let alpha = slopes[i] / secant;
+            let beta = slopes[i + 1] / secant;
+            let magnitude = alpha.hypot(beta);
+            if magnitude > 3.0 {
+                let tau = 3.0 / magnitude;
+                slopes[i] = tau * alpha * secant;
+                slopes[i + 1] = tau * beta * secant;
+            }
+        }
+
+        Ok(PiecewiseCubic::from_hermite(samples, &slopes))
+    }
+}
@@ -0,0 +1,66 @@
+use crate::calculations::traits::CurveFitStrategy;
+use crate::calculations::CalculationError;
+
+use super::{secant_slopes, validate_samples, PiecewiseCubic};
+
+// Smoothest curve through every point, with no bending at either end
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct NaturalCubicSpline;
+
+impl CurveFitStrategy for NaturalCubicSpline {
+    type Fitted = PiecewiseCubic;
+
+    fn fit(&self, samples: &[(f64, f64)]) -> Result<PiecewiseCubic, CalculationError> {
+        validate_samples(samples, 2)?;
+        let secants = secant_slopes(samples);
+        let widths: Vec<f64> = samples
+            .windows(2)
+            .map(|pair| pair[1].0 - pair[0].0)
+            .collect();
+        let count = samples.len();
+
+        // Second derivatives at each knot, solved with the Thomas algorithm.
+        // The natural end conditions pin the first and last at zero.
+        let mut curvatures = vec![0.0; count];
+        if count > 2 {
+            let interior = count - 2;
+            let mut diagonal = vec![0.0; interior];
+            let mut rhs = vec![0.0; interior];
+            for row in 0..interior {
+                let i = row + 1;
+                diagonal[row] = 2.0 * (widths[i - 1] + widths[i]);
+                rhs[row] = 6.0 * (secants[i] - secants[i - 1]);
+                if row > 0 {
+                    let factor = widths[i - 1] / diagonal[row - 1];
+                    diagonal[row] -= factor * widths[i - 1];
+                    rhs[row] -= factor * rhs[row - 1];
+                }
+            }
+            for row in (0..interior).rev() {
+                let i = row + 1;
+                let next = if row + 1 < interior {
+                    curvatures[i + 1]
+                } else {
+                    0.0
+                };
+                curvatures[i] = (rhs[row] - widths[i] * next) / diagonal[row];
+            }
+        }
+
+        let coefficients = (0..count - 1)
+            .map(|i| {
+                [
+                    samples[i].1,
+                    secants[i] - widths[i] * (2.0 * curvatures[i] + curvatures[i + 1]) / 6.0,
+                    curvatures[i] / 2.0,
+                    (curvatures[i + 1] - curvatures[i]) / (6.0 * widths[i]),
+                ]
+            })
+            .collect();
+
+        Ok(PiecewiseCubic::new(
+            samples.iter().map(|(x, _y)| *x).collect(),
+            coefficients,
+        ))
+    }
+}
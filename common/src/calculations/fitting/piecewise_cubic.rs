@@ -0,0 +1,52 @@
+use crate::calculations::traits::FittedCurve;
+
+// Segment i is a + b·t + c·t² + d·t³ where t is the distance past knots[i]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PiecewiseCubic {
+    knots: Vec<f64>,
+    coefficients: Vec<[f64; 4]>,
+}
+
+impl PiecewiseCubic {
+    pub fn new(knots: Vec<f64>, coefficients: Vec<[f64; 4]>) -> Self {
+        debug_assert_eq!(knots.len(), coefficients.len() + 1);
+        PiecewiseCubic {
+            knots,
+            coefficients,
+        }
+    }
+
+    // Each segment from its end values and end slopes
+    pub fn from_hermite(samples: &[(f64, f64)], slopes: &[f64]) -> Self {
+        let coefficients = samples
+            .windows(2)
+            .zip(slopes.windows(2))
+            .map(|(points, ends)| {
+                let ((low_x, low_y), (high_x, high_y)) = (points[0], points[1]);
+                let (low_slope, high_slope) = (ends[0], ends[1]);
+                let width = high_x - low_x;
+                let secant = (high_y - low_y) / width;
+                [
+                    low_y,
+                    low_slope,
+                    (3.0 * secant - 2.0 * low_slope - high_slope) / width,
+                    (low_slope + high_slope - 2.0 * secant) / width.powi(2),
+                ]
+            })
+            .collect();
+        PiecewiseCubic::new(samples.iter().map(|(x, _y)| *x).collect(), coefficients)
+    }
+}
+
+impl FittedCurve for PiecewiseCubic {
+    fn value_at(&self, x: f64) -> f64 {
+        let segment = self
+            .knots
+            .partition_point(|knot| *knot <= x)
+            .saturating_sub(1)
+            .min(self.coefficients.len() - 1);
+        let [a, b, c, d] = self.coefficients[segment];
+        let t = x - self.knots[segment];
+        a + t * (b + t * (c + t * d))
+    }
+}
@@ -0,0 +1,25 @@
+use crate::calculations::traits::CurveFitStrategy;
+use crate::calculations::CalculationError;
+
+use super::{secant_slopes, validate_samples, PiecewiseCubic};
+
+// Straight lines between neighbouring determinations, as the standards are written
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PiecewiseLinear;
+
+impl CurveFitStrategy for PiecewiseLinear {
+    type Fitted = PiecewiseCubic;
+
+    fn fit(&self, samples: &[(f64, f64)]) -> Result<PiecewiseCubic, CalculationError> {
+        validate_samples(samples, 2)?;
+        let coefficients = samples
+            .iter()
+            .zip(secant_slopes(samples))
+            .map(|((_x, y), slope)| [*y, slope, 0.0, 0.0])
+            .collect();
+        Ok(PiecewiseCubic::new(
+            samples.iter().map(|(x, _y)| *x).collect(),
+            coefficients,
+        ))
+    }
+}
@@ -0,0 +1,92 @@
+use crate::calculations::traits::{CurveFitStrategy, FittedCurve};
+use crate::calculations::CalculationError;
+
+use super::validate_samples;
+
+// A single polynomial of the given degree, which smooths out scatter in the
+// determinations rather than passing through each one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PolynomialLeastSquares {
+    pub degree: usize,
+}
+
+// Coefficients apply to (x - centre) / spread, which keeps the normal
+// equations well conditioned for airflows in the thousands
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial {
+    centre: f64,
+    spread: f64,
+    coefficients: Vec<f64>,
+}
+
+impl FittedCurve for Polynomial {
+    fn value_at(&self, x: f64) -> f64 {
+        let u = (x - self.centre) / self.spread;
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |total, coefficient| total * u + coefficient)
+    }
+}
+
+impl CurveFitStrategy for PolynomialLeastSquares {
+    type Fitted = Polynomial;
+
+    fn fit(&self, samples: &[(f64, f64)]) -> Result<Polynomial, CalculationError> {
+        // Even a constant needs two distinct airflows to scale them by their spread
+        validate_samples(samples, (self.degree + 1).max(2))?;
+        let (low_x, high_x) = (samples[0].0, samples[samples.len() - 1].0);
+        let centre = (low_x + high_x) / 2.0;
+        let spread = (high_x - low_x) / 2.0;
+
+        let size = self.degree + 1;
+        let mut normal = vec![vec![0.0; size + 1]; size];
+        for (x, y) in samples {
+            let u = (x - centre) / spread;
+            let powers: Vec<f64> = (0..size).map(|power| u.powi(power as i32)).collect();
+            for row in 0..size {
+                for col in 0..size {
+                    normal[row][col] += powers[row] * powers[col];
+                }
+                normal[row][size] += powers[row] * y;
+            }
+        }
+
+        Ok(Polynomial {
+            centre,
+            spread,
+            coefficients: solve_augmented(normal)?,
+        })
+    }
+}
+
+// Gaussian elimination with partial pivoting on an augmented matrix
+fn solve_augmented(mut matrix: Vec<Vec<f64>>) -> Result<Vec<f64>, CalculationError> {
+    let size = matrix.len();
+    for col in 0..size {
+        let pivot = (col..size)
+            .max_by(|a, b| matrix[*a][col].abs().total_cmp(&matrix[*b][col].abs()))
+            .unwrap_or(col);
+        if matrix[pivot][col].abs() < f64::EPSILON {
+            return Err(CalculationError::SingularFit);
+        }
+        matrix.swap(col, pivot);
+        let (upper, lower) = matrix.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for row in lower.iter_mut() {
+            let factor = row[col] / pivot_row[col];
+            for (value, pivot_value) in row.iter_mut().zip(pivot_row).skip(col) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+
+    let mut solution = vec![0.0; size];
+    for row in (0..size).rev() {
+        let known: f64 = (row + 1..size)
+            .map(|col| matrix[row][col] * solution[col])
+            .sum();
+        solution[row] = (matrix[row][size] - known) / matrix[row][row];
+    }
+    Ok(solution)
+}
@@ -0,0 +1,238 @@
+use crate::calculations::{
+    core::FanCurve,
+    fitting::FitStrategy,
+    standards::{A1InterpolationPoint, A1OperatingPoint, CanFindA1OperatingPoint},
+    units::{BrakeHorsepower, FanDiameter, FanSpeed, InletAirflow, StaticPressure},
+    CalculationError,
+};
+
+// What the wheel and motor are rated for, cells needing more are left blank
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RatingLimits {
+    pub max_fan_speed: Option<FanSpeed>,
+    pub max_brake_horsepower: Option<BrakeHorsepower>,
+}
+
+impl RatingLimits {
+    pub fn allow(&self, operating_point: &A1InterpolationPoint) -> bool {
+        let fan_speed: &FanSpeed = operating_point.as_ref();
+        let brake_horsepower: &BrakeHorsepower = operating_point.as_ref();
+        self.max_fan_speed
+            .iter()
+            .all(|max| fan_speed.rpm() <= max.rpm())
+            && self
+                .max_brake_horsepower
+                .iter()
+                .all(|max| brake_horsepower.hp() <= max.hp())
+    }
+}
+
+// Rows of airflow by columns of static pressure, as printed in a catalogue.
+// Cells are None where the size can't be rated from the reference report.
+#[derive(Clone)]
+pub struct RatingTable {
+    pub airflows: Vec<InletAirflow>,
+    pub static_pressures: Vec<StaticPressure>,
+    pub cells: Vec<Vec<Option<A1InterpolationPoint>>>,
+}
+
+// Rates one size at every airflow and static pressure using the fan laws. Duties
+// outside the tested range, or that would pass a limit, are left blank.
+pub fn rating_table<Reference: CanFindA1OperatingPoint>(
+    reference: &Reference,
+    fan_diameter: &FanDiameter,
+    airflows: &[InletAirflow],
+    static_pressures: &[StaticPressure],
+    limits: &RatingLimits,
+    fit: FitStrategy,
+) -> Result<RatingTable, CalculationError> {
+    let (min_resistance, max_resistance) = tested_resistances(reference, fan_diameter)?;
+    let cells = airflows
+        .iter()
+        .map(|inlet_airflow| {
+            static_pressures
+                .iter()
+                .map(|static_pressure| {
+                    let resistance = system_resistance(inlet_airflow, static_pressure)?;
+                    if resistance < min_resistance || resistance > max_resistance {
+                        return None;
+                    }
+                    reference
+                        .clone()
+                        .a1_operating_point_for(fan_diameter, inlet_airflow, static_pressure, fit)
+                        .ok()
+                        .filter(|operating_point| limits.allow(operating_point))
+                })
+                .collect()
+        })
+        .collect();
+    Ok(RatingTable {
+        airflows: airflows.to_vec(),
+        static_pressures: static_pressures.to_vec(),
+        cells,
+    })
+}
+
+// The fan laws slide a duty along its system curve, P = kQ², as the speed changes.
+// So a size can only be rated between the flattest and steepest system curves that
+// pass through a tested point. Shut off has no airflow to scale from, and is skipped.
+fn tested_resistances<Reference: CanFindA1OperatingPoint>(
+    reference: &Reference,
+    fan_diameter: &FanDiameter,
+) -> Result<(f64, f64), CalculationError> {
+    let tested_curve: FanCurve<A1OperatingPoint> = reference.clone().into();
+    let tested_speed: FanSpeed = *tested_curve
+        .as_ref()
+        .first()
+        .map(|op| op.as_ref() as &FanSpeed)
+        .ok_or(CalculationError::EmptyCurve)?;
+    let resistances: Vec<f64> = reference
+        .clone()
+        .a1_fan_curve_at(fan_diameter, &tested_speed)?
+        .stable_branch()
+        .as_ref()
+        .iter()
+        .filter_map(|op| system_resistance(op.as_ref(), op.as_ref()))
+        .collect();
+    if resistances.is_empty() {
+        return Err(CalculationError::EmptyCurve);
+    }
+    Ok(resistances.into_iter().fold(
+        (f64::INFINITY, f64::NEG_INFINITY),
+        |(min, max), resistance| (min.min(resistance), max.max(resistance)),
+    ))
+}
+
+fn system_resistance(
+    inlet_airflow: &InletAirflow,
+    static_pressure: &StaticPressure,
+) -> Option<f64> {
+    (inlet_airflow.cfm() > 0.0).then(|| static_pressure.inches() / inlet_airflow.cfm().powi(2))
+}
+
+// Evenly stepped rows and columns, in round numbers, out to free delivery and
+// peak pressure on the size's stable curve at the given speed
+pub fn rating_axes<Reference: CanFindA1OperatingPoint>(
+    reference: &Reference,
+    fan_diameter: &FanDiameter,
+    fan_speed: &FanSpeed,
+    rows: usize,
+    columns: usize,
+) -> Result<(Vec<InletAirflow>, Vec<StaticPressure>), CalculationError> {
+    let fan_curve: FanCurve<A1OperatingPoint> = reference
+        .clone()
+        .a1_fan_curve_at(fan_diameter, fan_speed)?
+        .stable_branch();
+    let max_of = |value_of: fn(&A1OperatingPoint) -> f64| {
+        fan_curve.as_ref().iter().map(value_of).fold(0.0, f64::max)
+    };
+    let free_delivery = max_of(|op| (op.as_ref() as &InletAirflow).cfm());
+    let peak_pressure = max_of(|op| (op.as_ref() as &StaticPressure).inches());
+    Ok((
+        round_steps_up_to(free_delivery, rows)
+            .into_iter()
+            .map(InletAirflow::from_cfm)
+            .collect(),
+        round_steps_up_to(peak_pressure, columns)
+            .into_iter()
+            .map(StaticPressure::from_inches)
+            .collect(),
+    ))
+}
+
+// Up to count multiples of a 1, 2, 2.5 or 5 step, none past max
+fn round_steps_up_to(max: f64, count: usize) -> Vec<f64> {
+    if count == 0 || !(max.is_finite() && max > 0.0) {
+        return vec![];
+    }
+    let raw_step = max / count as f64;
+    let magnitude = 10f64.powf(raw_step.log10().floor());
+    let step = [1.0, 2.0, 2.5, 5.0, 10.0]
+        .into_iter()
+        .map(|multiple| multiple * magnitude)
+        .find(|step| *step >= raw_step)
+        .unwrap_or(10.0 * magnitude);
+    (1..=count)
+        .map(|i| step * i as f64)
+        .filter(|value| *value <= max)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::test_report;
+
+    use super::*;
+
+    #[test]
+    fn it_steps_in_round_numbers() {
+        assert_eq!(
+            round_steps_up_to(11077.0, 5),
+            vec![2500.0, 5000.0, 7500.0, 10000.0]
+        );
+        assert_eq!(round_steps_up_to(6.839, 4), vec![2.0, 4.0, 6.0]);
+        assert_eq!(round_steps_up_to(0.0, 4), Vec::<f64>::new());
+    }
+
+    #[test]
+    fn it_rates_the_tested_size_and_blanks_what_it_cant() {
+        let report = test_report();
+        let diameter = FanDiameter::from_inches(27.0);
+        let airflows = [
+            InletAirflow::from_cfm(7749.0),
+            InletAirflow::from_cfm(1000.0),
+        ];
+        let static_pressures = [
+            StaticPressure::from_inches(3.789),
+            StaticPressure::from_inches(12.0),
+        ];
+
+        let unlimited = rating_table(
+            &report,
+            &diameter,
+            &airflows,
+            &static_pressures,
+            &RatingLimits::default(),
+            FitStrategy::default(),
+        )
+        .unwrap();
+        let tested = unlimited.cells[0][0].as_ref().unwrap();
+        assert!(((tested.as_ref() as &FanSpeed).rpm() - 1750.0).abs() < 1.0);
+        assert!(((tested.as_ref() as &BrakeHorsepower).hp() - 7.481).abs() < 0.01);
+        // Far more pressure than the curve makes at this airflow, at any speed
+        assert!(unlimited.cells[1][1].is_none());
+
+        let limited = rating_table(
+            &report,
+            &diameter,
+            &airflows,
+            &static_pressures,
+            &RatingLimits {
+                max_fan_speed: Some(FanSpeed::from_rpm(1700.0)),
+                max_brake_horsepower: None,
+            },
+            FitStrategy::default(),
+        )
+        .unwrap();
+        assert!(limited.cells[0][0].is_none());
+    }
+
+    #[test]
+    fn it_lays_out_axes_from_the_scaled_curve() {
+        let (airflows, static_pressures) = rating_axes(
+            &test_report(),
+            &FanDiameter::from_inches(27.0),
+            &FanSpeed::from_rpm(1750.0),
+            5,
+            4,
+        )
+        .unwrap();
+        let cfms: Vec<f64> = airflows.iter().map(InletAirflow::cfm).collect();
+        let inches: Vec<f64> = static_pressures
+            .iter()
+            .map(StaticPressure::inches)
+            .collect();
+        assert_eq!(cfms, vec![2500.0, 5000.0, 7500.0, 10000.0]);
+        assert_eq!(inches, vec![2.0, 4.0, 6.0]);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    calculations::{
+        fitting::FitStrategy,
+        standards::{A1Selection, CanFindA1OperatingPoint},
+        units::{BrakeHorsepower, FanDiameter, InletAirflow, StaticPressure},
+    },
+    models::{A1Standard2010Report, FanSize},
+};
+
+#[derive(Clone)]
+pub struct FanSelection<FanSeriesRepr> {
+    pub fan_size: FanSize<FanSeriesRepr>,
+    pub a1_report_id: String,
+    pub selection: A1Selection,
+}
+
+// Every fan size is rated from each A1 report in its series using the fan laws.
+// Sizes that can't reach the duty point are left out, and the rest are ranked
+// from lowest to highest brake horsepower.
+pub fn select_fans<FanSeriesRepr: Clone>(
+    fan_sizes: &[FanSize<FanSeriesRepr>],
+    reports: &[A1Standard2010Report<FanSize<FanSeriesRepr>>],
+    inlet_airflow: &InletAirflow,
+    static_pressure: &StaticPressure,
+    fit: FitStrategy,
+) -> Vec<FanSelection<FanSeriesRepr>> {
+    let mut selections: Vec<FanSelection<FanSeriesRepr>> = fan_sizes
+        .iter()
+        .flat_map(|fan_size| {
+            reports
+                .iter()
+                .filter(|report| report.fan_size.fan_series_id == fan_size.fan_series_id)
+                .filter_map(|report| {
+                    report
+                        .clone()
+                        .a1_selection_for(
+                            &FanDiameter::from_inches(fan_size.diameter),
+                            inlet_airflow,
+                            static_pressure,
+                            fit,
+                        )
+                        .ok()
+                        .map(|selection| FanSelection {
+                            fan_size: fan_size.clone(),
+                            a1_report_id: report.id.clone(),
+                            selection,
+                        })
+                })
+        })
+        .collect();
+
+    selections.sort_by(|a, b| {
+        let a_bhp: &BrakeHorsepower = a.selection.operating_point.as_ref();
+        let b_bhp: &BrakeHorsepower = b.selection.operating_point.as_ref();
+        a_bhp.hp().total_cmp(&b_bhp.hp())
+    });
+    selections
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{calculations::units::FanSpeed, models::test_report};
+
+    use super::*;
+
+    #[test]
+    fn it_ranks_sizes_by_power() {
+        let report = test_report();
+        let fan_size = |id: &str, diameter: f64| FanSize {
+            id: id.to_string(),
+            diameter,
+            ..report.fan_size.clone()
+        };
+        let fan_sizes = vec![
+            report.fan_size.clone(),
+            fan_size("DMF-250", 36.0),
+            fan_size("DMF-050", 12.0),
+        ];
+
+        let selections = select_fans(
+            &fan_sizes,
+            &[report],
+            &InletAirflow::from_cfm(7749.0),
+            &StaticPressure::from_inches(3.789),
+            FitStrategy::default(),
+        );
+
+        assert_eq!(selections.len(), 3);
+        let bhps: Vec<f64> = selections
+            .iter()
+            .map(|selection| {
+                let bhp: &BrakeHorsepower = selection.selection.operating_point.as_ref();
+                bhp.hp()
+            })
+            .collect();
+        assert!(bhps.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        // The tested size at a tested point is the tested speed
+        let tested = selections
+            .iter()
+            .find(|selection| selection.fan_size.id == "DMF-150")
+            .unwrap();
+        let tested_speed: &FanSpeed = tested.selection.operating_point.as_ref();
+        assert!((tested_speed.rpm() - 1750.0).abs() < 1.0);
+        assert!((tested.selection.static_pressure_margin - (6.839 - 3.789) / 6.839).abs() < 1e-3);
+    }
+}
@@ -1,5 +1,9 @@
 mod a1_2010;
-pub use a1_2010::{A1InterpolationPoint, A1OperatingPoint, CanFindA1OperatingPoint};
+pub use a1_2010::{
+    fan_speeds_between, A1Candidate, A1CurveFamily, A1InterpolationPoint, A1OperatingPoint,
+    A1Selection, CanFindA1OperatingPoint,
+};
 mod a2_2010;
 pub use a2_2010::{A1A2OperatingPoint, A2OperatingPoint, CanProduceA1A2Curve};
 mod s1_2010;
+pub use s1_2010::{CanFindS1SoundRating, S1InterpolationPoint, S1OperatingPoint};
@@ -1,11 +1,18 @@
 use tuple_list::tuple_list;
 use tuple_list::tuple_list_type;
 
-use crate::calculations::core::{FanCurve, InterpolableFanCurve, OperatingPoint};
+use crate::calculations::core::{FanCurve, InterpolableFanCurve, OperatingPoint, SystemCurve};
+use crate::calculations::fitting::FitStrategy;
 use crate::calculations::traits::{indexing, ScalesTo, ScalesWith};
 use crate::calculations::units::{
-    BrakeHorsepower, FanDiameter, FanSpeed, InletAirflow, StaticPressure,
+    AirDensity, AirHorsepower, BrakeHorsepower, Efficiency, FanDiameter, FanSpeed, InletAirflow,
+    OutletArea, OutletVelocity, StaticPressure, TotalPressure, VelocityPressure,
 };
+use crate::calculations::CalculationError;
+use crate::util::pairwise;
+
+mod curve_family;
+pub use curve_family::{fan_speeds_between, A1CurveFamily};
 
 // #[derive(Debug, Clone, PartialEq)]
 pub type A1OperatingPoint =
@@ -15,6 +22,34 @@ impl A1OperatingPoint {
     pub fn new(fs: FanSpeed, ia: InletAirflow, sp: StaticPressure, bhp: BrakeHorsepower) -> Self {
         OperatingPoint(tuple_list!(fs, ia, sp, bhp))
     }
+
+    pub fn static_air_horsepower(&self) -> AirHorsepower {
+        AirHorsepower::from_static_pressure(self.as_ref(), self.as_ref())
+    }
+
+    pub fn outlet_velocity(&self, outlet_area: &OutletArea) -> OutletVelocity {
+        OutletVelocity::from_airflow(self.as_ref(), outlet_area)
+    }
+
+    pub fn velocity_pressure(&self, outlet_area: &OutletArea) -> VelocityPressure {
+        VelocityPressure::from_outlet_velocity(&self.outlet_velocity(outlet_area))
+    }
+
+    pub fn total_pressure(&self, outlet_area: &OutletArea) -> TotalPressure {
+        TotalPressure::from_pressures(self.as_ref(), &self.velocity_pressure(outlet_area))
+    }
+
+    pub fn total_air_horsepower(&self, outlet_area: &OutletArea) -> AirHorsepower {
+        AirHorsepower::from_total_pressure(self.as_ref(), &self.total_pressure(outlet_area))
+    }
+
+    pub fn static_efficiency(&self) -> Efficiency {
+        Efficiency::from_horsepowers(&self.static_air_horsepower(), self.as_ref())
+    }
+
+    pub fn total_efficiency(&self, outlet_area: &OutletArea) -> Efficiency {
+        Efficiency::from_horsepowers(&self.total_air_horsepower(outlet_area), self.as_ref())
+    }
 }
 
 impl AsRef<FanSpeed> for A1OperatingPoint {
@@ -38,6 +73,144 @@ impl AsRef<BrakeHorsepower> for A1OperatingPoint {
     }
 }
 
+impl FanCurve<A1OperatingPoint> {
+    pub fn total_pressures(
+        &self,
+        outlet_area: &OutletArea,
+    ) -> Vec<(StaticPressure, TotalPressure)> {
+        self.as_ref()
+            .iter()
+            .map(|op| (*op.as_ref(), op.total_pressure(outlet_area)))
+            .collect()
+    }
+
+    pub fn peak_static_efficiency(&self) -> Option<&A1OperatingPoint> {
+        self.as_ref().iter().max_by(|a, b| {
+            a.static_efficiency()
+                .fraction()
+                .total_cmp(&b.static_efficiency().fraction())
+        })
+    }
+
+    pub fn peak_total_efficiency(&self, outlet_area: &OutletArea) -> Option<&A1OperatingPoint> {
+        self.as_ref().iter().max_by(|a, b| {
+            a.total_efficiency(outlet_area)
+                .fraction()
+                .total_cmp(&b.total_efficiency(outlet_area).fraction())
+        })
+    }
+
+    pub fn sorted_by_airflow(&self) -> FanCurve<A1OperatingPoint> {
+        let mut points = self.as_ref().clone();
+        points.sort_by(|a, b| {
+            let a_airflow: &InletAirflow = a.as_ref();
+            let b_airflow: &InletAirflow = b.as_ref();
+            a_airflow.cfm().total_cmp(&b_airflow.cfm())
+        });
+        points.into_iter().collect()
+    }
+
+    // Throttling a fan back from free delivery raises its pressure until it
+    // stalls. Past this airflow the pressure stops rising, and the curve is no
+    // longer a reliable way to rate the fan. None when it never stalls in test.
+    pub fn stall_airflow(&self) -> Option<InletAirflow> {
+        let points = self.sorted_by_airflow().into_iter().collect::<Vec<_>>();
+        let static_pressure_at =
+            |index: usize| (points[index].as_ref() as &StaticPressure).inches();
+        (1..points.len())
+            .rev()
+            .find(|index| static_pressure_at(index - 1) <= static_pressure_at(*index))
+            .map(|peak_index| *points[peak_index].as_ref())
+    }
+
+    // From peak pressure out to free delivery, ordered by airflow
+    pub fn stable_branch(&self) -> FanCurve<A1OperatingPoint> {
+        let stall_airflow = self.stall_airflow();
+        self.sorted_by_airflow()
+            .into_iter()
+            .filter(|op| match stall_airflow {
+                Some(stall_airflow) => (op.as_ref() as &InletAirflow).cfm() >= stall_airflow.cfm(),
+                None => true,
+            })
+            .collect()
+    }
+
+    // From shut off up to peak pressure, ordered by airflow. Shares the peak with
+    // the stable branch, and is empty when the fan never stalls in test.
+    pub fn stall_branch(&self) -> FanCurve<A1OperatingPoint> {
+        match self.stall_airflow() {
+            Some(stall_airflow) => self
+                .sorted_by_airflow()
+                .into_iter()
+                .filter(|op| (op.as_ref() as &InletAirflow).cfm() <= stall_airflow.cfm())
+                .collect(),
+            None => FanCurve::from_iter(vec![]),
+        }
+    }
+
+    pub fn is_stalled_at(&self, inlet_airflow: &InletAirflow) -> bool {
+        self.stall_airflow()
+            .map(|stall_airflow| inlet_airflow.cfm() < stall_airflow.cfm())
+            .unwrap_or(false)
+    }
+
+    // Where the duct system's resistance meets the fan's pressure. Stall dips can
+    // cross a system curve several times, and there's no single answer then.
+    pub fn system_curve_intersection(
+        &self,
+        system_curve: &SystemCurve,
+    ) -> Result<A1OperatingPoint, CalculationError> {
+        let surplus_pressure = |op: &A1OperatingPoint| {
+            let static_pressure: &StaticPressure = op.as_ref();
+            (*static_pressure - system_curve.static_pressure_at(op.as_ref())).inches()
+        };
+
+        let points = self.sorted_by_airflow().into_iter().collect();
+        let crossings: Vec<(A1OperatingPoint, A1OperatingPoint)> = pairwise(points)
+            .filter(|(low, high)| (surplus_pressure(low) <= 0.0) != (surplus_pressure(high) <= 0.0))
+            .collect();
+
+        match crossings.as_slice() {
+            [] => Err(CalculationError::NoIntersection),
+            [(low, high)] => {
+                let (mut low_fraction, mut high_fraction) = (0.0, 1.0);
+                let low_surplus_is_positive = surplus_pressure(low) > 0.0;
+                for _ in 0..64 {
+                    let fraction = (low_fraction + high_fraction) / 2.0;
+                    let surplus_is_positive =
+                        surplus_pressure(&operating_point_between(low, high, fraction)) > 0.0;
+                    if surplus_is_positive == low_surplus_is_positive {
+                        low_fraction = fraction;
+                    } else {
+                        high_fraction = fraction;
+                    }
+                }
+                Ok(operating_point_between(
+                    low,
+                    high,
+                    (low_fraction + high_fraction) / 2.0,
+                ))
+            }
+            crossings => Err(CalculationError::AmbiguousIntersection {
+                crossings: crossings.len(),
+            }),
+        }
+    }
+}
+
+fn operating_point_between(
+    OperatingPoint((low_fs, (low_ia, (low_sp, (low_bhp, ()))))): &A1OperatingPoint,
+    OperatingPoint((high_fs, (high_ia, (high_sp, (high_bhp, ()))))): &A1OperatingPoint,
+    fraction: f64,
+) -> A1OperatingPoint {
+    A1OperatingPoint::new(
+        low_fs + &((high_fs - low_fs) * fraction),
+        low_ia + &((high_ia - low_ia) * fraction),
+        low_sp + &((high_sp - low_sp) * fraction),
+        low_bhp + &((high_bhp - low_bhp) * fraction),
+    )
+}
+
 pub type A1InterpolationPoint = OperatingPoint<tuple_list_type!(FanSpeed, BrakeHorsepower)>;
 
 impl A1InterpolationPoint {
@@ -46,18 +219,48 @@ impl A1InterpolationPoint {
     }
 }
 
+impl AsRef<FanSpeed> for A1InterpolationPoint {
+    fn as_ref(&self) -> &FanSpeed {
+        indexing::first(&self.0)
+    }
+}
+impl AsRef<BrakeHorsepower> for A1InterpolationPoint {
+    fn as_ref(&self) -> &BrakeHorsepower {
+        indexing::second(&self.0)
+    }
+}
+
 impl From<A1OperatingPoint> for (StaticPressure, A1InterpolationPoint) {
     fn from(OperatingPoint((fs, (_ia, (sp, (bhp, ()))))): A1OperatingPoint) -> Self {
         (sp, A1InterpolationPoint::new(fs, bhp))
     }
 }
 
+// One way a fan can meet a duty. Stalled candidates sit left of peak pressure,
+// where the fan can hunt between operating points.
+#[derive(Clone)]
+pub struct A1Candidate {
+    pub operating_point: A1InterpolationPoint,
+    pub stalled: bool,
+}
+
+// A catalogue selection, with how far the required static pressure sits below
+// the peak of the fan curve at the selected speed, as a fraction of that peak
+#[derive(Clone)]
+pub struct A1Selection {
+    pub operating_point: A1InterpolationPoint,
+    pub static_pressure_margin: f64,
+}
+
 pub trait CanFindA1OperatingPoint
 where
     Self: Clone,
     Self: Into<FanCurve<A1OperatingPoint>>,
     Self: Into<FanDiameter>,
+    Self: Into<AirDensity>,
     FanCurve<A1OperatingPoint>: ScalesWith<FanDiameter>,
+    FanCurve<A1OperatingPoint>: ScalesWith<AirDensity>,
+    FanCurve<A1OperatingPoint>: ScalesWith<FanSpeed>,
     FanCurve<A1OperatingPoint>: ScalesTo<InletAirflow>,
     FanCurve<A1OperatingPoint>: InterpolableFanCurve<StaticPressure, A1InterpolationPoint>,
 {
@@ -66,13 +269,152 @@ where
         fan_diameter: &FanDiameter,
         inlet_airflow: &InletAirflow,
         static_pressure: &StaticPressure,
-    ) -> Result<A1InterpolationPoint, String> {
+        fit: FitStrategy,
+    ) -> Result<A1InterpolationPoint, CalculationError> {
+        self.a1_operating_point_at_density_for(
+            fan_diameter,
+            inlet_airflow,
+            static_pressure,
+            &AirDensity::standard(),
+            fit,
+        )
+    }
+
+    // Corrects the tested curve from the air density in the lab to the one on site
+    fn a1_operating_point_at_density_for(
+        self,
+        fan_diameter: &FanDiameter,
+        inlet_airflow: &InletAirflow,
+        static_pressure: &StaticPressure,
+        air_density: &AirDensity,
+        fit: FitStrategy,
+    ) -> Result<A1InterpolationPoint, CalculationError> {
+        CalculationError::check_airflow(inlet_airflow)?;
         let reference_fan_diameter: FanDiameter = self.clone().into();
+        let reference_air_density: AirDensity = self.clone().into();
 
+        // Only the stable branch is used, as the stall region can reach the same
+        // pressure at a second, lower speed. Shut off can't be scaled to deliver any airflow
         let fan_curve: FanCurve<A1OperatingPoint> = self.into();
         fan_curve
+            .stable_branch()
+            .into_iter()
+            .filter(|op| (op.as_ref() as &InletAirflow).cfm() > 0.0)
+            .collect::<FanCurve<A1OperatingPoint>>()
             .scale(&reference_fan_diameter, fan_diameter)
+            .scale(&reference_air_density, air_density)
             .scale_to(inlet_airflow)
-            .interpolate(static_pressure)
+            .interpolate_using(fit, static_pressure)
+    }
+
+    // Every speed at which the fan meets the duty, stalled or not
+    fn a1_operating_point_candidates_for(
+        self,
+        fan_diameter: &FanDiameter,
+        inlet_airflow: &InletAirflow,
+        static_pressure: &StaticPressure,
+    ) -> Result<Vec<A1Candidate>, CalculationError> {
+        CalculationError::check_airflow(inlet_airflow)?;
+        let reference_fan_diameter: FanDiameter = self.clone().into();
+        let reference_air_density: AirDensity = self.clone().into();
+
+        let fan_curve: FanCurve<A1OperatingPoint> = self.into();
+        let candidates_on = |branch: FanCurve<A1OperatingPoint>, stalled: bool| {
+            // Shut off can't be scaled to deliver any airflow
+            branch
+                .into_iter()
+                .filter(|op| (op.as_ref() as &InletAirflow).cfm() > 0.0)
+                .collect::<FanCurve<A1OperatingPoint>>()
+                .scale(&reference_fan_diameter, fan_diameter)
+                .scale(&reference_air_density, &AirDensity::standard())
+                .scale_to(inlet_airflow)
+                .interpolation_candidates(static_pressure)
+                .map(|operating_points| {
+                    operating_points
+                        .into_iter()
+                        .map(|operating_point| A1Candidate {
+                            operating_point,
+                            stalled,
+                        })
+                        .collect::<Vec<_>>()
+                })
+        };
+
+        let mut candidates = candidates_on(fan_curve.stable_branch(), false)?;
+        for stall_candidate in candidates_on(fan_curve.stall_branch(), true)? {
+            // The peak belongs to both branches, keep it as stable
+            let stall_speed: &FanSpeed = stall_candidate.operating_point.as_ref();
+            if !candidates.iter().any(|candidate| {
+                (candidate.operating_point.as_ref() as &FanSpeed).rpm() == stall_speed.rpm()
+            }) {
+                candidates.push(stall_candidate);
+            }
+        }
+        Ok(candidates)
+    }
+
+    fn a1_fan_curve_at(
+        self,
+        fan_diameter: &FanDiameter,
+        fan_speed: &FanSpeed,
+    ) -> Result<FanCurve<A1OperatingPoint>, CalculationError> {
+        let reference_fan_diameter: FanDiameter = self.clone().into();
+        let reference_air_density: AirDensity = self.clone().into();
+
+        let fan_curve: FanCurve<A1OperatingPoint> = self.into();
+        let reference_fan_speed: FanSpeed = *fan_curve
+            .as_ref()
+            .first()
+            .map(|op| op.as_ref() as &FanSpeed)
+            .ok_or(CalculationError::EmptyCurve)?;
+
+        Ok(fan_curve
+            .scale(&reference_fan_diameter, fan_diameter)
+            .scale(&reference_air_density, &AirDensity::standard())
+            .scale(&reference_fan_speed, fan_speed))
+    }
+
+    // The curve redrawn at each speed for the given size, in standard air
+    fn a1_curve_family_for(
+        self,
+        fan_diameter: &FanDiameter,
+        fan_speeds: &[FanSpeed],
+    ) -> Result<A1CurveFamily, CalculationError> {
+        let fan_curve: FanCurve<A1OperatingPoint> = self.clone().into();
+        let reference_fan_speed: FanSpeed = *fan_curve
+            .as_ref()
+            .first()
+            .map(|op| op.as_ref() as &FanSpeed)
+            .ok_or(CalculationError::EmptyCurve)?;
+        self.a1_fan_curve_at(fan_diameter, &reference_fan_speed)?
+            .curve_family(fan_speeds)
+    }
+
+    fn a1_selection_for(
+        self,
+        fan_diameter: &FanDiameter,
+        inlet_airflow: &InletAirflow,
+        static_pressure: &StaticPressure,
+        fit: FitStrategy,
+    ) -> Result<A1Selection, CalculationError> {
+        let operating_point = self.clone().a1_operating_point_for(
+            fan_diameter,
+            inlet_airflow,
+            static_pressure,
+            fit,
+        )?;
+
+        let peak_static_pressure = self
+            .a1_fan_curve_at(fan_diameter, operating_point.as_ref())?
+            .as_ref()
+            .iter()
+            .map(|op| (op.as_ref() as &StaticPressure).inches())
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        Ok(A1Selection {
+            operating_point,
+            static_pressure_margin: (peak_static_pressure - static_pressure.inches())
+                / peak_static_pressure,
+        })
     }
 }
@@ -0,0 +1,168 @@
+use crate::calculations::core::FanCurve;
+use crate::calculations::traits::ScalesWith;
+use crate::calculations::units::{BrakeHorsepower, Efficiency, FanSpeed};
+use crate::calculations::CalculationError;
+use crate::util::pairwise;
+
+use super::{operating_point_between, A1OperatingPoint};
+
+const TRACING_SPEEDS: usize = 41;
+
+// The tested curve redrawn at several speeds, as on a catalogue page, with lines
+// of constant power and static efficiency laid across them
+#[derive(Clone)]
+pub struct A1CurveFamily {
+    pub curves: Vec<FanCurve<A1OperatingPoint>>,
+    pub brake_horsepower_lines: Vec<(BrakeHorsepower, Vec<A1OperatingPoint>)>,
+    pub static_efficiency_contours: Vec<(Efficiency, Vec<A1OperatingPoint>)>,
+}
+
+// Evenly spaced from min to max, inclusive
+pub fn fan_speeds_between(min: &FanSpeed, max: &FanSpeed, count: usize) -> Vec<FanSpeed> {
+    match count {
+        0 => vec![],
+        1 => vec![*max],
+        _ => (0..count)
+            .map(|i| {
+                FanSpeed::from_rpm(
+                    min.rpm() + (max.rpm() - min.rpm()) * i as f64 / (count - 1) as f64,
+                )
+            })
+            .collect(),
+    }
+}
+
+impl FanCurve<A1OperatingPoint> {
+    pub fn curve_family(&self, fan_speeds: &[FanSpeed]) -> Result<A1CurveFamily, CalculationError> {
+        let reference_fan_speed: FanSpeed = *self
+            .as_ref()
+            .first()
+            .map(|op| op.as_ref() as &FanSpeed)
+            .ok_or(CalculationError::EmptyCurve)?;
+
+        let mut fan_speeds = fan_speeds.to_vec();
+        fan_speeds.sort_by(|a, b| a.rpm().total_cmp(&b.rpm()));
+        let curves_at = |fan_speeds: &[FanSpeed]| -> Vec<FanCurve<A1OperatingPoint>> {
+            fan_speeds
+                .iter()
+                .map(|fan_speed| {
+                    self.sorted_by_airflow()
+                        .scale(&reference_fan_speed, fan_speed)
+                })
+                .collect()
+        };
+        let curves = curves_at(&fan_speeds);
+        // Lines and contours are traced over many more speeds than are drawn,
+        // otherwise they'd only be found where a level happens to meet a curve
+        let tracing_curves = match (fan_speeds.first(), fan_speeds.last()) {
+            (Some(min), Some(max)) => curves_at(&fan_speeds_between(min, max, TRACING_SPEEDS)),
+            _ => vec![],
+        };
+
+        let brake_horsepower_of = |op: &A1OperatingPoint| (op.as_ref() as &BrakeHorsepower).hp();
+        let brake_horsepower_lines = brake_horsepower_levels(
+            tracing_curves
+                .iter()
+                .flat_map(|curve| curve.as_ref().iter().map(brake_horsepower_of)),
+        )
+        .into_iter()
+        .filter_map(|level| {
+            // Power dips in the stall region, so follow the free delivery side
+            let line: Vec<A1OperatingPoint> = tracing_curves
+                .iter()
+                .filter_map(|curve| crossings(curve, brake_horsepower_of, level).pop())
+                .collect();
+            (line.len() > 1).then(|| (BrakeHorsepower::from_hp(level), line))
+        })
+        .collect();
+
+        // Efficiency holds along each fan law parabola, so each level crosses a
+        // curve once either side of the peak and the contour wraps around it
+        let static_efficiency_of = |op: &A1OperatingPoint| op.static_efficiency().fraction();
+        let peak_static_efficiency = self
+            .peak_static_efficiency()
+            .map(static_efficiency_of)
+            .unwrap_or_default();
+        let static_efficiency_contours = (1..10)
+            .map(|tenths| tenths as f64 / 10.0)
+            .filter(|level| {
+                *level < peak_static_efficiency && *level > peak_static_efficiency - 0.3
+            })
+            .filter_map(|level| {
+                let crossings_by_curve: Vec<Vec<A1OperatingPoint>> = tracing_curves
+                    .iter()
+                    .map(|curve| crossings(curve, static_efficiency_of, level))
+                    .filter(|crossings| !crossings.is_empty())
+                    .collect();
+                let low_side = crossings_by_curve
+                    .iter()
+                    .filter_map(|crossings| crossings.first().cloned());
+                let high_side = crossings_by_curve
+                    .iter()
+                    .rev()
+                    .filter(|crossings| crossings.len() > 1)
+                    .filter_map(|crossings| crossings.last().cloned());
+                let contour: Vec<A1OperatingPoint> = low_side.chain(high_side).collect();
+                (contour.len() > 1).then(|| (Efficiency::from_fraction(level), contour))
+            })
+            .collect();
+
+        Ok(A1CurveFamily {
+            curves,
+            brake_horsepower_lines,
+            static_efficiency_contours,
+        })
+    }
+}
+
+// Every point along a curve, in order of airflow, where a value passes the level
+fn crossings(
+    curve: &FanCurve<A1OperatingPoint>,
+    value_of: impl Fn(&A1OperatingPoint) -> f64,
+    level: f64,
+) -> Vec<A1OperatingPoint> {
+    pairwise(curve.as_ref().clone())
+        .filter(|(low, high)| (value_of(low) < level) != (value_of(high) < level))
+        .map(|(low, high)| {
+            // Efficiency isn't linear between points, so bisect for the level
+            let low_is_below = value_of(&low) < level;
+            let (mut low_fraction, mut high_fraction) = (0.0, 1.0);
+            for _ in 0..32 {
+                let fraction = (low_fraction + high_fraction) / 2.0;
+                if (value_of(&operating_point_between(&low, &high, fraction)) < level)
+                    == low_is_below
+                {
+                    low_fraction = fraction;
+                } else {
+                    high_fraction = fraction;
+                }
+            }
+            operating_point_between(&low, &high, (low_fraction + high_fraction) / 2.0)
+        })
+        .collect()
+}
+
+// Round numbers of horsepower spread across the range the family covers
+fn brake_horsepower_levels(brake_horsepowers: impl Iterator<Item = f64>) -> Vec<f64> {
+    let (low, high) = brake_horsepowers
+        .filter(|hp| hp.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), hp| {
+            (low.min(hp), high.max(hp))
+        });
+    if low >= high {
+        return vec![];
+    }
+    let rough_step = (high - low) / 5.0;
+    let magnitude = 10_f64.powf(rough_step.log10().floor());
+    let step = [1.0, 2.0, 2.5, 5.0, 10.0]
+        .into_iter()
+        .map(|multiple| multiple * magnitude)
+        .find(|step| *step >= rough_step)
+        .unwrap_or(rough_step);
+    let first = (low / step).ceil() as i64;
+    let last = (high / step).floor() as i64;
+    (first..=last)
+        .map(|multiple| multiple as f64 * step)
+        .filter(|level| *level > 0.0)
+        .collect()
+}
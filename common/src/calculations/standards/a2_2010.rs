@@ -7,6 +7,7 @@ use crate::calculations::traits::{indexing, ScalesWith};
 use crate::calculations::units::{
     BrakeHorsepower, FanDiameter, FanSpeed, InletAirflow, OutletAirflow, StaticPressure,
 };
+use crate::calculations::CalculationError;
 
 // A2 Operating point, can determine inlet airflow from A1 results
 // Calculate an induced ratio, varies over region of interest
@@ -73,6 +74,11 @@ impl A1A2OperatingPoint {
     }
 }
 
+impl AsRef<FanSpeed> for A1A2OperatingPoint {
+    fn as_ref(&self) -> &FanSpeed {
+        indexing::first(&self.0)
+    }
+}
 impl AsRef<InletAirflow> for A1A2OperatingPoint {
     fn as_ref(&self) -> &InletAirflow {
         indexing::second(&self.0)
@@ -83,11 +89,21 @@ impl AsRef<OutletAirflow> for A1A2OperatingPoint {
         indexing::third(&self.0)
     }
 }
+impl AsRef<StaticPressure> for A1A2OperatingPoint {
+    fn as_ref(&self) -> &StaticPressure {
+        indexing::fourth(&self.0)
+    }
+}
+impl AsRef<BrakeHorsepower> for A1A2OperatingPoint {
+    fn as_ref(&self) -> &BrakeHorsepower {
+        indexing::fifth(&self.0)
+    }
+}
 
 fn augment_with_outlet_airflow(
     a1: A1OperatingPoint,
     a2: &FanCurve<A2OperatingPoint>,
-) -> Result<A1A2OperatingPoint, String> {
+) -> Result<A1A2OperatingPoint, CalculationError> {
     let OperatingPoint((fs, (ia, (sp, (bhp, ()))))) = a1;
 
     let corresponding_a2: OutletAirflow = a2.interpolate(&sp)?;
@@ -105,7 +121,7 @@ where
     fn a1_a2_fan_curve(
         self,
         fan_diameter: &FanDiameter,
-    ) -> Result<FanCurve<A1A2OperatingPoint>, String> {
+    ) -> Result<FanCurve<A1A2OperatingPoint>, CalculationError> {
         let a1_points: FanCurve<A1OperatingPoint> = FanCurve::from(self.clone());
         let a2_points: FanCurve<A2OperatingPoint> = FanCurve::from(self.clone());
 
@@ -1,22 +1,99 @@
-use crate::models::{FanSeries, FanSize};
-
-#[derive(Clone, Debug)]
-pub struct S1Standard2010Parameters {}
-
-// These will be implimented at some point, and we need to capture the knowlege
-#[allow(unused_attributes, dead_code)]
-#[derive(Clone, Debug)]
-pub struct S1Standard2010Determination {
-    rpm: f64,
-    cfm: f64,
-    static_pressure: f64,
-    brake_horsepower: f64,
-}
-// These will be implimented at some point, and we need to capture the knowlege
-#[allow(unused_attributes, dead_code)]
-#[derive(Clone, Debug)]
-pub struct S1Standard2010TestEvent {
-    fan_size: FanSize<FanSeries<()>>,
-    parameters: S1Standard2010Parameters,
-    determinations: [S1Standard2010Determination; 10],
+use tuple_list::tuple_list;
+use tuple_list::tuple_list_type;
+
+use crate::calculations::core::{FanCurve, InterpolableFanCurve, OperatingPoint};
+use crate::calculations::traits::{indexing, ScalesTo, ScalesWith};
+use crate::calculations::units::{
+    FanDiameter, FanSpeed, InletAirflow, OctaveBandSoundPower, SoundPowerLevel, StaticPressure,
+};
+use crate::calculations::CalculationError;
+
+pub type S1OperatingPoint =
+    OperatingPoint<tuple_list_type!(FanSpeed, InletAirflow, StaticPressure, OctaveBandSoundPower)>;
+
+impl S1OperatingPoint {
+    pub fn new(
+        fs: FanSpeed,
+        ia: InletAirflow,
+        sp: StaticPressure,
+        sound: OctaveBandSoundPower,
+    ) -> Self {
+        OperatingPoint(tuple_list!(fs, ia, sp, sound))
+    }
+}
+
+impl AsRef<FanSpeed> for S1OperatingPoint {
+    fn as_ref(&self) -> &FanSpeed {
+        indexing::first(&self.0)
+    }
+}
+impl AsRef<InletAirflow> for S1OperatingPoint {
+    fn as_ref(&self) -> &InletAirflow {
+        indexing::second(&self.0)
+    }
+}
+impl AsRef<StaticPressure> for S1OperatingPoint {
+    fn as_ref(&self) -> &StaticPressure {
+        indexing::third(&self.0)
+    }
+}
+impl AsRef<OctaveBandSoundPower> for S1OperatingPoint {
+    fn as_ref(&self) -> &OctaveBandSoundPower {
+        indexing::fourth(&self.0)
+    }
+}
+
+pub type S1InterpolationPoint = OperatingPoint<tuple_list_type!(FanSpeed, OctaveBandSoundPower)>;
+
+impl S1InterpolationPoint {
+    pub fn new(fs: FanSpeed, sound: OctaveBandSoundPower) -> Self {
+        OperatingPoint(tuple_list!(fs, sound))
+    }
+
+    pub fn a_weighted_sound_power(&self) -> SoundPowerLevel {
+        let sound: &OctaveBandSoundPower = self.as_ref();
+        sound.a_weighted()
+    }
+}
+
+impl AsRef<FanSpeed> for S1InterpolationPoint {
+    fn as_ref(&self) -> &FanSpeed {
+        indexing::first(&self.0)
+    }
+}
+impl AsRef<OctaveBandSoundPower> for S1InterpolationPoint {
+    fn as_ref(&self) -> &OctaveBandSoundPower {
+        indexing::second(&self.0)
+    }
+}
+
+impl From<S1OperatingPoint> for (StaticPressure, S1InterpolationPoint) {
+    fn from(OperatingPoint((fs, (_ia, (sp, (sound, ()))))): S1OperatingPoint) -> Self {
+        (sp, S1InterpolationPoint::new(fs, sound))
+    }
+}
+
+pub trait CanFindS1SoundRating
+where
+    Self: Clone,
+    Self: Into<FanCurve<S1OperatingPoint>>,
+    Self: Into<FanDiameter>,
+    FanCurve<S1OperatingPoint>: ScalesWith<FanDiameter>,
+    FanCurve<S1OperatingPoint>: ScalesTo<InletAirflow>,
+    FanCurve<S1OperatingPoint>: InterpolableFanCurve<StaticPressure, S1InterpolationPoint>,
+{
+    fn s1_sound_rating_for(
+        self,
+        fan_diameter: &FanDiameter,
+        inlet_airflow: &InletAirflow,
+        static_pressure: &StaticPressure,
+    ) -> Result<S1InterpolationPoint, CalculationError> {
+        let reference_fan_diameter: FanDiameter = self.clone().into();
+
+        let fan_curve: FanCurve<S1OperatingPoint> = self.into();
+        fan_curve
+            .scale(&reference_fan_diameter, fan_diameter)
+            .scale_to(inlet_airflow)
+            .interpolate(static_pressure)
+    }
 }
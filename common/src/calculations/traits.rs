@@ -7,3 +7,9 @@ mod interpolable;
 pub use interpolable::Interpolable;
 mod lenable;
 pub use lenable::Lenable;
+mod scalar;
+pub use scalar::Scalar;
+mod curve_fit;
+pub use curve_fit::{CurveFitStrategy, FittedCurve};
+mod fittable;
+pub use fittable::Fittable;
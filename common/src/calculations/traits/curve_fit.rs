@@ -0,0 +1,12 @@
+use crate::calculations::CalculationError;
+
+pub trait FittedCurve {
+    fn value_at(&self, x: f64) -> f64;
+}
+
+pub trait CurveFitStrategy {
+    type Fitted: FittedCurve;
+
+    // Samples must be sorted by x, with no repeated x values
+    fn fit(&self, samples: &[(f64, f64)]) -> Result<Self::Fitted, CalculationError>;
+}
@@ -0,0 +1,51 @@
+use tuple_list::TupleList;
+
+use super::{CurveFitStrategy, FittedCurve, Scalar};
+use crate::calculations::CalculationError;
+
+// Each member of a value is fitted against X on its own
+pub trait Fittable<X>
+where
+    Self: Sized,
+{
+    fn fit_at<S: CurveFitStrategy>(
+        strategy: &S,
+        samples: &[(X, Self)],
+        target: &X,
+    ) -> Result<Self, CalculationError>;
+}
+
+impl<X> Fittable<X> for () {
+    fn fit_at<S: CurveFitStrategy>(
+        _strategy: &S,
+        _samples: &[(X, ())],
+        _target: &X,
+    ) -> Result<(), CalculationError> {
+        Ok(())
+    }
+}
+
+impl<X, Head, Tail> Fittable<X> for (Head, Tail)
+where
+    X: Scalar + Clone,
+    Head: Scalar,
+    Tail: Fittable<X> + TupleList + Clone,
+{
+    fn fit_at<S: CurveFitStrategy>(
+        strategy: &S,
+        samples: &[(X, (Head, Tail))],
+        target: &X,
+    ) -> Result<(Head, Tail), CalculationError> {
+        let head_samples: Vec<(f64, f64)> = samples
+            .iter()
+            .map(|(x, (head, _tail))| (x.scalar(), head.scalar()))
+            .collect();
+        let tail_samples: Vec<(X, Tail)> = samples
+            .iter()
+            .map(|(x, (_head, tail))| (x.clone(), tail.clone()))
+            .collect();
+
+        let head = Head::from_scalar(strategy.fit(&head_samples)?.value_at(target.scalar()));
+        Ok((head, Tail::fit_at(strategy, &tail_samples, target)?))
+    }
+}
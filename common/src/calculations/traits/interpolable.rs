@@ -1,18 +1,26 @@
 use tuple_list::TupleList;
 
+use crate::calculations::CalculationError;
+
 pub trait Interpolable<X, Y>
 where
     X: Clone,
     Self: Clone,
 {
-    fn interpolate_between(low: (X, Y), high: (X, Y), target: &X) -> Y;
+    fn interpolate_between(low: (X, Y), high: (X, Y), target: &X) -> Result<Y, CalculationError>;
 }
 
 impl<X> Interpolable<X, ()> for ()
 where
     X: Clone,
 {
-    fn interpolate_between(_low: (X, ()), _high: (X, ()), _target: &X) {}
+    fn interpolate_between(
+        _low: (X, ()),
+        _high: (X, ()),
+        _target: &X,
+    ) -> Result<(), CalculationError> {
+        Ok(())
+    }
 }
 
 impl<X, Head, Tail> Interpolable<X, (Head, Tail)> for (Head, Tail)
@@ -25,18 +33,18 @@ where
         (low_static_pressure, (low_head, low_tail)): (X, (Head, Tail)),
         (high_static_pressure, (high_head, high_tail)): (X, (Head, Tail)),
         target_static_pressure: &X,
-    ) -> (Head, Tail) {
-        (
+    ) -> Result<(Head, Tail), CalculationError> {
+        Ok((
             Head::interpolate_between(
                 (low_static_pressure.clone(), low_head),
                 (high_static_pressure.clone(), high_head),
                 target_static_pressure,
-            ),
+            )?,
             Tail::interpolate_between(
                 (low_static_pressure, low_tail),
                 (high_static_pressure, high_tail),
                 target_static_pressure,
-            ),
-        )
+            )?,
+        ))
     }
 }
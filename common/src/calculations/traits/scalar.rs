@@ -0,0 +1,7 @@
+// Units are thin wrappers over an f64, this exposes that number for numeric work
+// like curve fitting that doesn't care which unit it's holding
+pub trait Scalar {
+    fn from_scalar(value: f64) -> Self;
+
+    fn scalar(&self) -> f64;
+}
@@ -1,13 +1,32 @@
+mod air_density;
+pub use air_density::AirDensity;
+mod air_horsepower;
+pub use air_horsepower::AirHorsepower;
 mod brake_horsepower;
 pub use brake_horsepower::BrakeHorsepower;
+mod efficiency;
+pub use efficiency::Efficiency;
 mod fan_diameter;
 pub use fan_diameter::FanDiameter;
 mod fan_speed;
 pub use fan_speed::FanSpeed;
 mod inlet_airflow;
 pub use inlet_airflow::InletAirflow;
+mod octave_band_sound_power;
+pub use octave_band_sound_power::{OctaveBandSoundPower, OCTAVE_BAND_CENTRE_FREQUENCIES};
 mod outlet_airflow;
 pub use outlet_airflow::OutletAirflow;
+mod outlet_area;
+pub use outlet_area::OutletArea;
+mod outlet_velocity;
+pub use outlet_velocity::OutletVelocity;
+mod sound_power_level;
+pub use sound_power_level::SoundPowerLevel;
+pub mod si;
 mod static_pressure;
 pub use static_pressure::StaticPressure;
+mod total_pressure;
+pub use total_pressure::TotalPressure;
 pub mod unit_math;
+mod velocity_pressure;
+pub use velocity_pressure::VelocityPressure;
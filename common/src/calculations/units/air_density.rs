@@ -0,0 +1,78 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    calculations::traits::{MeanErrorSquareComparable, ScalesWith},
+    impl_UnitMath,
+};
+
+use super::si::KG_PER_CUBIC_METER_PER_LB_PER_CUBIC_FOOT;
+
+// Standard air per AMCA 210, 70°F dry air at 29.92 in. Hg
+const STANDARD_LB_PER_CUBIC_FOOT: f64 = 0.075;
+const SEA_LEVEL_BAROMETRIC_IN_HG: f64 = 29.921;
+const STANDARD_TEMPERATURE_F: f64 = 70.0;
+
+#[derive(Clone, PartialEq, Debug, Copy, PartialOrd)]
+pub struct AirDensity(f64);
+impl_UnitMath!(AirDensity);
+
+impl AirDensity {
+    pub fn new(lb_per_cubic_foot: f64) -> Self {
+        Self(lb_per_cubic_foot)
+    }
+
+    pub fn from_lb_per_cubic_foot(lb_per_cubic_foot: f64) -> Self {
+        Self(lb_per_cubic_foot)
+    }
+
+    pub fn standard() -> Self {
+        Self(STANDARD_LB_PER_CUBIC_FOOT)
+    }
+
+    // Dry air, ideal gas law with the constants folded into the 1.325 factor
+    pub fn from_barometric_pressure(barometric_in_hg: f64, temperature_f: f64) -> Self {
+        Self(1.325 * barometric_in_hg / (temperature_f + 459.67))
+    }
+
+    pub fn from_temperature(temperature_f: f64) -> Self {
+        Self::from_barometric_pressure(SEA_LEVEL_BAROMETRIC_IN_HG, temperature_f)
+    }
+
+    // Barometric pressure from the standard atmosphere at the given elevation
+    pub fn from_elevation(elevation_ft: f64, temperature_f: f64) -> Self {
+        let barometric_in_hg =
+            SEA_LEVEL_BAROMETRIC_IN_HG * (1.0 - 6.8754e-6 * elevation_ft).powf(5.2559);
+        Self::from_barometric_pressure(barometric_in_hg, temperature_f)
+    }
+
+    pub fn from_standard_elevation(elevation_ft: f64) -> Self {
+        Self::from_elevation(elevation_ft, STANDARD_TEMPERATURE_F)
+    }
+
+    pub fn lb_per_cubic_foot(&self) -> f64 {
+        self.0
+    }
+
+    pub fn from_kg_per_cubic_meter(kg_per_cubic_meter: f64) -> Self {
+        Self(kg_per_cubic_meter / KG_PER_CUBIC_METER_PER_LB_PER_CUBIC_FOOT)
+    }
+
+    pub fn kg_per_cubic_meter(&self) -> f64 {
+        self.0 * KG_PER_CUBIC_METER_PER_LB_PER_CUBIC_FOOT
+    }
+}
+
+impl Default for AirDensity {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl ScalesWith<AirDensity> for AirDensity {
+    fn scale(self, from: &AirDensity, to: &AirDensity) -> Self {
+        if self != *from {
+            panic!("Tried to scale an value to an one of its own type, but not from itself")
+        }
+        *to
+    }
+}
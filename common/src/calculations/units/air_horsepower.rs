@@ -0,0 +1,49 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{calculations::traits::MeanErrorSquareComparable, impl_UnitMath};
+
+use super::si::KILOWATTS_PER_HORSEPOWER;
+use super::{InletAirflow, StaticPressure, TotalPressure};
+
+// cfm × in. wg per horsepower
+const CFM_INCHES_PER_HP: f64 = 6356.0;
+
+#[derive(Clone, PartialEq, Debug, Copy, PartialOrd)]
+pub struct AirHorsepower(f64);
+impl_UnitMath!(AirHorsepower);
+
+impl AirHorsepower {
+    pub fn new(hp: f64) -> Self {
+        Self(hp)
+    }
+
+    pub fn from_hp(hp: f64) -> Self {
+        Self(hp)
+    }
+
+    pub fn from_static_pressure(
+        inlet_airflow: &InletAirflow,
+        static_pressure: &StaticPressure,
+    ) -> Self {
+        Self(inlet_airflow.cfm() * static_pressure.inches() / CFM_INCHES_PER_HP)
+    }
+
+    pub fn from_total_pressure(
+        inlet_airflow: &InletAirflow,
+        total_pressure: &TotalPressure,
+    ) -> Self {
+        Self(inlet_airflow.cfm() * total_pressure.inches() / CFM_INCHES_PER_HP)
+    }
+
+    pub fn hp(&self) -> f64 {
+        self.0
+    }
+
+    pub fn from_kilowatts(kilowatts: f64) -> Self {
+        Self(kilowatts / KILOWATTS_PER_HORSEPOWER)
+    }
+
+    pub fn kilowatts(&self) -> f64 {
+        self.0 * KILOWATTS_PER_HORSEPOWER
+    }
+}
@@ -1,8 +1,12 @@
 use crate::calculations::traits::{Interpolable, MeanErrorSquareComparable, ScalesWith};
+use crate::calculations::CalculationError;
 use crate::impl_UnitMath;
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
-use super::{inlet_airflow::InletAirflow, static_pressure::StaticPressure, FanDiameter};
+use super::si::KILOWATTS_PER_HORSEPOWER;
+use super::{
+    inlet_airflow::InletAirflow, static_pressure::StaticPressure, AirDensity, FanDiameter, FanSpeed,
+};
 
 #[derive(Clone, PartialEq, Debug, Copy)]
 pub struct BrakeHorsepower(f64);
@@ -19,6 +23,14 @@ impl BrakeHorsepower {
     pub fn hp(&self) -> f64 {
         self.0
     }
+
+    pub fn from_kilowatts(kilowatts: f64) -> Self {
+        BrakeHorsepower(kilowatts / KILOWATTS_PER_HORSEPOWER)
+    }
+
+    pub fn kilowatts(&self) -> f64 {
+        self.0 * KILOWATTS_PER_HORSEPOWER
+    }
 }
 impl_UnitMath!(BrakeHorsepower);
 
@@ -34,19 +46,33 @@ impl ScalesWith<InletAirflow> for BrakeHorsepower {
     }
 }
 
+impl ScalesWith<FanSpeed> for BrakeHorsepower {
+    fn scale(self, &from: &FanSpeed, &to: &FanSpeed) -> Self {
+        Self(self.0 * (to / from).powi(3))
+    }
+}
+
+impl ScalesWith<AirDensity> for BrakeHorsepower {
+    fn scale(self, &from: &AirDensity, &to: &AirDensity) -> Self {
+        Self(self.0 * (to / from))
+    }
+}
+
 impl Interpolable<StaticPressure, BrakeHorsepower> for BrakeHorsepower {
     fn interpolate_between(
         (low_pressure, low_bhp): (StaticPressure, Self),
         (high_pressure, high_bhp): (StaticPressure, Self),
         required_static: &StaticPressure,
-    ) -> Self {
+    ) -> Result<Self, CalculationError> {
         //linear interpolation for horespower
 
-        if &low_pressure > required_static || &high_pressure < required_static {
-            panic!("interpolating out of bounds")
-        }
+        CalculationError::check_interval(
+            low_pressure.inches(),
+            high_pressure.inches(),
+            required_static.inches(),
+        )?;
 
         let interval_fraction = (required_static - &low_pressure) / (high_pressure - low_pressure);
-        low_bhp + (high_bhp - low_bhp) * interval_fraction
+        Ok(low_bhp + (high_bhp - low_bhp) * interval_fraction)
     }
 }
@@ -0,0 +1,39 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{calculations::traits::MeanErrorSquareComparable, impl_UnitMath};
+
+use super::{AirHorsepower, BrakeHorsepower};
+
+#[derive(Clone, PartialEq, Debug, Copy, PartialOrd)]
+pub struct Efficiency(f64);
+impl_UnitMath!(Efficiency);
+
+impl Efficiency {
+    pub fn new(fraction: f64) -> Self {
+        Self(fraction)
+    }
+
+    pub fn from_fraction(fraction: f64) -> Self {
+        Self(fraction)
+    }
+
+    // A fan drawing no power has no meaningful efficiency, so it's reported as zero
+    pub fn from_horsepowers(
+        air_horsepower: &AirHorsepower,
+        brake_horsepower: &BrakeHorsepower,
+    ) -> Self {
+        if brake_horsepower.hp() <= 0.0 {
+            Self(0.0)
+        } else {
+            Self(air_horsepower.hp() / brake_horsepower.hp())
+        }
+    }
+
+    pub fn fraction(&self) -> f64 {
+        self.0
+    }
+
+    pub fn percent(&self) -> f64 {
+        self.0 * 100.0
+    }
+}
@@ -1,5 +1,7 @@
 use std::ops::Div;
 
+use super::si::MILLIMETERS_PER_INCH;
+
 #[derive(Clone, PartialEq, Debug, Copy, PartialOrd)]
 pub struct FanDiameter(f64);
 
@@ -11,6 +13,14 @@ impl FanDiameter {
     pub fn inches(&self) -> f64 {
         self.0
     }
+
+    pub fn from_millimeters(millimeters: f64) -> Self {
+        FanDiameter(millimeters / MILLIMETERS_PER_INCH)
+    }
+
+    pub fn millimeters(&self) -> f64 {
+        self.0 * MILLIMETERS_PER_INCH
+    }
 }
 
 impl Div for FanDiameter {
@@ -1,13 +1,18 @@
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
 use crate::{
-    calculations::traits::{Interpolable, MeanErrorSquareComparable, ScalesWith},
+    calculations::{
+        traits::{Interpolable, MeanErrorSquareComparable, ScalesWith},
+        CalculationError,
+    },
     impl_UnitMath,
 };
 
-use super::{inlet_airflow::InletAirflow, static_pressure::StaticPressure, FanDiameter};
+use super::{
+    inlet_airflow::InletAirflow, static_pressure::StaticPressure, AirDensity, FanDiameter,
+};
 
-#[derive(Clone, PartialEq, Debug, Copy)]
+#[derive(Clone, PartialEq, Debug, Copy, PartialOrd)]
 pub struct FanSpeed(f64);
 impl_UnitMath!(FanSpeed);
 
@@ -35,15 +40,32 @@ impl ScalesWith<FanDiameter> for FanSpeed {
     }
 }
 
+impl ScalesWith<FanSpeed> for FanSpeed {
+    fn scale(self, from: &FanSpeed, to: &FanSpeed) -> Self {
+        if self != *from {
+            panic!("Tried to scale an value to an one of its own type, but not from itself")
+        }
+        *to
+    }
+}
+
+impl ScalesWith<AirDensity> for FanSpeed {
+    fn scale(self, _: &AirDensity, _: &AirDensity) -> Self {
+        self
+    }
+}
+
 impl Interpolable<StaticPressure, FanSpeed> for FanSpeed {
     fn interpolate_between(
         (low_static_pressure, low_speed): (StaticPressure, FanSpeed),
         (high_static_pressure, high_speed): (StaticPressure, FanSpeed),
         target: &StaticPressure,
-    ) -> FanSpeed {
-        if &low_static_pressure > target || &high_static_pressure < target {
-            panic!("interpolating out of bounds")
-        }
+    ) -> Result<FanSpeed, CalculationError> {
+        CalculationError::check_interval(
+            low_static_pressure.inches(),
+            high_static_pressure.inches(),
+            target.inches(),
+        )?;
 
         let low_rpm = low_speed.rpm();
         let high_rpm = high_speed.rpm();
@@ -55,6 +77,8 @@ impl Interpolable<StaticPressure, FanSpeed> for FanSpeed {
             .inches();
         let c = (target * interval).inches();
         // (-b - Math.sqrt(Math.pow(b,2) - 4 * a * c)) / (2 * a)
-        FanSpeed::from_rpm((-b - (b.powi(2) - a * c * 4.0).sqrt()) / (2.0 * a))
+        Ok(FanSpeed::from_rpm(
+            (-b - (b.powi(2) - a * c * 4.0).sqrt()) / (2.0 * a),
+        ))
     }
 }
@@ -4,7 +4,8 @@ use crate::{
 };
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
-use super::FanDiameter;
+use super::si::{CUBIC_METERS_PER_SECOND_PER_CFM, SECONDS_PER_HOUR};
+use super::{AirDensity, FanDiameter, FanSpeed};
 
 #[derive(Clone, PartialEq, Debug, Copy, PartialOrd)]
 pub struct InletAirflow(f64);
@@ -23,6 +24,22 @@ impl InletAirflow {
     pub fn cfm(&self) -> f64 {
         self.0
     }
+
+    pub fn from_cubic_meters_per_second(cubic_meters_per_second: f64) -> Self {
+        InletAirflow(cubic_meters_per_second / CUBIC_METERS_PER_SECOND_PER_CFM)
+    }
+
+    pub fn cubic_meters_per_second(&self) -> f64 {
+        self.0 * CUBIC_METERS_PER_SECOND_PER_CFM
+    }
+
+    pub fn from_cubic_meters_per_hour(cubic_meters_per_hour: f64) -> Self {
+        Self::from_cubic_meters_per_second(cubic_meters_per_hour / SECONDS_PER_HOUR)
+    }
+
+    pub fn cubic_meters_per_hour(&self) -> f64 {
+        self.cubic_meters_per_second() * SECONDS_PER_HOUR
+    }
 }
 
 impl ScalesWith<FanDiameter> for InletAirflow {
@@ -30,6 +47,11 @@ impl ScalesWith<FanDiameter> for InletAirflow {
         Self(self.0 * (to / from).powi(3))
     }
 }
+impl ScalesWith<FanSpeed> for InletAirflow {
+    fn scale(self, &from: &FanSpeed, &to: &FanSpeed) -> Self {
+        Self(self.0 * (to / from))
+    }
+}
 impl ScalesWith<InletAirflow> for InletAirflow {
     fn scale(self, from: &InletAirflow, to: &InletAirflow) -> Self {
         if self != *from {
@@ -38,3 +60,9 @@ impl ScalesWith<InletAirflow> for InletAirflow {
         *to
     }
 }
+
+impl ScalesWith<AirDensity> for InletAirflow {
+    fn scale(self, _: &AirDensity, _: &AirDensity) -> Self {
+        self
+    }
+}
@@ -0,0 +1,94 @@
+use crate::calculations::traits::{Interpolable, MeanErrorSquareComparable, ScalesWith};
+use crate::calculations::CalculationError;
+
+use super::{
+    inlet_airflow::InletAirflow, sound_power_level::SoundPowerLevel,
+    static_pressure::StaticPressure, FanDiameter,
+};
+
+pub const OCTAVE_BAND_CENTRE_FREQUENCIES: [f64; 8] =
+    [63.0, 125.0, 250.0, 500.0, 1000.0, 2000.0, 4000.0, 8000.0];
+
+// IEC 61672 A-weighting at each octave band centre frequency
+const A_WEIGHTING_CORRECTIONS: [f64; 8] = [-26.2, -16.1, -8.6, -3.2, 0.0, 1.2, 1.0, -1.1];
+
+// Sound power levels in the 63 Hz - 8 kHz octave bands
+#[derive(Clone, PartialEq, Debug, Copy)]
+pub struct OctaveBandSoundPower([SoundPowerLevel; 8]);
+
+impl OctaveBandSoundPower {
+    pub fn new(bands: [SoundPowerLevel; 8]) -> Self {
+        Self(bands)
+    }
+
+    pub fn from_decibels(bands: [f64; 8]) -> Self {
+        Self(bands.map(SoundPowerLevel::from_decibels))
+    }
+
+    pub fn bands(&self) -> &[SoundPowerLevel; 8] {
+        &self.0
+    }
+
+    pub fn decibels(&self) -> [f64; 8] {
+        self.0.map(|band| band.decibels())
+    }
+
+    pub fn total(&self) -> SoundPowerLevel {
+        SoundPowerLevel::logarithmic_sum(self.0)
+    }
+
+    pub fn a_weighted(&self) -> SoundPowerLevel {
+        SoundPowerLevel::logarithmic_sum(
+            self.0
+                .iter()
+                .zip(A_WEIGHTING_CORRECTIONS)
+                .map(|(band, correction)| {
+                    SoundPowerLevel::from_decibels(band.decibels() + correction)
+                }),
+        )
+    }
+}
+
+impl ScalesWith<FanDiameter> for OctaveBandSoundPower {
+    fn scale(self, from: &FanDiameter, to: &FanDiameter) -> Self {
+        Self(self.0.map(|band| band.scale(from, to)))
+    }
+}
+
+impl ScalesWith<InletAirflow> for OctaveBandSoundPower {
+    fn scale(self, from: &InletAirflow, to: &InletAirflow) -> Self {
+        Self(self.0.map(|band| band.scale(from, to)))
+    }
+}
+
+impl Interpolable<StaticPressure, OctaveBandSoundPower> for OctaveBandSoundPower {
+    fn interpolate_between(
+        (low_pressure, low_bands): (StaticPressure, Self),
+        (high_pressure, high_bands): (StaticPressure, Self),
+        required_static: &StaticPressure,
+    ) -> Result<Self, CalculationError> {
+        let mut bands = low_bands.0;
+        for (band, high_band) in bands.iter_mut().zip(high_bands.0) {
+            *band = SoundPowerLevel::interpolate_between(
+                (low_pressure, *band),
+                (high_pressure, high_band),
+                required_static,
+            )?;
+        }
+        Ok(Self(bands))
+    }
+}
+
+impl MeanErrorSquareComparable for OctaveBandSoundPower {
+    fn error_from(&self, other: &Self) -> f64 {
+        self.error_sum(other) / self.0.len() as f64
+    }
+
+    fn error_sum(&self, other: &Self) -> f64 {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .map(|(band, other_band)| band.error_sum(other_band))
+            .sum()
+    }
+}
@@ -1,10 +1,14 @@
 use crate::{
-    calculations::traits::{Interpolable, MeanErrorSquareComparable, ScalesWith},
+    calculations::{
+        traits::{Interpolable, MeanErrorSquareComparable, ScalesWith},
+        CalculationError,
+    },
     impl_UnitMath,
 };
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
-use super::{static_pressure::StaticPressure, FanDiameter};
+use super::si::{CUBIC_METERS_PER_SECOND_PER_CFM, SECONDS_PER_HOUR};
+use super::{static_pressure::StaticPressure, AirDensity, FanDiameter};
 
 #[derive(Clone, PartialEq, Debug, Copy)]
 pub struct OutletAirflow(f64);
@@ -23,6 +27,22 @@ impl OutletAirflow {
     pub fn cfm(&self) -> f64 {
         self.0
     }
+
+    pub fn from_cubic_meters_per_second(cubic_meters_per_second: f64) -> Self {
+        Self(cubic_meters_per_second / CUBIC_METERS_PER_SECOND_PER_CFM)
+    }
+
+    pub fn cubic_meters_per_second(&self) -> f64 {
+        self.0 * CUBIC_METERS_PER_SECOND_PER_CFM
+    }
+
+    pub fn from_cubic_meters_per_hour(cubic_meters_per_hour: f64) -> Self {
+        Self::from_cubic_meters_per_second(cubic_meters_per_hour / SECONDS_PER_HOUR)
+    }
+
+    pub fn cubic_meters_per_hour(&self) -> f64 {
+        self.cubic_meters_per_second() * SECONDS_PER_HOUR
+    }
 }
 
 impl ScalesWith<FanDiameter> for OutletAirflow {
@@ -36,13 +56,21 @@ impl Interpolable<StaticPressure, OutletAirflow> for OutletAirflow {
         (low_pressure, low_oaf): (StaticPressure, Self),
         (high_pressure, high_oaf): (StaticPressure, Self),
         required_pressure: &StaticPressure,
-    ) -> Self {
-        if &low_pressure > required_pressure || &high_pressure < required_pressure {
-            panic!("interpolating out of bounds")
-        }
+    ) -> Result<Self, CalculationError> {
+        CalculationError::check_interval(
+            low_pressure.inches(),
+            high_pressure.inches(),
+            required_pressure.inches(),
+        )?;
         // TODO, is this linear?
         let interval_fraction =
             (required_pressure - &low_pressure) / (high_pressure - low_pressure);
-        low_oaf + (high_oaf - low_oaf) * interval_fraction
+        Ok(low_oaf + (high_oaf - low_oaf) * interval_fraction)
+    }
+}
+
+impl ScalesWith<AirDensity> for OutletAirflow {
+    fn scale(self, _: &AirDensity, _: &AirDensity) -> Self {
+        self
     }
 }
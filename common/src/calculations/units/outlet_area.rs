@@ -0,0 +1,26 @@
+use super::si::SQUARE_METERS_PER_SQUARE_INCH;
+
+#[derive(Clone, PartialEq, Debug, Copy, PartialOrd)]
+pub struct OutletArea(f64);
+
+impl OutletArea {
+    pub fn from_square_inches(square_inches: f64) -> Self {
+        OutletArea(square_inches)
+    }
+
+    pub fn square_inches(&self) -> f64 {
+        self.0
+    }
+
+    pub fn square_feet(&self) -> f64 {
+        self.0 / 144.0
+    }
+
+    pub fn from_square_meters(square_meters: f64) -> Self {
+        OutletArea(square_meters / SQUARE_METERS_PER_SQUARE_INCH)
+    }
+
+    pub fn square_meters(&self) -> f64 {
+        self.0 * SQUARE_METERS_PER_SQUARE_INCH
+    }
+}
@@ -0,0 +1,64 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    calculations::traits::{MeanErrorSquareComparable, ScalesWith},
+    impl_UnitMath,
+};
+
+use super::si::METERS_PER_SECOND_PER_FPM;
+use super::{AirDensity, FanDiameter, FanSpeed, InletAirflow, OutletArea};
+
+#[derive(Clone, PartialEq, Debug, Copy, PartialOrd)]
+pub struct OutletVelocity(f64);
+impl_UnitMath!(OutletVelocity);
+
+impl OutletVelocity {
+    pub fn new(fpm: f64) -> Self {
+        Self(fpm)
+    }
+
+    pub fn from_fpm(fpm: f64) -> Self {
+        Self(fpm)
+    }
+
+    pub fn from_airflow(inlet_airflow: &InletAirflow, outlet_area: &OutletArea) -> Self {
+        Self(inlet_airflow.cfm() / outlet_area.square_feet())
+    }
+
+    pub fn fpm(&self) -> f64 {
+        self.0
+    }
+
+    pub fn from_meters_per_second(meters_per_second: f64) -> Self {
+        Self(meters_per_second / METERS_PER_SECOND_PER_FPM)
+    }
+
+    pub fn meters_per_second(&self) -> f64 {
+        self.0 * METERS_PER_SECOND_PER_FPM
+    }
+}
+
+// Airflow goes with the cube of diameter, but outlet area only with the square
+impl ScalesWith<FanDiameter> for OutletVelocity {
+    fn scale(self, &from: &FanDiameter, &to: &FanDiameter) -> Self {
+        Self(self.0 * (to / from))
+    }
+}
+
+impl ScalesWith<InletAirflow> for OutletVelocity {
+    fn scale(self, &from_airflow: &InletAirflow, &to_airflow: &InletAirflow) -> Self {
+        Self(self.0 * (to_airflow / from_airflow))
+    }
+}
+
+impl ScalesWith<FanSpeed> for OutletVelocity {
+    fn scale(self, &from: &FanSpeed, &to: &FanSpeed) -> Self {
+        Self(self.0 * (to / from))
+    }
+}
+
+impl ScalesWith<AirDensity> for OutletVelocity {
+    fn scale(self, _: &AirDensity, _: &AirDensity) -> Self {
+        self
+    }
+}
@@ -0,0 +1,10 @@
+// Conversion factors from the imperial units every value is stored in, exact or per NIST
+
+pub const PASCALS_PER_INCH_OF_WATER: f64 = 249.088_91;
+pub const CUBIC_METERS_PER_SECOND_PER_CFM: f64 = 0.000_471_947_443;
+pub const SECONDS_PER_HOUR: f64 = 3600.0;
+pub const KILOWATTS_PER_HORSEPOWER: f64 = 0.745_699_872;
+pub const MILLIMETERS_PER_INCH: f64 = 25.4;
+pub const SQUARE_METERS_PER_SQUARE_INCH: f64 = 0.000_645_16;
+pub const METERS_PER_SECOND_PER_FPM: f64 = 0.005_08;
+pub const KG_PER_CUBIC_METER_PER_LB_PER_CUBIC_FOOT: f64 = 16.018_463;
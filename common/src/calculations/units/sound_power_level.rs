@@ -0,0 +1,68 @@
+use crate::calculations::traits::{Interpolable, MeanErrorSquareComparable, ScalesWith};
+use crate::calculations::CalculationError;
+use crate::impl_UnitMath;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::{inlet_airflow::InletAirflow, static_pressure::StaticPressure, FanDiameter};
+
+// Sound power level in dB re 1 pW
+#[derive(Clone, PartialEq, Debug, Copy, PartialOrd)]
+pub struct SoundPowerLevel(f64);
+impl_UnitMath!(SoundPowerLevel);
+
+impl SoundPowerLevel {
+    pub fn new(decibels: f64) -> Self {
+        Self(decibels)
+    }
+
+    pub fn from_decibels(decibels: f64) -> Self {
+        Self(decibels)
+    }
+
+    pub fn decibels(&self) -> f64 {
+        self.0
+    }
+
+    // Levels add logarithmically, not arithmetically
+    pub fn logarithmic_sum<I>(levels: I) -> Self
+    where
+        I: IntoIterator<Item = SoundPowerLevel>,
+    {
+        let energy: f64 = levels
+            .into_iter()
+            .map(|level| 10_f64.powf(level.0 / 10.0))
+            .sum();
+        Self(10.0 * energy.log10())
+    }
+}
+
+// AMCA 301 fan laws for sound: Lw2 = Lw1 + 70 log(D2/D1) + 50 log(N2/N1)
+impl ScalesWith<FanDiameter> for SoundPowerLevel {
+    fn scale(self, &from: &FanDiameter, &to: &FanDiameter) -> Self {
+        Self(self.0 + 70.0 * (to / from).log10())
+    }
+}
+
+// Scaling with airflow at a constant diameter is a change of speed
+impl ScalesWith<InletAirflow> for SoundPowerLevel {
+    fn scale(self, &from_airflow: &InletAirflow, &to_airflow: &InletAirflow) -> Self {
+        Self(self.0 + 50.0 * (to_airflow / from_airflow).log10())
+    }
+}
+
+impl Interpolable<StaticPressure, SoundPowerLevel> for SoundPowerLevel {
+    fn interpolate_between(
+        (low_pressure, low_level): (StaticPressure, Self),
+        (high_pressure, high_level): (StaticPressure, Self),
+        required_static: &StaticPressure,
+    ) -> Result<Self, CalculationError> {
+        CalculationError::check_interval(
+            low_pressure.inches(),
+            high_pressure.inches(),
+            required_static.inches(),
+        )?;
+
+        let interval_fraction = (required_static - &low_pressure) / (high_pressure - low_pressure);
+        Ok(low_level + (high_level - low_level) * interval_fraction)
+    }
+}
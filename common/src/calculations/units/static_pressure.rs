@@ -5,7 +5,8 @@ use crate::{
     impl_UnitMath,
 };
 
-use super::{inlet_airflow::InletAirflow, FanDiameter};
+use super::si::PASCALS_PER_INCH_OF_WATER;
+use super::{inlet_airflow::InletAirflow, AirDensity, FanDiameter, FanSpeed};
 
 #[derive(Clone, PartialEq, Debug, PartialOrd, Copy)]
 pub struct StaticPressure(f64);
@@ -23,6 +24,14 @@ impl StaticPressure {
     pub fn inches(&self) -> f64 {
         self.0
     }
+
+    pub fn from_pascals(pascals: f64) -> Self {
+        Self(pascals / PASCALS_PER_INCH_OF_WATER)
+    }
+
+    pub fn pascals(&self) -> f64 {
+        self.0 * PASCALS_PER_INCH_OF_WATER
+    }
 }
 
 impl ScalesWith<FanDiameter> for StaticPressure {
@@ -36,3 +45,15 @@ impl ScalesWith<InletAirflow> for StaticPressure {
         Self(self.0 * (*to_airflow / *from_airflow).powi(2))
     }
 }
+
+impl ScalesWith<FanSpeed> for StaticPressure {
+    fn scale(self, &from: &FanSpeed, &to: &FanSpeed) -> Self {
+        Self(self.0 * (to / from).powi(2))
+    }
+}
+
+impl ScalesWith<AirDensity> for StaticPressure {
+    fn scale(self, &from: &AirDensity, &to: &AirDensity) -> Self {
+        Self(self.0 * (to / from))
+    }
+}
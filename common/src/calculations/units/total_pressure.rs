@@ -0,0 +1,66 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    calculations::traits::{MeanErrorSquareComparable, ScalesWith},
+    impl_UnitMath,
+};
+
+use super::si::PASCALS_PER_INCH_OF_WATER;
+use super::{AirDensity, FanDiameter, FanSpeed, InletAirflow, StaticPressure, VelocityPressure};
+
+#[derive(Clone, PartialEq, Debug, Copy, PartialOrd)]
+pub struct TotalPressure(f64);
+impl_UnitMath!(TotalPressure);
+
+impl TotalPressure {
+    pub fn new(inches: f64) -> Self {
+        Self(inches)
+    }
+
+    pub fn from_inches(inches: f64) -> Self {
+        Self(inches)
+    }
+
+    pub fn from_pressures(
+        static_pressure: &StaticPressure,
+        velocity_pressure: &VelocityPressure,
+    ) -> Self {
+        Self(static_pressure.inches() + velocity_pressure.inches())
+    }
+
+    pub fn inches(&self) -> f64 {
+        self.0
+    }
+
+    pub fn from_pascals(pascals: f64) -> Self {
+        Self(pascals / PASCALS_PER_INCH_OF_WATER)
+    }
+
+    pub fn pascals(&self) -> f64 {
+        self.0 * PASCALS_PER_INCH_OF_WATER
+    }
+}
+
+impl ScalesWith<FanDiameter> for TotalPressure {
+    fn scale(self, &from: &FanDiameter, &to: &FanDiameter) -> Self {
+        Self(self.0 * (to / from).powi(2))
+    }
+}
+
+impl ScalesWith<InletAirflow> for TotalPressure {
+    fn scale(self, &from_airflow: &InletAirflow, &to_airflow: &InletAirflow) -> Self {
+        Self(self.0 * (to_airflow / from_airflow).powi(2))
+    }
+}
+
+impl ScalesWith<FanSpeed> for TotalPressure {
+    fn scale(self, &from: &FanSpeed, &to: &FanSpeed) -> Self {
+        Self(self.0 * (to / from).powi(2))
+    }
+}
+
+impl ScalesWith<AirDensity> for TotalPressure {
+    fn scale(self, &from: &AirDensity, &to: &AirDensity) -> Self {
+        Self(self.0 * (to / from))
+    }
+}
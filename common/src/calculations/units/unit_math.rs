@@ -101,6 +101,16 @@ macro_rules! impl_UnitMath {
             }
         }
 
+        impl $crate::calculations::traits::Scalar for $T {
+            fn from_scalar(value: f64) -> Self {
+                $T::new(value)
+            }
+
+            fn scalar(&self) -> f64 {
+                self.0
+            }
+        }
+
         impl MeanErrorSquareComparable for $T {
             fn error_from(&self, other: &Self) -> f64 {
                 (&(self - other) / other).powi(2)
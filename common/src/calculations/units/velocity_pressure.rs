@@ -0,0 +1,76 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    calculations::traits::{MeanErrorSquareComparable, ScalesWith},
+    impl_UnitMath,
+};
+
+use super::si::PASCALS_PER_INCH_OF_WATER;
+use super::{AirDensity, FanDiameter, FanSpeed, InletAirflow, OutletVelocity};
+
+// fpm for one in. wg of velocity pressure in standard air
+const FPM_PER_ROOT_INCH: f64 = 4005.0;
+
+#[derive(Clone, PartialEq, Debug, Copy, PartialOrd)]
+pub struct VelocityPressure(f64);
+impl_UnitMath!(VelocityPressure);
+
+impl VelocityPressure {
+    pub fn new(inches: f64) -> Self {
+        Self(inches)
+    }
+
+    pub fn from_inches(inches: f64) -> Self {
+        Self(inches)
+    }
+
+    pub fn from_outlet_velocity(outlet_velocity: &OutletVelocity) -> Self {
+        Self::from_outlet_velocity_at_density(outlet_velocity, &AirDensity::standard())
+    }
+
+    pub fn from_outlet_velocity_at_density(
+        outlet_velocity: &OutletVelocity,
+        air_density: &AirDensity,
+    ) -> Self {
+        Self(
+            (outlet_velocity.fpm() / FPM_PER_ROOT_INCH).powi(2)
+                * (*air_density / AirDensity::standard()),
+        )
+    }
+
+    pub fn inches(&self) -> f64 {
+        self.0
+    }
+
+    pub fn from_pascals(pascals: f64) -> Self {
+        Self(pascals / PASCALS_PER_INCH_OF_WATER)
+    }
+
+    pub fn pascals(&self) -> f64 {
+        self.0 * PASCALS_PER_INCH_OF_WATER
+    }
+}
+
+impl ScalesWith<FanDiameter> for VelocityPressure {
+    fn scale(self, &from: &FanDiameter, &to: &FanDiameter) -> Self {
+        Self(self.0 * (to / from).powi(2))
+    }
+}
+
+impl ScalesWith<InletAirflow> for VelocityPressure {
+    fn scale(self, &from_airflow: &InletAirflow, &to_airflow: &InletAirflow) -> Self {
+        Self(self.0 * (to_airflow / from_airflow).powi(2))
+    }
+}
+
+impl ScalesWith<FanSpeed> for VelocityPressure {
+    fn scale(self, &from: &FanSpeed, &to: &FanSpeed) -> Self {
+        Self(self.0 * (to / from).powi(2))
+    }
+}
+
+impl ScalesWith<AirDensity> for VelocityPressure {
+    fn scale(self, &from: &AirDensity, &to: &AirDensity) -> Self {
+        Self(self.0 * (to / from))
+    }
+}
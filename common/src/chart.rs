@@ -0,0 +1,480 @@
+mod a1;
+pub use a1::a1_fan_chart;
+
+use std::fmt::Write;
+
+use crate::calculations::{
+    fitting::MonotoneCubic,
+    traits::{CurveFitStrategy, FittedCurve},
+};
+
+// Charts drawn straight to SVG markup, for PDFs and anywhere else without a browser.
+// The markup only depends on the chart, so it can be compared against snapshots.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chart {
+    pub title: String,
+    pub x_title: String,
+    pub left_title: String,
+    pub right_title: String,
+    pub series: Vec<Series>,
+    pub markers: Vec<PointMarker>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YAxis {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineStyle {
+    #[default]
+    Solid,
+    Dashed,
+    Dotted,
+    DashDot,
+}
+
+impl LineStyle {
+    fn dash_array(&self) -> Option<&'static str> {
+        match self {
+            Self::Solid => None,
+            Self::Dashed => Some("6 4"),
+            Self::Dotted => Some("2 3"),
+            Self::DashDot => Some("6 3 2 3"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    pub name: String,
+    pub y_axis: YAxis,
+    pub color: &'static str,
+    pub style: LineStyle,
+    pub width: f64,
+    // Drawn as a line, in order
+    pub line: Vec<(f64, f64)>,
+    // Drawn as open circles, like the determinations a line was fitted through
+    pub points: Vec<(f64, f64)>,
+    // Written beside the line's highest point, like the speed of a curve in a family
+    pub label: Option<String>,
+    // Families share one legend entry, so only their first member is listed
+    pub in_legend: bool,
+}
+
+impl Series {
+    pub fn new(name: impl Into<String>, y_axis: YAxis, color: &'static str) -> Self {
+        Series {
+            name: name.into(),
+            y_axis,
+            color,
+            style: LineStyle::Solid,
+            width: 2.0,
+            line: vec![],
+            points: vec![],
+            label: None,
+            in_legend: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointMarker {
+    pub name: String,
+    pub y_axis: YAxis,
+    pub color: &'static str,
+    pub at: (f64, f64),
+}
+
+const WIDTH: f64 = 720.0;
+const HEIGHT: f64 = 432.0;
+const PLOT_LEFT: f64 = 72.0;
+const PLOT_RIGHT: f64 = WIDTH - 72.0;
+const PLOT_BOTTOM: f64 = HEIGHT - 56.0;
+const LEGEND_TOP: f64 = 38.0;
+const LEGEND_ROW_HEIGHT: f64 = 16.0;
+const LEGEND_ENTRY_WIDTH: f64 = 144.0;
+const FONT: &str = "font-family=\"Helvetica, Arial, sans-serif\"";
+
+impl Chart {
+    pub fn to_svg(&self) -> String {
+        // The legend wraps onto as many rows as it needs, and the plot starts below it
+        let legend = self.legend();
+        let per_row = ((PLOT_RIGHT - PLOT_LEFT) / LEGEND_ENTRY_WIDTH).floor() as usize;
+        let legend_rows = legend.len().div_ceil(per_row);
+        let plot_top = LEGEND_TOP + LEGEND_ROW_HEIGHT * legend_rows.max(1) as f64 + 10.0;
+
+        let x_axis = AxisScale::to_fit(self.all_values(|_| true, |(x, _)| x));
+        let left_axis = AxisScale::to_fit(self.all_values(|axis| axis == YAxis::Left, |(_, y)| y));
+        let right_axis =
+            AxisScale::to_fit(self.all_values(|axis| axis == YAxis::Right, |(_, y)| y));
+        let to_svg_point = |y_axis: YAxis, (x, y): (f64, f64)| {
+            let y_scale = match y_axis {
+                YAxis::Left => &left_axis,
+                YAxis::Right => &right_axis,
+            };
+            (
+                PLOT_LEFT + x_axis.fraction_of(x) * (PLOT_RIGHT - PLOT_LEFT),
+                PLOT_BOTTOM - y_scale.fraction_of(y) * (PLOT_BOTTOM - plot_top),
+            )
+        };
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{HEIGHT}\" viewBox=\"0 0 {WIDTH} {HEIGHT}\">"
+        );
+        let _ = writeln!(
+            svg,
+            "<rect width=\"{WIDTH}\" height=\"{HEIGHT}\" fill=\"white\"/>"
+        );
+        let _ = writeln!(
+            svg,
+            "<text x=\"{}\" y=\"24\" text-anchor=\"middle\" font-size=\"16\" {FONT}>{}</text>",
+            WIDTH / 2.0,
+            escape(&self.title)
+        );
+
+        // Gridlines follow the x and left axes, the right axis only gets ticks
+        for (value, label) in x_axis.ticks() {
+            let (x, _) = to_svg_point(YAxis::Left, (value, 0.0));
+            let _ = writeln!(
+                svg,
+                "<line x1=\"{x:.1}\" y1=\"{plot_top}\" x2=\"{x:.1}\" y2=\"{PLOT_BOTTOM}\" stroke=\"#ddd\"/>"
+            );
+            let _ = writeln!(
+                svg,
+                "<text x=\"{x:.1}\" y=\"{}\" text-anchor=\"middle\" font-size=\"11\" {FONT}>{label}</text>",
+                PLOT_BOTTOM + 16.0
+            );
+        }
+        for (value, label) in left_axis.ticks() {
+            let (_, y) = to_svg_point(YAxis::Left, (0.0, value));
+            let _ = writeln!(
+                svg,
+                "<line x1=\"{PLOT_LEFT}\" y1=\"{y:.1}\" x2=\"{PLOT_RIGHT}\" y2=\"{y:.1}\" stroke=\"#ddd\"/>"
+            );
+            let _ = writeln!(
+                svg,
+                "<text x=\"{}\" y=\"{:.1}\" text-anchor=\"end\" font-size=\"11\" {FONT}>{label}</text>",
+                PLOT_LEFT - 6.0,
+                y + 4.0
+            );
+        }
+        for (value, label) in right_axis.ticks() {
+            let (_, y) = to_svg_point(YAxis::Right, (0.0, value));
+            let _ = writeln!(
+                svg,
+                "<line x1=\"{PLOT_RIGHT}\" y1=\"{y:.1}\" x2=\"{}\" y2=\"{y:.1}\" stroke=\"black\"/>",
+                PLOT_RIGHT + 4.0
+            );
+            let _ = writeln!(
+                svg,
+                "<text x=\"{}\" y=\"{:.1}\" font-size=\"11\" {FONT}>{label}</text>",
+                PLOT_RIGHT + 6.0,
+                y + 4.0
+            );
+        }
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{PLOT_LEFT}\" y=\"{plot_top}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"black\"/>",
+            PLOT_RIGHT - PLOT_LEFT,
+            PLOT_BOTTOM - plot_top
+        );
+        let _ = writeln!(
+            svg,
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\" font-size=\"12\" {FONT}>{}</text>",
+            (PLOT_LEFT + PLOT_RIGHT) / 2.0,
+            HEIGHT - 24.0,
+            escape(&self.x_title)
+        );
+        let middle = (plot_top + PLOT_BOTTOM) / 2.0;
+        let _ = writeln!(
+            svg,
+            "<text x=\"18\" y=\"{middle}\" text-anchor=\"middle\" font-size=\"12\" transform=\"rotate(-90 18 {middle})\" {FONT}>{}</text>",
+            escape(&self.left_title)
+        );
+        let _ = writeln!(
+            svg,
+            "<text x=\"{0}\" y=\"{middle}\" text-anchor=\"middle\" font-size=\"12\" transform=\"rotate(90 {0} {middle})\" {FONT}>{1}</text>",
+            WIDTH - 18.0,
+            escape(&self.right_title)
+        );
+
+        for series in &self.series {
+            if !series.line.is_empty() {
+                let path: Vec<String> = series
+                    .line
+                    .iter()
+                    .map(|point| {
+                        let (x, y) = to_svg_point(series.y_axis, *point);
+                        format!("{x:.1},{y:.1}")
+                    })
+                    .collect();
+                let dash = series
+                    .style
+                    .dash_array()
+                    .map(|dash_array| format!(" stroke-dasharray=\"{dash_array}\""))
+                    .unwrap_or_default();
+                let _ = writeln!(
+                    svg,
+                    "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\"{dash}/>",
+                    path.join(" "),
+                    series.color,
+                    series.width
+                );
+            }
+            for point in &series.points {
+                let (x, y) = to_svg_point(series.y_axis, *point);
+                let _ = writeln!(
+                    svg,
+                    "<circle cx=\"{x:.1}\" cy=\"{y:.1}\" r=\"3\" fill=\"white\" stroke=\"{}\"/>",
+                    series.color
+                );
+            }
+            let highest = series.line.iter().copied().reduce(|highest, point| {
+                if point.1 > highest.1 {
+                    point
+                } else {
+                    highest
+                }
+            });
+            if let (Some(label), Some(highest)) = (&series.label, highest) {
+                let (x, y) = to_svg_point(series.y_axis, highest);
+                let _ = writeln!(
+                    svg,
+                    "<text x=\"{:.1}\" y=\"{:.1}\" font-size=\"9\" fill=\"{}\" {FONT}>{}</text>",
+                    x + 4.0,
+                    y - 4.0,
+                    series.color,
+                    escape(label)
+                );
+            }
+        }
+        for marker in &self.markers {
+            let (x, y) = to_svg_point(marker.y_axis, marker.at);
+            let _ = writeln!(
+                svg,
+                "<path d=\"M {x:.1} {:.1} L {:.1} {y:.1} L {x:.1} {:.1} L {:.1} {y:.1} Z\" fill=\"{}\"/>",
+                y - 7.0,
+                x + 7.0,
+                y + 7.0,
+                x - 7.0,
+                marker.color
+            );
+        }
+
+        for (i, (name, color, style)) in legend.into_iter().enumerate() {
+            let x = PLOT_LEFT + LEGEND_ENTRY_WIDTH * (i % per_row) as f64;
+            let y = LEGEND_TOP + LEGEND_ROW_HEIGHT * (i / per_row) as f64;
+            let _ = match style.and_then(|style| style.dash_array()) {
+                Some(dash_array) => writeln!(
+                    svg,
+                    "<line x1=\"{x}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{color}\" stroke-width=\"2\" stroke-dasharray=\"{dash_array}\"/>",
+                    y + 6.0,
+                    x + 12.0,
+                    y + 6.0
+                ),
+                None => writeln!(
+                    svg,
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"12\" height=\"12\" fill=\"{color}\"/>"
+                ),
+            };
+            let _ = writeln!(
+                svg,
+                "<text x=\"{}\" y=\"{}\" font-size=\"11\" {FONT}>{}</text>",
+                x + 16.0,
+                y + 10.0,
+                escape(name)
+            );
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    // Names, colours and, for lines that aren't solid, how they're dashed
+    fn legend(&self) -> Vec<(&str, &'static str, Option<LineStyle>)> {
+        self.series
+            .iter()
+            .filter(|series| series.in_legend)
+            .map(|series| (series.name.as_str(), series.color, Some(series.style)))
+            .chain(
+                self.markers
+                    .iter()
+                    .map(|marker| (marker.name.as_str(), marker.color, None)),
+            )
+            .collect()
+    }
+
+    fn all_values(
+        &self,
+        on_axis: impl Fn(YAxis) -> bool,
+        value_of: impl Fn((f64, f64)) -> f64,
+    ) -> Vec<f64> {
+        let series_values = self
+            .series
+            .iter()
+            .filter(|series| on_axis(series.y_axis))
+            .flat_map(|series| series.line.iter().chain(series.points.iter()).copied());
+        let marker_values = self
+            .markers
+            .iter()
+            .filter(|marker| on_axis(marker.y_axis))
+            .map(|marker| marker.at);
+        series_values.chain(marker_values).map(value_of).collect()
+    }
+}
+
+// From zero up to a round number past the largest value, in round steps
+struct AxisScale {
+    max: f64,
+    step: f64,
+}
+
+impl AxisScale {
+    const TICK_COUNT: f64 = 5.0;
+
+    fn to_fit(values: impl IntoIterator<Item = f64>) -> Self {
+        let largest = values
+            .into_iter()
+            .filter(|v| v.is_finite())
+            .fold(0.0, f64::max);
+        if largest <= 0.0 {
+            return AxisScale {
+                max: 1.0,
+                step: 0.2,
+            };
+        }
+        let raw_step = largest / Self::TICK_COUNT;
+        let magnitude = 10f64.powf(raw_step.log10().floor());
+        let step = [1.0, 2.0, 2.5, 5.0, 10.0]
+            .into_iter()
+            .map(|multiple| multiple * magnitude)
+            .find(|step| *step >= raw_step)
+            .unwrap_or(10.0 * magnitude);
+        AxisScale {
+            max: (largest / step).ceil() * step,
+            step,
+        }
+    }
+
+    fn fraction_of(&self, value: f64) -> f64 {
+        value / self.max
+    }
+
+    fn ticks(&self) -> Vec<(f64, String)> {
+        // Enough decimals to tell a 2.5 step from its neighbours
+        let decimals = (-(self.step.log10().floor()) as i32
+            + if (self.step / 10f64.powf(self.step.log10().floor()) - 2.5).abs() < 1e-9 {
+                1
+            } else {
+                0
+            })
+        .max(0) as usize;
+        let count = (self.max / self.step).round() as usize;
+        (0..=count)
+            .map(|i| {
+                let value = self.step * i as f64;
+                (value, format!("{:.*}", decimals, value))
+            })
+            .collect()
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// Samples a monotone fit through the points so the line doesn't kink at each one,
+// falling back to straight segments if they can't be fitted
+pub fn smoothed_line(points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut samples = points.to_vec();
+    samples.sort_by(|(a_x, _), (b_x, _)| a_x.total_cmp(b_x));
+    match (MonotoneCubic.fit(&samples), samples.first(), samples.last()) {
+        (Ok(fitted), Some((low_x, _)), Some((high_x, _))) => (0..=100)
+            .map(|i| {
+                let x = low_x + (high_x - low_x) * i as f64 / 100.0;
+                (x, fitted.value_at(x))
+            })
+            .collect(),
+        _ => samples,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rounds_axes_up_to_whole_steps() {
+        let axis = AxisScale::to_fit([11077.0, 0.0]);
+        assert_eq!((axis.max, axis.step), (12500.0, 2500.0));
+        let labels: Vec<String> = AxisScale::to_fit([6.839])
+            .ticks()
+            .into_iter()
+            .map(|(_, label)| label)
+            .collect();
+        assert_eq!(labels, vec!["0", "2", "4", "6", "8"]);
+    }
+
+    #[test]
+    fn it_wraps_the_legend_and_escapes_text() {
+        let chart = Chart {
+            title: "A <B> & \"C\"".to_string(),
+            x_title: "x".to_string(),
+            left_title: "y".to_string(),
+            right_title: "y2".to_string(),
+            series: (0..6)
+                .map(|i| Series {
+                    line: vec![(0.0, i as f64), (1.0, i as f64 + 1.0)],
+                    in_legend: i != 5,
+                    ..Series::new(format!("Series {}", i), YAxis::Left, "black")
+                })
+                .collect(),
+            markers: vec![],
+        };
+        let svg = chart.to_svg();
+        assert!(svg.contains("A &lt;B&gt; &amp; &quot;C&quot;"));
+        assert!(svg.contains(">Series 4</text>"));
+        assert!(!svg.contains(">Series 5</text>"));
+        // Four entries fit on a row, so the fifth starts the second
+        assert!(svg.contains("<text x=\"88\" y=\"64\" font-size=\"11\""));
+    }
+
+    #[test]
+    fn it_draws_dual_axes_like_the_snapshot() {
+        let chart = Chart {
+            title: "Snapshot".to_string(),
+            x_title: "x".to_string(),
+            left_title: "y".to_string(),
+            right_title: "y2".to_string(),
+            series: vec![
+                Series {
+                    style: LineStyle::Dashed,
+                    line: vec![(0.0, 0.0), (10.0, 5.0)],
+                    points: vec![(10.0, 5.0)],
+                    label: Some("5 & up".to_string()),
+                    ..Series::new("Left", YAxis::Left, "red")
+                },
+                Series {
+                    line: vec![(0.0, 2.0), (10.0, 1.0)],
+                    ..Series::new("Right", YAxis::Right, "blue")
+                },
+            ],
+            markers: vec![PointMarker {
+                name: "Marker".to_string(),
+                y_axis: YAxis::Left,
+                color: "green",
+                at: (5.0, 2.5),
+            }],
+        };
+        assert_eq!(
+            chart.to_svg(),
+            include_str!("chart/snapshots/dual_axes.svg")
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use crate::{
+    calculations::{
+        core::{FanCurve, SystemCurve},
+        standards::{A1CurveFamily, A1OperatingPoint},
+        units::{BrakeHorsepower, FanSpeed, InletAirflow, StaticPressure},
+    },
+    display::{Measure, UnitSystem},
+};
+
+use super::{smoothed_line, Chart, LineStyle, PointMarker, Series, YAxis};
+
+fn airflow_of(op: &A1OperatingPoint, units: UnitSystem) -> f64 {
+    (op.as_ref() as &InletAirflow).measurement(units).value
+}
+
+fn static_pressure_of(op: &A1OperatingPoint, units: UnitSystem) -> f64 {
+    (op.as_ref() as &StaticPressure).measurement(units).value
+}
+
+fn brake_horsepower_of(op: &A1OperatingPoint, units: UnitSystem) -> f64 {
+    (op.as_ref() as &BrakeHorsepower).measurement(units).value
+}
+
+// Static pressure and brake horsepower against airflow, with the operating point
+// marked if there is one, in the given units
+pub fn a1_fan_chart(
+    title: impl Into<String>,
+    fan_curve: &FanCurve<A1OperatingPoint>,
+    operating_point: Option<&A1OperatingPoint>,
+    units: UnitSystem,
+) -> Chart {
+    let static_pressures: Vec<(f64, f64)> = fan_curve
+        .as_ref()
+        .iter()
+        .map(|op| (airflow_of(op, units), static_pressure_of(op, units)))
+        .collect();
+    let brake_horsepowers: Vec<(f64, f64)> = fan_curve
+        .as_ref()
+        .iter()
+        .map(|op| (airflow_of(op, units), brake_horsepower_of(op, units)))
+        .collect();
+    Chart {
+        title: title.into(),
+        x_title: format!("Airflow ({})", InletAirflow::symbol(units)),
+        left_title: format!("Static Pressure ({})", StaticPressure::symbol(units)),
+        right_title: format!("Brake Horsepower ({})", BrakeHorsepower::symbol(units)),
+        series: vec![
+            Series {
+                line: smoothed_line(&static_pressures),
+                points: static_pressures,
+                ..Series::new("Static Pressure", YAxis::Left, "#1f77b4")
+            },
+            Series {
+                line: smoothed_line(&brake_horsepowers),
+                points: brake_horsepowers,
+                ..Series::new("Brake Horsepower", YAxis::Right, "#d62728")
+            },
+        ],
+        markers: operating_point
+            .map(|op| PointMarker {
+                name: "Operating Point".to_string(),
+                y_axis: YAxis::Left,
+                color: "#2ca02c",
+                at: (airflow_of(op, units), static_pressure_of(op, units)),
+            })
+            .into_iter()
+            .collect(),
+    }
+}
+
+impl Chart {
+    // The duct system's resistance out to the fan's free delivery, with where the
+    // two meet marked. Nothing is marked when the fan stalls across it.
+    pub fn with_system_curve(
+        mut self,
+        fan_curve: &FanCurve<A1OperatingPoint>,
+        system_curve: &SystemCurve,
+        units: UnitSystem,
+    ) -> Self {
+        let max_cfm = fan_curve
+            .as_ref()
+            .iter()
+            .map(|op| (op.as_ref() as &InletAirflow).cfm())
+            .fold(0.0, f64::max);
+        let line = (0..=20)
+            .map(|i| {
+                let inlet_airflow = InletAirflow::from_cfm(max_cfm * i as f64 / 20.0);
+                (
+                    inlet_airflow.measurement(units).value,
+                    system_curve
+                        .static_pressure_at(&inlet_airflow)
+                        .measurement(units)
+                        .value,
+                )
+            })
+            .collect();
+        self.series.push(Series {
+            style: LineStyle::Dashed,
+            line,
+            ..Series::new("System Curve", YAxis::Left, "#8c564b")
+        });
+        if let Ok(intersection) = fan_curve.system_curve_intersection(system_curve) {
+            self.markers.push(PointMarker {
+                name: "System Operating Point".to_string(),
+                y_axis: YAxis::Left,
+                color: "#17becf",
+                at: (
+                    airflow_of(&intersection, units),
+                    static_pressure_of(&intersection, units),
+                ),
+            });
+        }
+        self
+    }
+
+    // The curve redrawn at each speed of the family, with its lines of constant
+    // power and static efficiency, all drawn thin beneath the tested curve
+    pub fn with_curve_family(mut self, family: &A1CurveFamily, units: UnitSystem) -> Self {
+        let family_series = |name: &str,
+                             color: &'static str,
+                             style: LineStyle,
+                             label: Option<String>,
+                             points: &[A1OperatingPoint],
+                             first: bool| {
+            let pressures: Vec<(f64, f64)> = points
+                .iter()
+                .map(|op| (airflow_of(op, units), static_pressure_of(op, units)))
+                .collect();
+            Series {
+                style,
+                width: 1.0,
+                line: pressures,
+                label,
+                in_legend: first,
+                ..Series::new(name, YAxis::Left, color)
+            }
+        };
+
+        let speed_curves = family.curves.iter().enumerate().map(|(i, curve)| {
+            let fan_speed = curve
+                .as_ref()
+                .first()
+                .map(|op| (op.as_ref() as &FanSpeed).format(units));
+            let mut series = family_series(
+                "Fan Speeds",
+                "#7f7f7f",
+                LineStyle::DashDot,
+                fan_speed,
+                curve.as_ref(),
+                i == 0,
+            );
+            series.line = smoothed_line(&series.line);
+            series
+        });
+        let power_lines = family.brake_horsepower_lines.iter().enumerate().map(
+            |(i, (brake_horsepower, line))| {
+                family_series(
+                    "Constant Power",
+                    "#ff7f0e",
+                    LineStyle::Dashed,
+                    Some(brake_horsepower.format(units)),
+                    line,
+                    i == 0,
+                )
+            },
+        );
+        let efficiency_contours = family.static_efficiency_contours.iter().enumerate().map(
+            |(i, (efficiency, contour))| {
+                family_series(
+                    "Static Efficiency",
+                    "#9467bd",
+                    LineStyle::Dotted,
+                    Some(format!("{:.0}%", efficiency.percent())),
+                    contour,
+                    i == 0,
+                )
+            },
+        );
+
+        // Ahead of the tested curve so it's drawn over them
+        let family_series: Vec<Series> = speed_curves
+            .chain(power_lines)
+            .chain(efficiency_contours)
+            .collect();
+        self.series.splice(0..0, family_series);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        calculations::{standards::fan_speeds_between, units::FanSpeed},
+        models::test_report,
+    };
+
+    use super::*;
+
+    fn fan_curve() -> FanCurve<A1OperatingPoint> {
+        test_report().into()
+    }
+
+    #[test]
+    fn it_draws_both_curves_and_the_operating_point() {
+        let fan_curve = fan_curve();
+        let operating_point = fan_curve.as_ref()[3].clone();
+        let svg = a1_fan_chart(
+            "DMF-150 <A1>",
+            &fan_curve,
+            Some(&operating_point),
+            UnitSystem::Imperial,
+        )
+        .to_svg();
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<polyline").count(), 2);
+        assert_eq!(svg.matches("<circle").count(), 20);
+        assert!(svg.contains("Static Pressure (in. wg)"));
+        assert!(svg.contains("DMF-150 &lt;A1&gt;"));
+        assert!(svg.contains("Operating Point"));
+    }
+
+    #[test]
+    fn it_marks_where_the_system_curve_meets_the_fan() {
+        let fan_curve = fan_curve();
+        let system_curve = SystemCurve::through(
+            &InletAirflow::from_cfm(7749.0),
+            &StaticPressure::from_inches(3.789),
+        );
+        let chart = a1_fan_chart("DMF-150", &fan_curve, None, UnitSystem::Imperial)
+            .with_system_curve(&fan_curve, &system_curve, UnitSystem::Imperial);
+        assert_eq!(chart.series.len(), 3);
+        let marker = &chart.markers[0];
+        assert_eq!(marker.name, "System Operating Point");
+        assert!((marker.at.0 - 7749.0).abs() < 1.0);
+        assert!((marker.at.1 - 3.789).abs() < 0.001);
+        assert!(chart.to_svg().contains("stroke-dasharray=\"6 4\""));
+    }
+
+    #[test]
+    fn it_labels_each_speed_of_a_family_with_one_legend_entry() {
+        let fan_curve = fan_curve();
+        let family = fan_curve
+            .curve_family(&fan_speeds_between(
+                &FanSpeed::from_rpm(875.0),
+                &FanSpeed::from_rpm(1750.0),
+                3,
+            ))
+            .unwrap();
+        let chart = a1_fan_chart("DMF-150", &fan_curve, None, UnitSystem::Imperial)
+            .with_curve_family(&family, UnitSystem::Imperial);
+        // The tested curves stay last so they're drawn on top
+        assert_eq!(chart.series.last().unwrap().name, "Brake Horsepower");
+        let svg = chart.to_svg();
+        for rpm in ["875 rpm", "1312 rpm", "1750 rpm"] {
+            assert!(svg.contains(&format!(">{}</text>", rpm)), "{}", rpm);
+        }
+        assert_eq!(svg.matches(">Fan Speeds</text>").count(), 1);
+    }
+}
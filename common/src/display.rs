@@ -0,0 +1,235 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::calculations::units::{
+    AirDensity, AirHorsepower, BrakeHorsepower, Efficiency, FanDiameter, FanSpeed, InletAirflow,
+    OutletAirflow, OutletArea, OutletVelocity, SoundPowerLevel, StaticPressure, TotalPressure,
+    VelocityPressure,
+};
+
+// Values are always stored in imperial units, this picks how they're shown and entered
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum UnitSystem {
+    #[default]
+    #[serde(rename = "imperial")]
+    Imperial,
+    #[serde(rename = "si")]
+    Si,
+}
+
+impl UnitSystem {
+    pub fn all_options() -> Vec<Self> {
+        vec![Self::Imperial, Self::Si]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Imperial => "imperial",
+            Self::Si => "si",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Imperial => "Imperial",
+            Self::Si => "SI",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseUnitSystemError;
+
+impl TryFrom<&str> for UnitSystem {
+    type Error = ParseUnitSystemError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "imperial" => Ok(Self::Imperial),
+            "si" => Ok(Self::Si),
+            _ => Err(ParseUnitSystemError),
+        }
+    }
+}
+
+// A value converted for display, rounded to what the unit is measured to
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Measurement {
+    pub value: f64,
+    pub symbol: &'static str,
+    pub precision: usize,
+}
+
+impl Measurement {
+    pub fn new(value: f64, symbol: &'static str, precision: usize) -> Self {
+        Measurement {
+            value,
+            symbol,
+            precision,
+        }
+    }
+
+    // Just the number, for table cells with the unit in the header
+    pub fn value_string(&self) -> String {
+        format!("{:.*}", self.precision, self.value)
+    }
+}
+
+impl fmt::Display for Measurement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.*} {}", self.precision, self.value, self.symbol)
+    }
+}
+
+pub trait Measure {
+    fn measurement(&self, system: UnitSystem) -> Measurement;
+
+    fn symbol(system: UnitSystem) -> &'static str;
+
+    fn format(&self, system: UnitSystem) -> String {
+        self.measurement(system).to_string()
+    }
+}
+
+macro_rules! impl_Measure {
+    ($unit:ty, $imperial:ident, $imperial_symbol:literal, $imperial_precision:literal, $si:ident, $si_symbol:literal, $si_precision:literal) => {
+        impl Measure for $unit {
+            fn measurement(&self, system: UnitSystem) -> Measurement {
+                match system {
+                    UnitSystem::Imperial => {
+                        Measurement::new(self.$imperial(), $imperial_symbol, $imperial_precision)
+                    }
+                    UnitSystem::Si => Measurement::new(self.$si(), $si_symbol, $si_precision),
+                }
+            }
+
+            fn symbol(system: UnitSystem) -> &'static str {
+                match system {
+                    UnitSystem::Imperial => $imperial_symbol,
+                    UnitSystem::Si => $si_symbol,
+                }
+            }
+        }
+    };
+}
+
+impl_Measure!(StaticPressure, inches, "in. wg", 3, pascals, "Pa", 0);
+impl_Measure!(TotalPressure, inches, "in. wg", 3, pascals, "Pa", 0);
+impl_Measure!(VelocityPressure, inches, "in. wg", 3, pascals, "Pa", 0);
+impl_Measure!(
+    InletAirflow,
+    cfm,
+    "cfm",
+    0,
+    cubic_meters_per_second,
+    "m³/s",
+    3
+);
+impl_Measure!(
+    OutletAirflow,
+    cfm,
+    "cfm",
+    0,
+    cubic_meters_per_second,
+    "m³/s",
+    3
+);
+impl_Measure!(BrakeHorsepower, hp, "hp", 2, kilowatts, "kW", 2);
+impl_Measure!(AirHorsepower, hp, "hp", 2, kilowatts, "kW", 2);
+impl_Measure!(FanDiameter, inches, "in", 2, millimeters, "mm", 0);
+impl_Measure!(OutletArea, square_inches, "in²", 1, square_meters, "m²", 4);
+impl_Measure!(OutletVelocity, fpm, "fpm", 0, meters_per_second, "m/s", 2);
+impl_Measure!(
+    AirDensity,
+    lb_per_cubic_foot,
+    "lb/ft³",
+    4,
+    kg_per_cubic_meter,
+    "kg/m³",
+    3
+);
+impl_Measure!(FanSpeed, rpm, "rpm", 0, rpm, "rpm", 0);
+impl_Measure!(Efficiency, percent, "%", 1, percent, "%", 1);
+impl_Measure!(SoundPowerLevel, decibels, "dB", 0, decibels, "dB", 0);
+
+// The other way, for values entered in the user's units
+pub trait FromMeasurement {
+    fn from_measurement(value: f64, system: UnitSystem) -> Self;
+}
+
+macro_rules! impl_FromMeasurement {
+    ($unit:ty, $imperial:ident, $si:ident) => {
+        impl FromMeasurement for $unit {
+            fn from_measurement(value: f64, system: UnitSystem) -> Self {
+                match system {
+                    UnitSystem::Imperial => <$unit>::$imperial(value),
+                    UnitSystem::Si => <$unit>::$si(value),
+                }
+            }
+        }
+    };
+}
+
+impl_FromMeasurement!(StaticPressure, from_inches, from_pascals);
+impl_FromMeasurement!(InletAirflow, from_cfm, from_cubic_meters_per_second);
+impl_FromMeasurement!(BrakeHorsepower, from_hp, from_kilowatts);
+impl_FromMeasurement!(AirDensity, from_lb_per_cubic_foot, from_kg_per_cubic_meter);
+impl_FromMeasurement!(FanSpeed, from_rpm, from_rpm);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_round_trips_unit_systems() {
+        for system in UnitSystem::all_options() {
+            assert_eq!(UnitSystem::try_from(system.as_str()), Ok(system));
+        }
+        assert_eq!(UnitSystem::try_from("metric"), Err(ParseUnitSystemError));
+    }
+
+    #[test]
+    fn it_converts_to_si_and_back() {
+        let pressure = StaticPressure::from_pascals(249.08891);
+        assert!((pressure.inches() - 1.0).abs() < 1e-9);
+        let airflow = InletAirflow::from_cubic_meters_per_second(1.0);
+        assert!((airflow.cfm() - 2118.88).abs() < 0.01);
+        assert!((airflow.cubic_meters_per_hour() - 3600.0).abs() < 1e-9);
+        assert!((BrakeHorsepower::from_kilowatts(0.745_699_872).hp() - 1.0).abs() < 1e-9);
+        assert_eq!(FanDiameter::from_millimeters(635.0).inches(), 25.0);
+        assert!((OutletVelocity::from_fpm(1000.0).meters_per_second() - 5.08).abs() < 1e-9);
+        assert!((AirDensity::standard().kg_per_cubic_meter() - 1.2014).abs() < 1e-4);
+    }
+
+    #[test]
+    fn it_reads_back_what_it_measures() {
+        let pressure = StaticPressure::from_inches(1.184);
+        for system in UnitSystem::all_options() {
+            let value = pressure.measurement(system).value;
+            let read = StaticPressure::from_measurement(value, system);
+            assert!((read.inches() - 1.184).abs() < 1e-9);
+        }
+        assert!((InletAirflow::from_measurement(1.0, UnitSystem::Si).cfm() - 2118.88).abs() < 0.01);
+    }
+
+    #[test]
+    fn it_formats_each_unit_to_its_precision() {
+        let pressure = StaticPressure::from_inches(1.184);
+        assert_eq!(pressure.format(UnitSystem::Imperial), "1.184 in. wg");
+        assert_eq!(pressure.format(UnitSystem::Si), "295 Pa");
+        let airflow = InletAirflow::from_cfm(11077.0);
+        assert_eq!(airflow.format(UnitSystem::Imperial), "11077 cfm");
+        assert_eq!(airflow.format(UnitSystem::Si), "5.228 m³/s");
+        assert_eq!(
+            BrakeHorsepower::from_hp(6.32).format(UnitSystem::Si),
+            "4.71 kW"
+        );
+        assert_eq!(
+            FanDiameter::from_inches(18.25)
+                .measurement(UnitSystem::Si)
+                .value_string(),
+            "464"
+        );
+    }
+}
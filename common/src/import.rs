@@ -0,0 +1,660 @@
+mod import_error;
+pub use import_error::ImportError;
+mod units;
+pub use units::{ImportUnit, ParseImportUnitError, Quantity};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::A1Standard2010Determination;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ImportFormat {
+    #[serde(rename = "csv")]
+    Csv,
+    #[serde(rename = "tsv")]
+    Tsv,
+    // The lab's data acquisition export, two header lines then whitespace separated columns:
+    // Det. No., Pt, Pv, Ps, Q, H, Kp, ηt, ηs
+    #[serde(rename = "lab_export")]
+    LabExport,
+}
+
+// Where the lab export keeps Ps, Q and H, and how many columns each row has
+const LAB_EXPORT_COLUMNS: ColumnMapping = ColumnMapping {
+    airflow: 4,
+    static_pressure: 3,
+    brake_horsepower: 5,
+};
+const LAB_EXPORT_ROW_LENGTH: usize = 9;
+
+impl ImportFormat {
+    // Spreadsheets copy to the clipboard as tab separated text and save as comma separated
+    pub fn detect(text: &str) -> Self {
+        let first_line = text.lines().find(|line| !line.trim().is_empty());
+        match first_line {
+            Some(line) if line.trim_start().starts_with("Det.") => ImportFormat::LabExport,
+            Some(line) if line.contains('\t') => ImportFormat::Tsv,
+            _ => ImportFormat::Csv,
+        }
+    }
+
+    pub fn all_options() -> Vec<Self> {
+        vec![
+            ImportFormat::Csv,
+            ImportFormat::Tsv,
+            ImportFormat::LabExport,
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportFormat::Csv => "csv",
+            ImportFormat::Tsv => "tsv",
+            ImportFormat::LabExport => "lab_export",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ImportFormat::Csv => "CSV",
+            ImportFormat::Tsv => "TSV",
+            ImportFormat::LabExport => "Lab export",
+        }
+    }
+
+    fn split_row(&self, line: &str) -> Vec<String> {
+        match self {
+            ImportFormat::Csv => split_delimited(line, ','),
+            ImportFormat::Tsv => split_delimited(line, '\t'),
+            ImportFormat::LabExport => line.split_whitespace().map(str::to_string).collect(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseImportFormatError;
+
+impl TryFrom<&str> for ImportFormat {
+    type Error = ParseImportFormatError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "csv" => Ok(ImportFormat::Csv),
+            "tsv" => Ok(ImportFormat::Tsv),
+            "lab_export" => Ok(ImportFormat::LabExport),
+            _ => Err(ParseImportFormatError),
+        }
+    }
+}
+
+// Columns count from 0
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ColumnMapping {
+    pub airflow: usize,
+    pub static_pressure: usize,
+    pub brake_horsepower: usize,
+}
+
+impl ColumnMapping {
+    pub fn column(&self, quantity: Quantity) -> usize {
+        match quantity {
+            Quantity::Airflow => self.airflow,
+            Quantity::StaticPressure => self.static_pressure,
+            Quantity::BrakeHorsepower => self.brake_horsepower,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ImportUnits {
+    pub airflow: ImportUnit,
+    pub static_pressure: ImportUnit,
+    pub brake_horsepower: ImportUnit,
+}
+
+impl ImportUnits {
+    pub fn unit(&self, quantity: Quantity) -> ImportUnit {
+        match quantity {
+            Quantity::Airflow => self.airflow,
+            Quantity::StaticPressure => self.static_pressure,
+            Quantity::BrakeHorsepower => self.brake_horsepower,
+        }
+    }
+}
+
+// Anything left as None is detected from the text
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ImportOptions {
+    pub format: Option<ImportFormat>,
+    pub airflow_column: Option<usize>,
+    pub static_pressure_column: Option<usize>,
+    pub brake_horsepower_column: Option<usize>,
+    pub airflow_unit: Option<ImportUnit>,
+    pub static_pressure_unit: Option<ImportUnit>,
+    pub brake_horsepower_unit: Option<ImportUnit>,
+    // Leave empty to accept however many rows there are
+    pub expected_rows: Option<usize>,
+}
+
+impl ImportOptions {
+    pub fn column(&self, quantity: Quantity) -> Option<usize> {
+        match quantity {
+            Quantity::Airflow => self.airflow_column,
+            Quantity::StaticPressure => self.static_pressure_column,
+            Quantity::BrakeHorsepower => self.brake_horsepower_column,
+        }
+    }
+
+    pub fn unit(&self, quantity: Quantity) -> Option<ImportUnit> {
+        match quantity {
+            Quantity::Airflow => self.airflow_unit,
+            Quantity::StaticPressure => self.static_pressure_unit,
+            Quantity::BrakeHorsepower => self.brake_horsepower_unit,
+        }
+    }
+}
+
+// The determinations in cfm, in. wg and hp, along with what was detected so it can be checked
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Import {
+    pub format: ImportFormat,
+    pub columns: ColumnMapping,
+    pub units: ImportUnits,
+    pub determinations: Vec<A1Standard2010Determination>,
+    pub warnings: Vec<String>,
+}
+
+pub fn import_determinations(text: &str, options: &ImportOptions) -> Result<Import, ImportError> {
+    let format = options.format.unwrap_or_else(|| ImportFormat::detect(text));
+    let rows: Vec<(usize, Vec<String>)> = text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| (index + 1, format.split_row(line)))
+        .collect();
+
+    // Headers are the leading rows without any numbers in them
+    let header_count = rows
+        .iter()
+        .take_while(|(_, cells)| {
+            cells.iter().all(|cell| {
+                parse_number(cell, DecimalSeparator::Point).is_none()
+                    && parse_number(cell, DecimalSeparator::Comma).is_none()
+            })
+        })
+        .count();
+    let (headers, data) = rows.split_at(header_count);
+    let header_for = |column: usize| -> String {
+        headers
+            .iter()
+            .filter_map(|(_, cells)| cells.get(column))
+            .map(|cell| cell.trim())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    // The lab export's first header line is split on spaces inside names, so only its units line lines up
+    let lab_units: Vec<String> = match (format, headers.last()) {
+        (ImportFormat::LabExport, Some((_, cells))) => split_lab_units(&cells.join(" ")),
+        _ => vec![],
+    };
+
+    let mut warnings = vec![];
+    let mut columns = [0; 3];
+    let mut units = [ImportUnit::Cfm; 3];
+    for (index, quantity) in Quantity::all().into_iter().enumerate() {
+        let column = match (options.column(quantity), format) {
+            (Some(column), _) => column,
+            (None, ImportFormat::LabExport) => LAB_EXPORT_COLUMNS.column(quantity),
+            (None, _) => find_column(
+                quantity,
+                headers.first().map_or(0, |(_, cells)| cells.len()),
+                &header_for,
+            )
+            .ok_or(ImportError::MissingColumn(quantity))?,
+        };
+        let header = match format {
+            // The units line has nothing above the determination number
+            ImportFormat::LabExport => column
+                .checked_sub(1)
+                .and_then(|unit_column| lab_units.get(unit_column))
+                .cloned()
+                .unwrap_or_default(),
+            _ => header_for(column),
+        };
+        let unit = match options.unit(quantity) {
+            Some(unit) if unit.quantity() != quantity => {
+                return Err(ImportError::UnitMismatch { quantity })
+            }
+            Some(unit) => unit,
+            None => ImportUnit::detect(&header)
+                .filter(|unit| unit.quantity() == quantity)
+                .unwrap_or_else(|| {
+                    let unit = ImportUnit::imperial(quantity);
+                    warnings.push(format!(
+                        "No unit given for {}, assuming {}",
+                        quantity.label().to_lowercase(),
+                        unit.symbol()
+                    ));
+                    unit
+                }),
+        };
+        // A header like "Flow Power" names two quantities, but a column only holds one
+        if let Some(shared) = columns[..index].iter().position(|&other| other == column) {
+            return Err(ImportError::SharedColumn {
+                column,
+                first: Quantity::all()[shared],
+                second: quantity,
+            });
+        }
+        columns[index] = column;
+        units[index] = unit;
+    }
+    let [airflow, static_pressure, brake_horsepower] = columns;
+    let columns = ColumnMapping {
+        airflow,
+        static_pressure,
+        brake_horsepower,
+    };
+    let [airflow, static_pressure, brake_horsepower] = units;
+    let units = ImportUnits {
+        airflow,
+        static_pressure,
+        brake_horsepower,
+    };
+
+    // Mapped columns can reach past the lab export's fixed width too
+    let mapped_length = columns
+        .airflow
+        .max(columns.static_pressure)
+        .max(columns.brake_horsepower)
+        + 1;
+    let needed_length = match format {
+        ImportFormat::LabExport => mapped_length.max(LAB_EXPORT_ROW_LENGTH),
+        _ => mapped_length,
+    };
+    // Decided once for the whole file, from the first cell that can only be read one way
+    let decimal_separator = data
+        .iter()
+        .flat_map(|(_, cells)| {
+            Quantity::all()
+                .into_iter()
+                .filter_map(|quantity| cells.get(columns.column(quantity)))
+        })
+        .find_map(|cell| DecimalSeparator::detect(cell));
+    let determinations = data
+        .iter()
+        .map(|(row, cells)| {
+            if cells.len() < needed_length {
+                return Err(ImportError::ShortRow {
+                    row: *row,
+                    expected: needed_length,
+                    found: cells.len(),
+                });
+            }
+            let value = |quantity: Quantity| {
+                let cell = &cells[columns.column(quantity)];
+                let decimal_separator = match decimal_separator {
+                    Some(decimal_separator) => decimal_separator,
+                    None if cell.contains([',', '.']) => {
+                        return Err(ImportError::AmbiguousNumber {
+                            row: *row,
+                            quantity,
+                            value: cell.clone(),
+                        })
+                    }
+                    None => DecimalSeparator::Point,
+                };
+                parse_number(cell, decimal_separator)
+                    .map(|value| units.unit(quantity).to_imperial(value))
+                    .ok_or_else(|| ImportError::InvalidNumber {
+                        row: *row,
+                        quantity,
+                        value: cell.clone(),
+                    })
+            };
+            Ok(A1Standard2010Determination {
+                cfm: value(Quantity::Airflow)?,
+                static_pressure: value(Quantity::StaticPressure)?,
+                brake_horsepower: value(Quantity::BrakeHorsepower)?,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if determinations.is_empty() {
+        return Err(ImportError::NoData);
+    }
+    if let Some(expected) = options.expected_rows {
+        if determinations.len() != expected {
+            return Err(ImportError::WrongRowCount {
+                expected,
+                found: determinations.len(),
+            });
+        }
+    }
+    Ok(Import {
+        format,
+        columns,
+        units,
+        determinations,
+        warnings,
+    })
+}
+
+// A column named for the quantity, or failing that the only one with a unit of it
+fn find_column(
+    quantity: Quantity,
+    column_count: usize,
+    header_for: &dyn Fn(usize) -> String,
+) -> Option<usize> {
+    let names: &[&str] = match quantity {
+        Quantity::Airflow => &["airflow", "flow", "volume", "cfm"],
+        Quantity::StaticPressure => &["static", "ps", "sp"],
+        Quantity::BrakeHorsepower => &["bhp", "brake", "power"],
+    };
+    let named = (0..column_count).find(|&column| {
+        let header = header_for(column).to_lowercase();
+        // Units in brackets don't count towards the name
+        let name = header.split(['(', '[']).next().unwrap_or_default();
+        name.split(|c: char| !c.is_alphanumeric())
+            .any(|word| names.contains(&word))
+    });
+    named.or_else(|| {
+        let with_unit: Vec<usize> = (0..column_count)
+            .filter(|&column| {
+                ImportUnit::detect(&header_for(column))
+                    .is_some_and(|unit| unit.quantity() == quantity)
+            })
+            .collect();
+        match with_unit[..] {
+            [column] => Some(column),
+            _ => None,
+        }
+    })
+}
+
+// Spreadsheets set to a European locale export 0,25 for a quarter and 18.820 for an airflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DecimalSeparator {
+    Point,
+    Comma,
+}
+
+impl DecimalSeparator {
+    fn as_char(&self) -> char {
+        match self {
+            DecimalSeparator::Point => '.',
+            DecimalSeparator::Comma => ',',
+        }
+    }
+
+    fn thousands(&self) -> char {
+        match self {
+            DecimalSeparator::Point => ',',
+            DecimalSeparator::Comma => '.',
+        }
+    }
+
+    // None when the cell reads the same either way, or is a number like 1,184 that doesn't
+    fn detect(cell: &str) -> Option<Self> {
+        let cell = cell.trim();
+        match (cell.rfind('.'), cell.rfind(',')) {
+            (Some(point), Some(comma)) if point > comma => Some(DecimalSeparator::Point),
+            (Some(_), Some(_)) => Some(DecimalSeparator::Comma),
+            (Some(_), None) if !is_grouped(cell, '.') => Some(DecimalSeparator::Point),
+            (None, Some(_)) if !is_grouped(cell, ',') => Some(DecimalSeparator::Comma),
+            _ => None,
+        }
+    }
+}
+
+// A whole number split into thousands, like 18,820 or -1.549
+fn is_grouped(digits: &str, separator: char) -> bool {
+    let all_digits = |group: &str| group.chars().all(|c| c.is_ascii_digit());
+    let mut groups = digits.strip_prefix('-').unwrap_or(digits).split(separator);
+    let first = groups.next().unwrap_or_default();
+    (1..=3).contains(&first.len())
+        && !first.starts_with('0')
+        && all_digits(first)
+        && groups.all(|group| group.len() == 3 && all_digits(group))
+}
+
+// Accepts thousands separators, which spreadsheets add to larger airflows
+fn parse_number(cell: &str, decimal_separator: DecimalSeparator) -> Option<f64> {
+    let cell = cell.trim();
+    let (whole, fraction) = match cell.split_once(decimal_separator.as_char()) {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (cell, None),
+    };
+    let thousands = decimal_separator.thousands();
+    if whole.contains(thousands) && !is_grouped(whole, thousands) {
+        return None;
+    }
+    let whole = whole.replace(thousands, "");
+    match fraction {
+        Some(fraction) => format!("{}.{}", whole, fraction),
+        None => whole,
+    }
+    .parse::<f64>()
+    .ok()
+    .filter(|value| value.is_finite())
+}
+
+// Splits one line, with quotes around any cell holding the delimiter and doubled quotes inside them
+fn split_delimited(line: &str, delimiter: char) -> Vec<String> {
+    let mut cells = vec![];
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = line.trim_end_matches('\r').chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                cell.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => cells.push(std::mem::take(&mut cell)),
+            c => cell.push(c),
+        }
+    }
+    cells.push(cell);
+    cells
+}
+
+// "(in. wg) (in. wg) (in. wg) (cfm) (hp) - (%) (%)" into one entry per column
+fn split_lab_units(line: &str) -> Vec<String> {
+    let mut units = vec![];
+    let mut rest = line.trim();
+    while !rest.is_empty() {
+        let end = if rest.starts_with('(') {
+            rest.find(')').map_or(rest.len(), |end| end + 1)
+        } else {
+            rest.find(char::is_whitespace).unwrap_or(rest.len())
+        };
+        units.push(rest[..end].to_string());
+        rest = rest[end..].trim_start();
+    }
+    units
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(import: &Import) -> Vec<(f64, f64, f64)> {
+        import
+            .determinations
+            .iter()
+            .map(|d| {
+                (
+                    (d.static_pressure * 1000.0).round() / 1000.0,
+                    d.cfm.round(),
+                    (d.brake_horsepower * 1000.0).round() / 1000.0,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn it_imports_the_lab_export() {
+        let text = "Det. No. P t P v P s Q H K p η t η s
+(in. wg) (in. wg) (in. wg) (cfm) (hp) - (%) (%)
+1 0.519 0.518 0.001 11077 6.320 0.998 14.3 0.0
+2 1.609 0.425 1.184 9981 6.632 0.995 38.1 28.0
+3 6.646 0.000 6.646 0 4.100 1.000 0.0 0.0";
+        let import = import_determinations(text, &ImportOptions::default()).unwrap();
+        assert_eq!(import.format, ImportFormat::LabExport);
+        assert_eq!(import.warnings, Vec::<String>::new());
+        assert_eq!(
+            points(&import),
+            vec![
+                (0.001, 11077.0, 6.32),
+                (1.184, 9981.0, 6.632),
+                (6.646, 0.0, 4.1)
+            ]
+        );
+    }
+
+    #[test]
+    fn it_rejects_a_lab_export_column_past_the_end_of_the_row() {
+        let text = "Det. No. P t P v P s Q H K p η t η s
+(in. wg) (in. wg) (in. wg) (cfm) (hp) - (%) (%)
+1 0.519 0.518 0.001 11077 6.320 0.998 14.3 0.0";
+        let options = ImportOptions {
+            format: Some(ImportFormat::LabExport),
+            airflow_column: Some(20),
+            ..Default::default()
+        };
+        assert_eq!(
+            import_determinations(text, &options),
+            Err(ImportError::ShortRow {
+                row: 3,
+                expected: 21,
+                found: 9
+            })
+        );
+    }
+
+    #[test]
+    fn it_finds_columns_and_converts_metric_units() {
+        let text = "Point,\"Airflow (m³/h)\",Static Pressure (Pa),Power (kW)\r
+1,\"18,820\",0.25,4.713\r
+2,16958,294.9,4.945\r";
+        let import = import_determinations(text, &ImportOptions::default()).unwrap();
+        assert_eq!(import.format, ImportFormat::Csv);
+        assert_eq!(
+            import.columns,
+            ColumnMapping {
+                airflow: 1,
+                static_pressure: 2,
+                brake_horsepower: 3
+            }
+        );
+        assert_eq!(import.units.static_pressure, ImportUnit::Pascals);
+        assert_eq!(
+            points(&import),
+            vec![(0.001, 11077.0, 6.32), (1.184, 9981.0, 6.631)]
+        );
+    }
+
+    #[test]
+    fn it_rejects_one_column_for_two_quantities() {
+        assert_eq!(
+            import_determinations(
+                "Static (in. wg),Flow Power,Airflow (cfm)\n0.001,6.32,11077",
+                &ImportOptions::default()
+            ),
+            Err(ImportError::SharedColumn {
+                column: 1,
+                first: Quantity::Airflow,
+                second: Quantity::BrakeHorsepower
+            })
+        );
+        let options = ImportOptions {
+            airflow_column: Some(0),
+            static_pressure_column: Some(0),
+            ..Default::default()
+        };
+        assert!(matches!(
+            import_determinations("SP,CFM,BHP\n0.001,11077,6.32", &options),
+            Err(ImportError::SharedColumn { column: 0, .. })
+        ));
+        // Single letters are too easily part of another name, so Q and H aren't matched
+        assert_eq!(
+            import_determinations("Ps,Q,H\n0.001,11077,6.32", &ImportOptions::default()),
+            Err(ImportError::MissingColumn(Quantity::Airflow))
+        );
+    }
+
+    #[test]
+    fn it_reads_decimal_commas() {
+        let text = "Ps (Pa)\tAirflow (m³/h)\tPower (kW)
+0,25\t18.820\t4,713
+294,9\t16.958\t4,945
+";
+        let import = import_determinations(text, &ImportOptions::default()).unwrap();
+        assert_eq!(import.format, ImportFormat::Tsv);
+        assert_eq!(
+            points(&import),
+            vec![(0.001, 11077.0, 6.32), (1.184, 9981.0, 6.631)]
+        );
+
+        // Without a cell that can only be read one way, 1,184 could be a thousand or one
+        assert_eq!(
+            import_determinations(
+                "SP\tCFM\tBHP\n1,184\t9981\t6,632\n",
+                &ImportOptions::default()
+            ),
+            Err(ImportError::AmbiguousNumber {
+                row: 2,
+                quantity: Quantity::StaticPressure,
+                value: "1,184".to_string()
+            })
+        );
+        assert_eq!(
+            parse_number("1.184,5", DecimalSeparator::Comma),
+            Some(1184.5)
+        );
+        assert_eq!(parse_number("18,82", DecimalSeparator::Point), None);
+    }
+
+    #[test]
+    fn it_uses_mapped_columns_and_checks_the_row_count() {
+        let text = "0.001\t11077\t6.320\n1.184\t9981\t6.632\n";
+        let options = ImportOptions {
+            airflow_column: Some(1),
+            static_pressure_column: Some(0),
+            brake_horsepower_column: Some(2),
+            ..Default::default()
+        };
+        let import = import_determinations(text, &options).unwrap();
+        assert_eq!(import.format, ImportFormat::Tsv);
+        assert_eq!(import.warnings.len(), 3);
+        assert_eq!(points(&import)[1], (1.184, 9981.0, 6.632));
+
+        assert_eq!(
+            import_determinations(
+                text,
+                &ImportOptions {
+                    expected_rows: Some(10),
+                    ..options.clone()
+                }
+            ),
+            Err(ImportError::WrongRowCount {
+                expected: 10,
+                found: 2
+            })
+        );
+        assert_eq!(
+            import_determinations(text, &ImportOptions::default()),
+            Err(ImportError::MissingColumn(Quantity::Airflow))
+        );
+        assert_eq!(
+            import_determinations("SP,CFM,BHP\n0.001,n/a,6.3", &ImportOptions::default()),
+            Err(ImportError::InvalidNumber {
+                row: 2,
+                quantity: Quantity::Airflow,
+                value: "n/a".to_string()
+            })
+        );
+    }
+}
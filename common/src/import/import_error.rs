@@ -0,0 +1,138 @@
+use std::fmt;
+
+use crate::{api::ApiError, import::Quantity};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportError {
+    NoData,
+    // No column was mapped to this quantity, and none of the headers named it
+    MissingColumn(Quantity),
+    // Two quantities were mapped to, or found in, the same column
+    SharedColumn {
+        column: usize,
+        first: Quantity,
+        second: Quantity,
+    },
+    // A column was given a unit for a different quantity, like pascals for airflow
+    UnitMismatch {
+        quantity: Quantity,
+    },
+    // Rows count from 1, as a spreadsheet shows them
+    ShortRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    InvalidNumber {
+        row: usize,
+        quantity: Quantity,
+        value: String,
+    },
+    // Nothing else in the file shows whether its commas are decimal or thousands separators
+    AmbiguousNumber {
+        row: usize,
+        quantity: Quantity,
+        value: String,
+    },
+    WrongRowCount {
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportError::NoData => write!(f, "There are no determination rows to import"),
+            ImportError::MissingColumn(quantity) => {
+                write!(
+                    f,
+                    "Could not find the {} column",
+                    quantity.label().to_lowercase()
+                )
+            }
+            // Columns are numbered from 1, as a spreadsheet's are
+            ImportError::SharedColumn {
+                column,
+                first,
+                second,
+            } => write!(
+                f,
+                "Column {} can't hold both {} and {}",
+                column + 1,
+                first.label().to_lowercase(),
+                second.label().to_lowercase()
+            ),
+            ImportError::UnitMismatch { quantity } => write!(
+                f,
+                "That unit can't be used for {}",
+                quantity.label().to_lowercase()
+            ),
+            ImportError::ShortRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Row {} has {} columns, but at least {} are needed",
+                row, found, expected
+            ),
+            ImportError::InvalidNumber {
+                row,
+                quantity,
+                value,
+            } => write!(
+                f,
+                "Row {} has '{}' for {}, which isn't a number",
+                row,
+                value,
+                quantity.label().to_lowercase()
+            ),
+            ImportError::AmbiguousNumber {
+                row,
+                quantity,
+                value,
+            } => write!(
+                f,
+                "Row {} has '{}' for {}, which could be read with either a decimal or a thousands separator",
+                row,
+                value,
+                quantity.label().to_lowercase()
+            ),
+            ImportError::WrongRowCount { expected, found } => write!(
+                f,
+                "Expected {} determination rows, but found {}",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ImportError {}
+
+// Problems with the options are placed on the option that caused them
+impl From<ImportError> for ApiError {
+    fn from(err: ImportError) -> Self {
+        let option = |quantity: &Quantity, suffix: &str| {
+            let name = match quantity {
+                Quantity::Airflow => "airflow",
+                Quantity::StaticPressure => "static_pressure",
+                Quantity::BrakeHorsepower => "brake_horsepower",
+            };
+            format!("{}_{}", name, suffix)
+        };
+        let message = err.to_string();
+        match &err {
+            ImportError::MissingColumn(quantity) => ApiError::validation("Could not import")
+                .with_field_error(option(quantity, "column"), message),
+            ImportError::SharedColumn { second, .. } => ApiError::validation("Could not import")
+                .with_field_error(option(second, "column"), message),
+            ImportError::UnitMismatch { quantity } => ApiError::validation("Could not import")
+                .with_field_error(option(quantity, "unit"), message),
+            ImportError::WrongRowCount { .. } => {
+                ApiError::validation("Could not import").with_field_error("expected_rows", message)
+            }
+            _ => ApiError::validation(message),
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use serde::{Deserialize, Serialize};
+
+use crate::calculations::units::{BrakeHorsepower, InletAirflow, StaticPressure};
+
+// What each imported column measures
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Quantity {
+    #[serde(rename = "airflow")]
+    Airflow,
+    #[serde(rename = "static_pressure")]
+    StaticPressure,
+    #[serde(rename = "brake_horsepower")]
+    BrakeHorsepower,
+}
+
+impl Quantity {
+    pub fn all() -> [Quantity; 3] {
+        [
+            Quantity::Airflow,
+            Quantity::StaticPressure,
+            Quantity::BrakeHorsepower,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Quantity::Airflow => "Airflow",
+            Quantity::StaticPressure => "Static pressure",
+            Quantity::BrakeHorsepower => "Brake horsepower",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ImportUnit {
+    #[serde(rename = "cfm")]
+    Cfm,
+    #[serde(rename = "m3_per_h")]
+    CubicMetersPerHour,
+    #[serde(rename = "in_wg")]
+    InchesOfWater,
+    #[serde(rename = "pa")]
+    Pascals,
+    #[serde(rename = "hp")]
+    Horsepower,
+    #[serde(rename = "kw")]
+    Kilowatts,
+}
+
+impl ImportUnit {
+    pub fn all() -> [ImportUnit; 6] {
+        [
+            ImportUnit::Cfm,
+            ImportUnit::CubicMetersPerHour,
+            ImportUnit::InchesOfWater,
+            ImportUnit::Pascals,
+            ImportUnit::Horsepower,
+            ImportUnit::Kilowatts,
+        ]
+    }
+
+    // The units reports are stored in
+    pub fn imperial(quantity: Quantity) -> Self {
+        match quantity {
+            Quantity::Airflow => ImportUnit::Cfm,
+            Quantity::StaticPressure => ImportUnit::InchesOfWater,
+            Quantity::BrakeHorsepower => ImportUnit::Horsepower,
+        }
+    }
+
+    pub fn quantity(&self) -> Quantity {
+        match self {
+            ImportUnit::Cfm | ImportUnit::CubicMetersPerHour => Quantity::Airflow,
+            ImportUnit::InchesOfWater | ImportUnit::Pascals => Quantity::StaticPressure,
+            ImportUnit::Horsepower | ImportUnit::Kilowatts => Quantity::BrakeHorsepower,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportUnit::Cfm => "cfm",
+            ImportUnit::CubicMetersPerHour => "m3_per_h",
+            ImportUnit::InchesOfWater => "in_wg",
+            ImportUnit::Pascals => "pa",
+            ImportUnit::Horsepower => "hp",
+            ImportUnit::Kilowatts => "kw",
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            ImportUnit::Cfm => "cfm",
+            ImportUnit::CubicMetersPerHour => "m³/h",
+            ImportUnit::InchesOfWater => "in. wg",
+            ImportUnit::Pascals => "Pa",
+            ImportUnit::Horsepower => "hp",
+            ImportUnit::Kilowatts => "kW",
+        }
+    }
+
+    // Converts a value in this unit to cfm, in. wg or hp
+    pub fn to_imperial(&self, value: f64) -> f64 {
+        match self {
+            ImportUnit::Cfm | ImportUnit::InchesOfWater | ImportUnit::Horsepower => value,
+            ImportUnit::CubicMetersPerHour => InletAirflow::from_cubic_meters_per_hour(value).cfm(),
+            ImportUnit::Pascals => StaticPressure::from_pascals(value).inches(),
+            ImportUnit::Kilowatts => BrakeHorsepower::from_kilowatts(value).hp(),
+        }
+    }
+
+    // Finds the unit written in a column header, like "Airflow (m³/h)" or "Ps [Pa]",
+    // falling back to the header's last word for ones like "Q cfm"
+    pub fn detect(header: &str) -> Option<Self> {
+        let bracketed = header.find(['(', '[']).and_then(|start| {
+            let rest = &header[start + 1..];
+            rest.find([')', ']']).map(|end| &rest[..end])
+        });
+        let candidate = bracketed.or_else(|| header.split_whitespace().last())?;
+        let compact: String = candidate
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '.')
+            .flat_map(char::to_lowercase)
+            .collect();
+        match compact.as_str() {
+            "cfm" | "ft³/min" | "ft3/min" => Some(ImportUnit::Cfm),
+            "m³/h" | "m3/h" | "m^3/h" | "m³/hr" | "m3/hr" | "cmh" => {
+                Some(ImportUnit::CubicMetersPerHour)
+            }
+            "inwg" | "inh2o" | "inwc" | "\"wg" => Some(ImportUnit::InchesOfWater),
+            "pa" => Some(ImportUnit::Pascals),
+            "hp" | "bhp" => Some(ImportUnit::Horsepower),
+            "kw" => Some(ImportUnit::Kilowatts),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseImportUnitError;
+
+impl TryFrom<&str> for ImportUnit {
+    type Error = ParseImportUnitError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "cfm" => Ok(ImportUnit::Cfm),
+            "m3_per_h" => Ok(ImportUnit::CubicMetersPerHour),
+            "in_wg" => Ok(ImportUnit::InchesOfWater),
+            "pa" => Ok(ImportUnit::Pascals),
+            "hp" => Ok(ImportUnit::Horsepower),
+            "kw" => Ok(ImportUnit::Kilowatts),
+            _ => Err(ParseImportUnitError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_detects_units_in_headers() {
+        assert_eq!(
+            ImportUnit::detect("Airflow (m³/h)"),
+            Some(ImportUnit::CubicMetersPerHour)
+        );
+        assert_eq!(
+            ImportUnit::detect("(in. wg)"),
+            Some(ImportUnit::InchesOfWater)
+        );
+        assert_eq!(ImportUnit::detect("Ps [Pa]"), Some(ImportUnit::Pascals));
+        assert_eq!(ImportUnit::detect("Q cfm"), Some(ImportUnit::Cfm));
+        assert_eq!(
+            ImportUnit::detect("Power (kW)"),
+            Some(ImportUnit::Kilowatts)
+        );
+        assert_eq!(ImportUnit::detect("Static Pressure"), None);
+    }
+
+    #[test]
+    fn it_parses_the_names_it_gives_units() {
+        for unit in ImportUnit::all() {
+            assert_eq!(ImportUnit::try_from(unit.as_str()), Ok(unit));
+        }
+    }
+
+    #[test]
+    fn it_converts_to_imperial_units() {
+        assert!((ImportUnit::Pascals.to_imperial(249.08891) - 1.0).abs() < 1e-9);
+        assert!((ImportUnit::CubicMetersPerHour.to_imperial(1699.0107948) - 1000.0).abs() < 1e-6);
+        assert!((ImportUnit::Kilowatts.to_imperial(7.4569987) - 10.0).abs() < 1e-6);
+        assert_eq!(ImportUnit::Cfm.to_imperial(11077.0), 11077.0);
+    }
+}
@@ -1,4 +1,7 @@
 pub mod api;
 pub mod calculations;
+pub mod chart;
+pub mod display;
+pub mod import;
 pub mod models;
 pub mod util;
@@ -1,3 +1,704 @@
+use std::{collections::HashMap, env, fmt, fs, process};
+
+use loquat_common::{
+    calculations::{
+        core::FanCurve,
+        fitting::FitStrategy,
+        standards::{
+            A1A2OperatingPoint, A1OperatingPoint, CanFindA1OperatingPoint, CanProduceA1A2Curve,
+        },
+        units::{
+            AirDensity, BrakeHorsepower, FanDiameter, FanSpeed, InletAirflow, OutletAirflow,
+            StaticPressure,
+        },
+        CalculationError,
+    },
+    display::{Measure, Measurement, UnitSystem},
+    import::{import_determinations, ImportError, ImportOptions},
+    models::{
+        A1Standard2010Determination, A1Standard2010Parameters, A1Standard2010Report,
+        A2Standard2010Determination, A2Standard2010Parameters, A2Standard2010Report, FanSize,
+        InducedFlowFanSize,
+    },
+};
+
+const USAGE: &str = "\
+Usage: loquat <command> [options]
+
+Commands:
+  operating-point  The speed and power a size needs to meet a duty
+      --a1 FILE --rpm RPM --diameter IN --at-diameter IN --cfm CFM --sp IN_WG
+      [--air-density LB_FT3] [--site-density LB_FT3]
+      [--fit piecewise_linear|monotone_cubic|natural_cubic_spline]
+  induced-ratio    The A1 curve with the A2 outlet airflow and induced ratio at each point
+      --a1 FILE --a2 FILE --rpm RPM --diameter IN [--at-diameter IN]
+  scale            The A1 curve scaled to other sizes, and optionally another speed
+      --a1 FILE --rpm RPM --diameter IN --to-diameters IN,IN,... [--to-rpm RPM]
+      [--air-density LB_FT3]
+
+Options for every command:
+  --output json|csv    How the table is written, json by default
+  --units imperial|si  The units the table is written in, imperial by default
+
+Determinations are read from CSV, TSV or the lab's export the same way the web app
+imports them, or from a .json file holding an array of determinations. A2 CSV and TSV
+files hold airflow (cfm) then static pressure (in. wg) in their first two columns.
+Options are always given in cfm, in. wg, rpm, inches and lb/ft³.
+";
+
 fn main() {
-    println!("Hello, world!");
+    let args: Vec<String> = env::args().skip(1).collect();
+    match run(&args) {
+        Ok(output) => print!("{output}"),
+        Err(err @ CliError::Usage(_)) => {
+            eprintln!("loquat: {err}\n\n{USAGE}");
+            process::exit(2);
+        }
+        Err(err) => {
+            eprintln!("loquat: {err}");
+            process::exit(1);
+        }
+    }
+}
+
+#[derive(Debug)]
+enum CliError {
+    Usage(String),
+    Read { path: String, message: String },
+    Import { path: String, err: ImportError },
+    Calculation(CalculationError),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{}", message),
+            CliError::Read { path, message } => write!(f, "Could not read {}: {}", path, message),
+            CliError::Import { path, err } => write!(f, "Could not import {}: {}", path, err),
+            CliError::Calculation(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<CalculationError> for CliError {
+    fn from(err: CalculationError) -> Self {
+        CliError::Calculation(err)
+    }
+}
+
+fn run(args: &[String]) -> Result<String, CliError> {
+    let (command, options) = match args.split_first() {
+        Some((command, rest)) if !command.starts_with("--") => (command.as_str(), rest),
+        _ => return Err(CliError::Usage("No command was given".to_string())),
+    };
+    let options = Options::parse(options)?;
+    if command == "help" {
+        return Ok(USAGE.to_string());
+    }
+    let (_, allowed, calculate) = COMMANDS
+        .iter()
+        .find(|(name, _, _)| *name == command)
+        .ok_or_else(|| CliError::Usage(format!("Unknown command {}", command)))?;
+    options.only_allow(allowed)?;
+    let write: fn(&Table) -> String = match options.get("output").unwrap_or("json") {
+        "json" => Table::to_json,
+        "csv" => Table::to_csv,
+        output => return Err(CliError::Usage(format!("Unknown output {}", output))),
+    };
+    Ok(write(&calculate(&options)?))
+}
+
+type Command = fn(&Options) -> Result<Table, CliError>;
+
+// Each command's name, the options it takes and the table it calculates
+const COMMANDS: &[(&str, &[&str], Command)] = &[
+    ("operating-point", OPERATING_POINT_OPTIONS, operating_point),
+    ("induced-ratio", INDUCED_RATIO_OPTIONS, induced_ratio),
+    ("scale", SCALE_OPTIONS, scale),
+];
+
+// --name value, or --name=value, each given at most once
+struct Options(HashMap<String, String>);
+
+const COMMON_OPTIONS: &[&str] = &["output", "units"];
+const OPERATING_POINT_OPTIONS: &[&str] = &[
+    "a1",
+    "rpm",
+    "diameter",
+    "air-density",
+    "at-diameter",
+    "cfm",
+    "sp",
+    "site-density",
+    "fit",
+];
+const INDUCED_RATIO_OPTIONS: &[&str] = &["a1", "a2", "rpm", "diameter", "at-diameter"];
+const SCALE_OPTIONS: &[&str] = &[
+    "a1",
+    "rpm",
+    "diameter",
+    "air-density",
+    "to-diameters",
+    "to-rpm",
+];
+
+impl Options {
+    fn parse(args: &[String]) -> Result<Self, CliError> {
+        let mut options = HashMap::new();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let name = arg
+                .strip_prefix("--")
+                .ok_or_else(|| CliError::Usage(format!("Expected an option but found {}", arg)))?;
+            let (name, value) = match name.split_once('=') {
+                Some((name, value)) => (name.to_string(), value.to_string()),
+                None => (
+                    name.to_string(),
+                    args.next()
+                        .cloned()
+                        .ok_or_else(|| CliError::Usage(format!("--{} needs a value", name)))?,
+                ),
+            };
+            if options.insert(name.clone(), value).is_some() {
+                return Err(CliError::Usage(format!("--{} was given twice", name)));
+            }
+        }
+        Ok(Options(options))
+    }
+
+    fn only_allow(&self, allowed: &[&str]) -> Result<(), CliError> {
+        let mut unknown: Vec<&String> = self
+            .0
+            .keys()
+            .filter(|name| {
+                !allowed.contains(&name.as_str()) && !COMMON_OPTIONS.contains(&name.as_str())
+            })
+            .collect();
+        unknown.sort();
+        match unknown.first() {
+            Some(name) => Err(CliError::Usage(format!("Unknown option --{}", name))),
+            None => Ok(()),
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+
+    fn required(&self, name: &str) -> Result<&str, CliError> {
+        self.get(name)
+            .ok_or_else(|| CliError::Usage(format!("--{} is required", name)))
+    }
+
+    fn number(&self, name: &str) -> Result<f64, CliError> {
+        parse_number(name, self.required(name)?)
+    }
+
+    // Speeds, sizes, airflows and densities, which the fan laws divide by
+    fn positive_number(&self, name: &str) -> Result<f64, CliError> {
+        parse_positive_number(name, self.required(name)?)
+    }
+
+    fn optional_positive_number(&self, name: &str) -> Result<Option<f64>, CliError> {
+        self.get(name)
+            .map(|value| parse_positive_number(name, value))
+            .transpose()
+    }
+
+    fn positive_numbers(&self, name: &str) -> Result<Vec<f64>, CliError> {
+        self.required(name)?
+            .split(',')
+            .map(|value| parse_positive_number(name, value.trim()))
+            .collect()
+    }
+
+    fn units(&self) -> Result<UnitSystem, CliError> {
+        let units = self.get("units").unwrap_or(UnitSystem::Imperial.as_str());
+        UnitSystem::try_from(units).map_err(|_| CliError::Usage(format!("Unknown units {}", units)))
+    }
+
+    fn fit(&self) -> Result<FitStrategy, CliError> {
+        let fit = self.get("fit").unwrap_or(FitStrategy::default().as_str());
+        FitStrategy::try_from(fit).map_err(|_| CliError::Usage(format!("Unknown fit {}", fit)))
+    }
+}
+
+fn parse_number(name: &str, value: &str) -> Result<f64, CliError> {
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|number| number.is_finite())
+        .ok_or_else(|| CliError::Usage(format!("--{} must be a number, not {}", name, value)))
+}
+
+fn parse_positive_number(name: &str, value: &str) -> Result<f64, CliError> {
+    let number = parse_number(name, value)?;
+    if number > 0.0 {
+        Ok(number)
+    } else {
+        Err(CliError::Usage(format!(
+            "--{} must be greater than 0, not {}",
+            name, value
+        )))
+    }
+}
+
+fn read(path: &str) -> Result<String, CliError> {
+    fs::read_to_string(path).map_err(|err| CliError::Read {
+        path: path.to_string(),
+        message: err.to_string(),
+    })
+}
+
+fn is_json(path: &str) -> bool {
+    path.to_lowercase().ends_with(".json")
+}
+
+fn a1_determinations(path: &str) -> Result<Vec<A1Standard2010Determination>, CliError> {
+    let text = read(path)?;
+    if is_json(path) {
+        return serde_json::from_str(&text).map_err(|err| CliError::Read {
+            path: path.to_string(),
+            message: err.to_string(),
+        });
+    }
+    let import = import_determinations(&text, &ImportOptions::default()).map_err(|err| {
+        CliError::Import {
+            path: path.to_string(),
+            err,
+        }
+    })?;
+    for warning in &import.warnings {
+        eprintln!("loquat: {}: {}", path, warning);
+    }
+    Ok(import.determinations)
+}
+
+// Rows before the first with numbers in both of the first two columns are taken to be headers,
+// every row after that has to have them
+fn a2_determinations(path: &str) -> Result<Vec<A2Standard2010Determination>, CliError> {
+    let text = read(path)?;
+    let invalid = |message: String| CliError::Read {
+        path: path.to_string(),
+        message,
+    };
+    if is_json(path) {
+        return serde_json::from_str(&text).map_err(|err| invalid(err.to_string()));
+    }
+    let mut determinations: Vec<A2Standard2010Determination> = vec![];
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let cells: Vec<Result<f64, _>> = line
+            .split([',', '\t'])
+            .map(|cell| cell.trim().trim_matches('"').parse::<f64>())
+            .collect();
+        match cells.as_slice() {
+            [Ok(cfm), Ok(static_pressure), ..] => {
+                determinations.push(A2Standard2010Determination {
+                    cfm: *cfm,
+                    static_pressure: *static_pressure,
+                })
+            }
+            _ if determinations.is_empty() => {}
+            _ => {
+                return Err(invalid(format!(
+                    "Row {} needs an airflow and a static pressure, but was {}",
+                    index + 1,
+                    line.trim()
+                )))
+            }
+        }
+    }
+    if determinations.is_empty() {
+        return Err(invalid("There are no determination rows".to_string()));
+    }
+    Ok(determinations)
+}
+
+// The tested size stands in for a fan size record, its outlet isn't needed here
+fn a1_report(options: &Options) -> Result<A1Standard2010Report<FanSize<()>>, CliError> {
+    let determinations = a1_determinations(options.required("a1")?)?;
+    Ok(A1Standard2010Report {
+        id: "a1".to_string(),
+        fan_size: FanSize {
+            id: "tested".to_string(),
+            fan_series_id: String::new(),
+            fan_series: (),
+            diameter: options.positive_number("diameter")?,
+            outlet_area: 0.0,
+        },
+        fan_size_id: "tested".to_string(),
+        parameters: A1Standard2010Parameters {
+            rpm: options.positive_number("rpm")?,
+            air_density: options.optional_positive_number("air-density")?,
+        },
+        determinations,
+    })
+}
+
+fn operating_point(options: &Options) -> Result<Table, CliError> {
+    let report = a1_report(options)?;
+    let fan_diameter = FanDiameter::from_inches(options.positive_number("at-diameter")?);
+    let inlet_airflow = InletAirflow::from_cfm(options.positive_number("cfm")?);
+    let static_pressure = StaticPressure::from_inches(options.number("sp")?);
+    let site_density = options
+        .optional_positive_number("site-density")?
+        .map(AirDensity::from_lb_per_cubic_foot)
+        .unwrap_or_default();
+    let fit = options.fit()?;
+    let operating_point = report.a1_operating_point_at_density_for(
+        &fan_diameter,
+        &inlet_airflow,
+        &static_pressure,
+        &site_density,
+        fit,
+    )?;
+
+    let units = options.units()?;
+    let mut table = Table::new(&[
+        (
+            "fan_diameter",
+            format!("Diameter ({})", FanDiameter::symbol(units)),
+        ),
+        (
+            "inlet_airflow",
+            format!("Airflow ({})", InletAirflow::symbol(units)),
+        ),
+        (
+            "static_pressure",
+            format!("Static Pressure ({})", StaticPressure::symbol(units)),
+        ),
+        (
+            "fan_speed",
+            format!("Fan Speed ({})", FanSpeed::symbol(units)),
+        ),
+        (
+            "brake_horsepower",
+            format!("Brake Horsepower ({})", BrakeHorsepower::symbol(units)),
+        ),
+    ]);
+    table.rows.push(vec![
+        fan_diameter.measurement(units),
+        inlet_airflow.measurement(units),
+        static_pressure.measurement(units),
+        (operating_point.as_ref() as &FanSpeed).measurement(units),
+        (operating_point.as_ref() as &BrakeHorsepower).measurement(units),
+    ]);
+    Ok(table)
+}
+
+fn induced_ratio(options: &Options) -> Result<Table, CliError> {
+    let a1_report = a1_report(options)?;
+    let fan_diameter = FanDiameter::from_inches(
+        options
+            .optional_positive_number("at-diameter")?
+            .unwrap_or(a1_report.fan_size.diameter),
+    );
+    let a2_report = A2Standard2010Report {
+        id: "a2".to_string(),
+        a1_report_id: a1_report.id.clone(),
+        induced_flow_fan_size_id: "tested".to_string(),
+        induced_flow_fan_size: InducedFlowFanSize {
+            id: "tested".to_string(),
+            fan_size_id: a1_report.fan_size_id.clone(),
+            fan_size: a1_report.fan_size.clone(),
+            nozzle_id: String::new(),
+            nozzle: (),
+        },
+        parameters: A2Standard2010Parameters {
+            rpm: a1_report.parameters.rpm,
+        },
+        determinations: a2_determinations(options.required("a2")?)?,
+        a1_report,
+    };
+    let fan_curve: FanCurve<A1A2OperatingPoint> = a2_report.a1_a2_fan_curve(&fan_diameter)?;
+
+    let units = options.units()?;
+    let mut table = Table::new(&[
+        (
+            "fan_speed",
+            format!("Fan Speed ({})", FanSpeed::symbol(units)),
+        ),
+        (
+            "inlet_airflow",
+            format!("Inlet Airflow ({})", InletAirflow::symbol(units)),
+        ),
+        (
+            "outlet_airflow",
+            format!("Outlet Airflow ({})", OutletAirflow::symbol(units)),
+        ),
+        (
+            "static_pressure",
+            format!("Static Pressure ({})", StaticPressure::symbol(units)),
+        ),
+        (
+            "brake_horsepower",
+            format!("Brake Horsepower ({})", BrakeHorsepower::symbol(units)),
+        ),
+        ("induced_ratio", "Induced Ratio".to_string()),
+    ]);
+    table.rows = fan_curve
+        .as_ref()
+        .iter()
+        .map(|op| {
+            vec![
+                (op.as_ref() as &FanSpeed).measurement(units),
+                (op.as_ref() as &InletAirflow).measurement(units),
+                (op.as_ref() as &OutletAirflow).measurement(units),
+                (op.as_ref() as &StaticPressure).measurement(units),
+                (op.as_ref() as &BrakeHorsepower).measurement(units),
+                Measurement::new(op.induced_ratio(), "", 3),
+            ]
+        })
+        .collect();
+    Ok(table)
+}
+
+fn scale(options: &Options) -> Result<Table, CliError> {
+    let report = a1_report(options)?;
+    let fan_speed = FanSpeed::from_rpm(
+        options
+            .optional_positive_number("to-rpm")?
+            .unwrap_or(report.parameters.rpm),
+    );
+    let fan_diameters: Vec<FanDiameter> = options
+        .positive_numbers("to-diameters")?
+        .into_iter()
+        .map(FanDiameter::from_inches)
+        .collect();
+
+    let units = options.units()?;
+    let mut table = Table::new(&[
+        (
+            "fan_diameter",
+            format!("Diameter ({})", FanDiameter::symbol(units)),
+        ),
+        (
+            "fan_speed",
+            format!("Fan Speed ({})", FanSpeed::symbol(units)),
+        ),
+        (
+            "inlet_airflow",
+            format!("Airflow ({})", InletAirflow::symbol(units)),
+        ),
+        (
+            "static_pressure",
+            format!("Static Pressure ({})", StaticPressure::symbol(units)),
+        ),
+        (
+            "brake_horsepower",
+            format!("Brake Horsepower ({})", BrakeHorsepower::symbol(units)),
+        ),
+    ]);
+    for fan_diameter in fan_diameters {
+        let fan_curve: FanCurve<A1OperatingPoint> = report
+            .clone()
+            .a1_fan_curve_at(&fan_diameter, &fan_speed)?
+            .sorted_by_airflow();
+        table.rows.extend(fan_curve.as_ref().iter().map(|op| {
+            vec![
+                fan_diameter.measurement(units),
+                (op.as_ref() as &FanSpeed).measurement(units),
+                (op.as_ref() as &InletAirflow).measurement(units),
+                (op.as_ref() as &StaticPressure).measurement(units),
+                (op.as_ref() as &BrakeHorsepower).measurement(units),
+            ]
+        }));
+    }
+    Ok(table)
+}
+
+// Each column has a key for JSON and a title, with its unit, for CSV
+struct Table {
+    columns: Vec<(&'static str, String)>,
+    rows: Vec<Vec<Measurement>>,
+}
+
+impl Table {
+    fn new(columns: &[(&'static str, String)]) -> Self {
+        Table {
+            columns: columns.to_vec(),
+            rows: vec![],
+        }
+    }
+
+    // An array with an object per row, values unrounded
+    fn to_json(&self) -> String {
+        let rows: Vec<serde_json::Value> = self
+            .rows
+            .iter()
+            .map(|row| {
+                self.columns
+                    .iter()
+                    .zip(row)
+                    .map(|((key, _), measurement)| (key.to_string(), measurement.value.into()))
+                    .collect::<serde_json::Map<_, _>>()
+                    .into()
+            })
+            .collect();
+        format!(
+            "{}\n",
+            serde_json::to_string_pretty(&rows).unwrap_or_default()
+        )
+    }
+
+    // Rounded to what each unit is measured to, as the web app shows them
+    fn to_csv(&self) -> String {
+        let mut lines = vec![self
+            .columns
+            .iter()
+            .map(|(_, title)| csv_cell(title))
+            .collect::<Vec<_>>()
+            .join(",")];
+        lines.extend(self.rows.iter().map(|row| {
+            row.iter()
+                .map(Measurement::value_string)
+                .collect::<Vec<_>>()
+                .join(",")
+        }));
+        lines.join("\n") + "\n"
+    }
+}
+
+fn csv_cell(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const A1_CSV: &str = "\
+CFM,SP (in. wg),BHP
+11077,0.001,6.320
+9981,1.184,6.632
+8884,2.593,7.243
+7749,3.789,7.481
+6659,4.608,7.416
+5524,5.158,7.079
+4436,5.532,6.606
+3311,5.795,6.171
+1549,6.054,6.419
+0,6.839,7.204
+";
+
+    fn args_with_file(name: &str, contents: &str, args: &[&str]) -> Vec<String> {
+        let path = env::temp_dir().join(format!("loquat-{}-{}", process::id(), name));
+        fs::write(&path, contents).unwrap();
+        args.iter()
+            .map(|arg| arg.replace("{file}", path.to_str().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn it_finds_an_operating_point_as_json() {
+        let args = args_with_file(
+            "operating-point.csv",
+            A1_CSV,
+            &[
+                "operating-point",
+                "--a1",
+                "{file}",
+                "--rpm",
+                "1750",
+                "--diameter",
+                "27",
+                "--at-diameter=27",
+                "--cfm",
+                "7749",
+                "--sp",
+                "3.789",
+            ],
+        );
+        let rows: Vec<serde_json::Value> = serde_json::from_str(&run(&args).unwrap()).unwrap();
+        let fan_speed = rows[0]["fan_speed"].as_f64().unwrap();
+        let brake_horsepower = rows[0]["brake_horsepower"].as_f64().unwrap();
+        assert!((fan_speed - 1750.0).abs() < 1.0, "{}", fan_speed);
+        assert!(
+            (brake_horsepower - 7.481).abs() < 0.01,
+            "{}",
+            brake_horsepower
+        );
+    }
+
+    #[test]
+    fn it_scales_to_each_size_as_csv() {
+        let args = args_with_file(
+            "scale.csv",
+            A1_CSV,
+            &[
+                "scale",
+                "--a1",
+                "{file}",
+                "--rpm",
+                "1750",
+                "--diameter",
+                "27",
+                "--to-diameters",
+                "27,54",
+                "--output",
+                "csv",
+            ],
+        );
+        let csv = run(&args).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 21);
+        assert_eq!(
+            lines[0],
+            "Diameter (in),Fan Speed (rpm),Airflow (cfm),Static Pressure (in. wg),Brake Horsepower (hp)"
+        );
+        // Doubling the diameter at the same speed gives eight times the airflow
+        assert_eq!(lines[20], "54.00,1750,88616,0.004,202.24");
+    }
+
+    #[test]
+    fn it_rejects_bad_arguments() {
+        let usage = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+            match run(&args) {
+                Err(CliError::Usage(message)) => message,
+                other => panic!("{:?}", other.map(|_| ())),
+            }
+        };
+        assert_eq!(usage(&[]), "No command was given");
+        assert_eq!(usage(&["plot"]), "Unknown command plot");
+        assert_eq!(usage(&["scale", "--rpm"]), "--rpm needs a value");
+        assert_eq!(
+            usage(&["scale", "--a1", "x", "--a1", "y"]),
+            "--a1 was given twice"
+        );
+        assert_eq!(usage(&["scale", "--rpm", "fast"]), "--a1 is required");
+        assert_eq!(usage(&["scale", "--cfm", "100"]), "Unknown option --cfm");
+        let zero_rpm = args_with_file(
+            "zero-rpm.csv",
+            A1_CSV,
+            &["scale", "--a1", "{file}", "--rpm", "0", "--diameter", "27"],
+        );
+        assert_eq!(
+            usage(&zero_rpm.iter().map(String::as_str).collect::<Vec<_>>()),
+            "--rpm must be greater than 0, not 0"
+        );
+    }
+
+    #[test]
+    fn it_reports_the_a2_row_it_cant_read() {
+        let args = args_with_file(
+            "bad-row.csv",
+            "CFM,SP (in. wg)\n12000,0.5\n11000,one\n",
+            &["induced-ratio", "--a2", "{file}"],
+        );
+        match a2_determinations(&args[2]) {
+            Err(CliError::Read { message, .. }) => assert_eq!(
+                message,
+                "Row 3 needs an airflow and a static pressure, but was 11000,one"
+            ),
+            other => panic!("{:?}", other),
+        }
+    }
 }
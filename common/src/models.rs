@@ -1,10 +1,19 @@
 mod a1_2010_report;
+#[cfg(test)]
+pub(crate) use a1_2010_report::test_report;
 pub use a1_2010_report::{
     A1Standard2010Determination, A1Standard2010Parameters, A1Standard2010Report,
 };
 
 mod a2_2010_report;
-pub use a2_2010_report::A2Standard2010Report;
+pub use a2_2010_report::{
+    A2Standard2010Determination, A2Standard2010Parameters, A2Standard2010Report,
+};
+
+mod s1_2010_report;
+pub use s1_2010_report::{
+    S1Standard2010Determination, S1Standard2010Parameters, S1Standard2010Report,
+};
 
 mod fan_series;
 pub use fan_series::FanSeries;
@@ -16,3 +25,5 @@ mod induced_flow_fan_size;
 pub use induced_flow_fan_size::InducedFlowFanSize;
 mod nozzle;
 pub use nozzle::Nozzle;
+mod user;
+pub use user::{Role, User};
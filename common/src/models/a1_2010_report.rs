@@ -5,7 +5,7 @@ use crate::{
     calculations::{
         core::FanCurve,
         standards::{A1OperatingPoint, CanFindA1OperatingPoint},
-        units::{BrakeHorsepower, FanDiameter, FanSpeed, InletAirflow, StaticPressure},
+        units::{AirDensity, BrakeHorsepower, FanDiameter, FanSpeed, InletAirflow, StaticPressure},
     },
     models::fan_size::FanSize,
 };
@@ -13,6 +13,9 @@ use crate::{
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct A1Standard2010Parameters {
     pub rpm: f64,
+    // lb/ft³, standard air when not recorded
+    #[serde(default)]
+    pub air_density: Option<f64>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -109,25 +112,44 @@ impl<R> From<A1Standard2010Report<FanSize<R>>> for FanDiameter {
     }
 }
 
+impl<R> From<A1Standard2010Report<R>> for AirDensity {
+    fn from(value: A1Standard2010Report<R>) -> Self {
+        value
+            .parameters
+            .air_density
+            .map(AirDensity::from_lb_per_cubic_foot)
+            .unwrap_or_default()
+    }
+}
+
 impl<R: Clone> CanFindA1OperatingPoint for A1Standard2010Report<FanSize<R>> {}
 
+// A lab test of a 27" fan at 1750 rpm in standard air, for tests that need a real curve
 #[cfg(test)]
-mod tests {
-    use crate::{
-        calculations::{
-            standards::{A1InterpolationPoint, CanFindA1OperatingPoint},
-            traits::MeanErrorSquareComparable,
-            units::{FanDiameter, InletAirflow, StaticPressure},
-        },
-        models::{fan_series::FanSeries, fan_size::FanSize, fan_type::FanType},
-    };
-
-    use super::*;
+pub(crate) fn test_report() -> A1Standard2010Report<FanSize<crate::models::FanSeries<()>>> {
+    use crate::models::{FanSeries, FanType};
 
-    #[test]
-    fn it_calculates() {
-        // Test
-        let raw_dets = [
+    let fan_series = FanSeries {
+        id: "SKYPLUME G1-ELLV DMF".to_string(),
+        fan_type: FanType::MixedFlow,
+        fan_sizes: (),
+    };
+    let fan_size = FanSize {
+        id: "DMF-150".to_string(),
+        fan_series_id: fan_series.id.clone(),
+        fan_series,
+        diameter: 27.0,
+        outlet_area: 100.0,
+    };
+    A1Standard2010Report {
+        id: "5000.1-A1".to_string(),
+        fan_size_id: fan_size.id.clone(),
+        fan_size,
+        parameters: A1Standard2010Parameters {
+            rpm: 1750.0,
+            air_density: None,
+        },
+        determinations: [
             // SP   CFM      BHP
             (0.001, 11077.0, 6.320),
             (1.184, 9981.0, 6.632),
@@ -139,43 +161,43 @@ mod tests {
             (5.795, 3311.0, 6.171),
             (6.054, 1549.0, 6.419),
             (6.839, 0.0, 7.204),
-        ];
-        // raw_dets.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
-        let test_points: Vec<A1Standard2010Determination> = raw_dets
-            .into_iter()
-            .map(
-                |(static_pressure, cfm, brake_horsepower)| A1Standard2010Determination {
-                    cfm,
-                    static_pressure,
-                    brake_horsepower,
-                },
-            )
-            .collect::<Vec<_>>();
-
-        let fan_series_id = "SKYPLUME G1-ELLV DMF".to_string();
-        let test_event = A1Standard2010Report {
-            id: "report-id-test".to_string(),
-            fan_size_id: "SKYPLUME G1-ELLV-18 DMF-150".to_string(),
-            fan_size: FanSize {
-                id: "SKYPLUME G1-ELLV-18 DMF-150".to_string(),
-                fan_series_id: fan_series_id.clone(),
-                fan_series: FanSeries {
-                    id: fan_series_id.clone(),
-                    fan_type: FanType::Axial,
-                    fan_sizes: (),
-                },
-                diameter: 27.0,
-                outlet_area: 100.0,
+        ]
+        .into_iter()
+        .map(
+            |(static_pressure, cfm, brake_horsepower)| A1Standard2010Determination {
+                cfm,
+                static_pressure,
+                brake_horsepower,
             },
+        )
+        .collect(),
+    }
+}
 
-            parameters: A1Standard2010Parameters { rpm: 1750.0 },
-            determinations: test_points,
-        };
+#[cfg(test)]
+mod tests {
+    use crate::calculations::{
+        core::{InterpolableFanCurve, SystemCurve},
+        fitting::{
+            FitStrategy, MonotoneCubic, NaturalCubicSpline, PiecewiseLinear, PolynomialLeastSquares,
+        },
+        standards::{fan_speeds_between, A1InterpolationPoint, CanFindA1OperatingPoint},
+        traits::{CurveFitStrategy, MeanErrorSquareComparable},
+        units::{FanDiameter, InletAirflow, OutletArea, StaticPressure},
+        CalculationError,
+    };
+
+    use super::*;
+
+    #[test]
+    fn it_calculates() {
+        let test_event = test_report();
 
         let op_res = test_event.a1_operating_point_for(
             &FanDiameter::from_inches(27.0),
             &InletAirflow::from_cfm(7749.0),
             &StaticPressure::from_inches(3.789),
+            FitStrategy::default(),
         );
 
         assert!(op_res.is_ok());
@@ -193,4 +215,337 @@ mod tests {
             assert!(op_res.is_ok());
         }
     }
+
+    #[test]
+    fn it_corrects_for_air_density() {
+        // Tested in a lab at altitude, where air is 5/6 as dense as standard
+        let test_event = A1Standard2010Report {
+            parameters: A1Standard2010Parameters {
+                rpm: 1750.0,
+                air_density: Some(0.0625),
+            },
+            ..test_report()
+        };
+        let allowable_percent_error = (0.1_f64 / 100.0).powi(2);
+
+        // At the lab's density the tested point comes straight back
+        let lab_point = test_event
+            .clone()
+            .a1_operating_point_at_density_for(
+                &FanDiameter::from_inches(27.0),
+                &InletAirflow::from_cfm(7749.0),
+                &StaticPressure::from_inches(3.789),
+                &AirDensity::from_lb_per_cubic_foot(0.0625),
+                FitStrategy::default(),
+            )
+            .unwrap();
+        assert!(
+            lab_point.error_from(&A1InterpolationPoint::new(
+                FanSpeed::from_rpm(1750.0),
+                BrakeHorsepower::from_hp(7.481),
+            )) < allowable_percent_error
+        );
+
+        // In standard air the same speed develops proportionally more pressure and power
+        let standard_point = test_event
+            .a1_operating_point_for(
+                &FanDiameter::from_inches(27.0),
+                &InletAirflow::from_cfm(7749.0),
+                &StaticPressure::from_inches(3.789 * 1.2),
+                FitStrategy::default(),
+            )
+            .unwrap();
+        assert!(
+            standard_point.error_from(&A1InterpolationPoint::new(
+                FanSpeed::from_rpm(1750.0),
+                BrakeHorsepower::from_hp(7.481 * 1.2),
+            )) < allowable_percent_error
+        );
+    }
+
+    #[test]
+    fn it_only_rates_a_positive_airflow() {
+        for cfm in [0.0, -7749.0, f64::NAN] {
+            let result = test_report().a1_operating_point_at_density_for(
+                &FanDiameter::from_inches(27.0),
+                &InletAirflow::from_cfm(cfm),
+                &StaticPressure::from_inches(3.789),
+                &AirDensity::standard(),
+                FitStrategy::default(),
+            );
+            assert!(
+                matches!(result, Err(CalculationError::NonPositiveAirflow { .. })),
+                "{}",
+                cfm
+            );
+        }
+    }
+
+    #[test]
+    fn it_rates_with_the_chosen_fit() {
+        let rate = |cfm: f64, static_pressure: f64, fit: FitStrategy| {
+            test_report()
+                .a1_operating_point_for(
+                    &FanDiameter::from_inches(27.0),
+                    &InletAirflow::from_cfm(cfm),
+                    &StaticPressure::from_inches(static_pressure),
+                    fit,
+                )
+                .unwrap()
+        };
+
+        for fit in FitStrategy::all_options() {
+            let tested = rate(7749.0, 3.789, fit);
+            let fan_speed: &FanSpeed = tested.as_ref();
+            assert!((fan_speed.rpm() - 1750.0).abs() < 1e-6, "{:?}", fit);
+            let bhp: &BrakeHorsepower = tested.as_ref();
+            assert!((bhp.hp() - 7.481).abs() < 1e-6, "{:?}", fit);
+        }
+
+        // Between determinations a smooth fit bends where straight lines don't
+        let linear = rate(7200.0, 4.2, FitStrategy::PiecewiseLinear);
+        let smooth = rate(7200.0, 4.2, FitStrategy::MonotoneCubic);
+        assert!(smooth.error_from(&linear) > 0.0);
+    }
+
+    #[test]
+    fn it_finds_peak_static_efficiency() {
+        let fan_curve: FanCurve<A1OperatingPoint> = test_report().into();
+
+        let peak = fan_curve.peak_static_efficiency().unwrap();
+        let peak_airflow: &InletAirflow = peak.as_ref();
+        assert_eq!(peak_airflow.cfm(), 6659.0);
+        // 6659 cfm × 4.608 in. wg / 6356 / 7.416 hp
+        assert!((peak.static_efficiency().percent() - 65.1).abs() < 0.01);
+    }
+
+    #[test]
+    fn it_reports_total_pressure() {
+        let fan_curve: FanCurve<A1OperatingPoint> = vec![A1OperatingPoint::new(
+            FanSpeed::from_rpm(1750.0),
+            InletAirflow::from_cfm(4005.0),
+            StaticPressure::from_inches(2.0),
+            BrakeHorsepower::from_hp(2.5),
+        )]
+        .into_iter()
+        .collect();
+        // One square foot of outlet, so 4005 fpm is one inch of velocity pressure
+        let outlet_area = OutletArea::from_square_inches(144.0);
+
+        let (static_pressure, total_pressure) = fan_curve.total_pressures(&outlet_area)[0];
+        assert_eq!(static_pressure.inches(), 2.0);
+        assert!((total_pressure.inches() - 3.0).abs() < 1e-9);
+        assert!(
+            (fan_curve.as_ref()[0]
+                .total_efficiency(&outlet_area)
+                .fraction()
+                - 4005.0 * 3.0 / 6356.0 / 2.5)
+                .abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn it_intersects_system_curves() {
+        let fan_curve: FanCurve<A1OperatingPoint> = test_report().into();
+
+        // A system designed through a tested point lands on that point
+        let point = fan_curve
+            .system_curve_intersection(&SystemCurve::through(
+                &InletAirflow::from_cfm(7749.0),
+                &StaticPressure::from_inches(3.789),
+            ))
+            .unwrap();
+        let airflow: &InletAirflow = point.as_ref();
+        let brake_horsepower: &BrakeHorsepower = point.as_ref();
+        assert!((airflow.cfm() - 7749.0).abs() < 0.01);
+        assert!((brake_horsepower.hp() - 7.481).abs() < 1e-6);
+
+        // Too restrictive a system for the tested range
+        let low_pressure_curve: FanCurve<A1OperatingPoint> =
+            fan_curve.as_ref()[..7].iter().cloned().collect();
+        assert!(low_pressure_curve
+            .system_curve_intersection(&SystemCurve::new(1e-6))
+            .is_err());
+
+        // A stall dip that a flat system curve crosses three times
+        let stalled_curve: FanCurve<A1OperatingPoint> = [
+            (0.0, 10000.0, 5.0),
+            (4.0, 7000.0, 6.0),
+            (1.5, 5000.0, 5.5),
+            (4.5, 3000.0, 5.0),
+            (5.0, 0.0, 4.0),
+        ]
+        .into_iter()
+        .map(|(static_pressure, cfm, brake_horsepower)| {
+            A1OperatingPoint::new(
+                FanSpeed::from_rpm(1750.0),
+                InletAirflow::from_cfm(cfm),
+                StaticPressure::from_inches(static_pressure),
+                BrakeHorsepower::from_hp(brake_horsepower),
+            )
+        })
+        .collect();
+        assert!(stalled_curve
+            .system_curve_intersection(&SystemCurve::through(
+                &InletAirflow::from_cfm(5000.0),
+                &StaticPressure::from_inches(2.0),
+            ))
+            .is_err());
+    }
+
+    #[test]
+    fn it_fits_smooth_curves() {
+        let fan_curve: FanCurve<A1OperatingPoint> = test_report().into();
+        let brake_horsepower_at = |point: A1InterpolationPoint| -> f64 {
+            let brake_horsepower: &BrakeHorsepower = point.as_ref();
+            brake_horsepower.hp()
+        };
+
+        // Interpolating fits pass through the determinations exactly
+        let tested = StaticPressure::from_inches(3.789);
+        for point in [
+            fan_curve.interpolate_with(&PiecewiseLinear, &tested),
+            fan_curve.interpolate_with(&MonotoneCubic, &tested),
+            fan_curve.interpolate_with(&NaturalCubicSpline, &tested),
+        ] {
+            assert!((brake_horsepower_at(point.unwrap()) - 7.481).abs() < 1e-9);
+        }
+        for report in [
+            fan_curve.fit_report_with(&MonotoneCubic).unwrap(),
+            fan_curve.fit_report_with(&NaturalCubicSpline).unwrap(),
+        ] {
+            assert_eq!(report.residuals.len(), fan_curve.as_ref().len());
+            assert!(report.mean_square_error < 1e-12);
+        }
+
+        // Between determinations the monotone fit stays within its neighbours
+        let between = brake_horsepower_at(
+            fan_curve
+                .interpolate_with(&MonotoneCubic, &StaticPressure::from_inches(4.2))
+                .unwrap(),
+        );
+        assert!((7.416..=7.481).contains(&between));
+
+        // A low order polynomial smooths over the points instead
+        let quadratic = PolynomialLeastSquares { degree: 2 };
+        let report = fan_curve.fit_report_with(&quadratic).unwrap();
+        assert!(report.mean_square_error > 0.0);
+        assert!(report.mean_square_error < (5.0_f64 / 100.0).powi(2));
+        assert!(PolynomialLeastSquares { degree: 10 }
+            .fit(&[(0.0, 1.0), (1.0, 2.0)])
+            .is_err());
+        assert!(PolynomialLeastSquares { degree: 0 }
+            .fit(&[(1.0, 2.0)])
+            .is_err());
+
+        assert!(fan_curve
+            .interpolate_with(&MonotoneCubic, &StaticPressure::from_inches(7.0))
+            .is_err());
+    }
+
+    #[test]
+    fn it_keeps_to_the_stable_branch() {
+        let report_from = |raw_dets: &[(f64, f64, f64)]| A1Standard2010Report {
+            determinations: raw_dets
+                .iter()
+                .map(
+                    |&(static_pressure, cfm, brake_horsepower)| A1Standard2010Determination {
+                        cfm,
+                        static_pressure,
+                        brake_horsepower,
+                    },
+                )
+                .collect(),
+            ..test_report()
+        };
+
+        // The seeded curve keeps rising all the way to shut off
+        let seeded_curve: FanCurve<A1OperatingPoint> = test_report().into();
+        assert!(seeded_curve.stall_airflow().is_none());
+        assert!(seeded_curve.stall_branch().as_ref().is_empty());
+
+        // Pressure peaks at 7000 cfm, then dips into stall before recovering
+        let stalled_report = report_from(&[
+            (0.0, 10000.0, 5.0),
+            (4.0, 7000.0, 6.0),
+            (1.5, 5000.0, 5.5),
+            (4.5, 3000.0, 5.0),
+            (5.0, 0.0, 4.0),
+        ]);
+        let stalled_curve: FanCurve<A1OperatingPoint> = stalled_report.clone().into();
+        assert_eq!(stalled_curve.stall_airflow().unwrap().cfm(), 7000.0);
+        assert_eq!(stalled_curve.stable_branch().as_ref().len(), 2);
+        assert_eq!(stalled_curve.stall_branch().as_ref().len(), 4);
+        assert!(stalled_curve.is_stalled_at(&InletAirflow::from_cfm(5000.0)));
+        assert!(!stalled_curve.is_stalled_at(&InletAirflow::from_cfm(7000.0)));
+
+        let fan_diameter = FanDiameter::from_inches(27.0);
+        let inlet_airflow = InletAirflow::from_cfm(7000.0);
+        let static_pressure = StaticPressure::from_inches(3.5);
+        let candidates = stalled_report
+            .clone()
+            .a1_operating_point_candidates_for(&fan_diameter, &inlet_airflow, &static_pressure)
+            .unwrap();
+        assert_eq!(
+            candidates
+                .iter()
+                .map(|candidate| candidate.stalled)
+                .collect::<Vec<_>>(),
+            vec![false, true, true]
+        );
+
+        // Rating picks the stable candidate rather than whichever bracket sorts first
+        let rated = stalled_report
+            .a1_operating_point_for(
+                &fan_diameter,
+                &inlet_airflow,
+                &static_pressure,
+                FitStrategy::default(),
+            )
+            .unwrap();
+        assert!(rated.error_from(&candidates[0].operating_point) < 1e-12);
+        let rated_speed: &FanSpeed = rated.as_ref();
+        assert!(rated_speed.rpm() < 1750.0);
+    }
+
+    #[test]
+    fn it_draws_curve_families() {
+        let test_event = test_report();
+
+        let fan_speeds =
+            fan_speeds_between(&FanSpeed::from_rpm(875.0), &FanSpeed::from_rpm(1750.0), 3);
+        assert_eq!(
+            fan_speeds.iter().map(FanSpeed::rpm).collect::<Vec<_>>(),
+            vec![875.0, 1312.5, 1750.0]
+        );
+
+        let family = test_event
+            .a1_curve_family_for(&FanDiameter::from_inches(27.0), &fan_speeds)
+            .unwrap();
+        assert_eq!(family.curves.len(), 3);
+        // Half speed gives half the airflow at a quarter of the pressure
+        let half_speed_free_delivery = family.curves[0].as_ref().last().unwrap();
+        let airflow: &InletAirflow = half_speed_free_delivery.as_ref();
+        assert!((airflow.cfm() - 11077.0 / 2.0).abs() < 1e-6);
+
+        assert!(!family.brake_horsepower_lines.is_empty());
+        for (brake_horsepower, line) in &family.brake_horsepower_lines {
+            assert!(line.len() > 1);
+            for point in line {
+                let point_brake_horsepower: &BrakeHorsepower = point.as_ref();
+                assert!((point_brake_horsepower.hp() - brake_horsepower.hp()).abs() < 1e-6);
+            }
+        }
+
+        assert!(!family.static_efficiency_contours.is_empty());
+        for (efficiency, contour) in &family.static_efficiency_contours {
+            assert!(efficiency.fraction() < 0.651);
+            for point in contour {
+                assert!(
+                    (point.static_efficiency().fraction() - efficiency.fraction()).abs() < 0.01
+                );
+            }
+        }
+    }
 }
@@ -1,3 +1,6 @@
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+
 use crate::{
     calculations::{
         core::FanCurve,
@@ -9,37 +12,118 @@ use crate::{
 
 use crate::models::{A1Standard2010Report, InducedFlowFanSize};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct A2Standard2010Parameters {
-    rpm: f64,
+    pub rpm: f64,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct A2Standard2010Determination {
-    cfm: f64,
-    static_pressure: f64,
+    pub cfm: f64,
+    pub static_pressure: f64,
+}
+impl Eq for A2Standard2010Determination {}
+impl Hash for A2Standard2010Determination {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.cfm.to_bits().hash(state);
+        self.static_pressure.to_bits().hash(state);
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct A2Standard2010Report<A1ReportRepr: 'static, InducedFlowFanSizeRepr: 'static> {
+    pub id: String,
+    pub a1_report_id: String,
+    pub a1_report: A1ReportRepr,
+    pub induced_flow_fan_size_id: String,
+    pub induced_flow_fan_size: InducedFlowFanSizeRepr,
+    pub parameters: A2Standard2010Parameters,
+    pub determinations: Vec<A2Standard2010Determination>,
+}
+
+impl<A1ReportRepr, InducedFlowFanSizeRepr>
+    From<(
+        A2Standard2010Report<(), ()>,
+        A1ReportRepr,
+        InducedFlowFanSizeRepr,
+    )> for A2Standard2010Report<A1ReportRepr, InducedFlowFanSizeRepr>
+{
+    fn from(
+        value: (
+            A2Standard2010Report<(), ()>,
+            A1ReportRepr,
+            InducedFlowFanSizeRepr,
+        ),
+    ) -> Self {
+        let (
+            A2Standard2010Report {
+                id,
+                a1_report_id,
+                a1_report: _,
+                induced_flow_fan_size_id,
+                induced_flow_fan_size: _,
+                parameters,
+                determinations,
+            },
+            a1_report,
+            induced_flow_fan_size,
+        ) = value;
+        A2Standard2010Report {
+            id,
+            a1_report_id,
+            a1_report,
+            induced_flow_fan_size_id,
+            induced_flow_fan_size,
+            parameters,
+            determinations,
+        }
+    }
 }
 
-#[derive(Clone, Debug)]
-pub struct A2Standard2010Report<A1FanSizeRepr: 'static, A2InducedFanSizeRepr: 'static> {
-    a1_report: A1Standard2010Report<A1FanSizeRepr>,
-    induced_flow_fan_size: A2InducedFanSizeRepr,
-    #[allow(dead_code, unused_attributes)] // this is unused because these don't really exist yet
-    induced_flow_fan_size_id: String,
-    parameters: A2Standard2010Parameters,
-    determinations: [A2Standard2010Determination; 10],
+impl<A1ReportRepr, InducedFlowFanSizeRepr>
+    From<A2Standard2010Report<A1ReportRepr, InducedFlowFanSizeRepr>>
+    for (
+        A2Standard2010Report<(), ()>,
+        A1ReportRepr,
+        InducedFlowFanSizeRepr,
+    )
+{
+    fn from(value: A2Standard2010Report<A1ReportRepr, InducedFlowFanSizeRepr>) -> Self {
+        let A2Standard2010Report {
+            id,
+            a1_report_id,
+            a1_report,
+            induced_flow_fan_size_id,
+            induced_flow_fan_size,
+            parameters,
+            determinations,
+        } = value;
+        (
+            A2Standard2010Report {
+                id,
+                a1_report_id,
+                a1_report: (),
+                induced_flow_fan_size_id,
+                induced_flow_fan_size: (),
+                parameters,
+                determinations,
+            },
+            a1_report,
+            induced_flow_fan_size,
+        )
+    }
 }
 
-impl<A1Series, A2Size> From<A2Standard2010Report<FanSize<A1Series>, A2Size>>
+impl<A1Series, A2Size> From<A2Standard2010Report<A1Standard2010Report<FanSize<A1Series>>, A2Size>>
     for FanCurve<A1OperatingPoint>
 {
-    fn from(value: A2Standard2010Report<FanSize<A1Series>, A2Size>) -> Self {
+    fn from(value: A2Standard2010Report<A1Standard2010Report<FanSize<A1Series>>, A2Size>) -> Self {
         Self::from(value.a1_report)
     }
 }
 
-impl<A1Size, A2Size> From<A2Standard2010Report<A1Size, A2Size>> for FanCurve<A2OperatingPoint> {
-    fn from(value: A2Standard2010Report<A1Size, A2Size>) -> Self {
+impl<A1Report, A2Size> From<A2Standard2010Report<A1Report, A2Size>> for FanCurve<A2OperatingPoint> {
+    fn from(value: A2Standard2010Report<A1Report, A2Size>) -> Self {
         value
             .determinations
             .iter()
@@ -54,25 +138,22 @@ impl<A1Size, A2Size> From<A2Standard2010Report<A1Size, A2Size>> for FanCurve<A2O
     }
 }
 
-impl<A1Size, A2Series, A2Nozzle>
-    From<A2Standard2010Report<A1Size, InducedFlowFanSize<FanSize<A2Series>, A2Nozzle>>>
+impl<A1Report, A2Series, A2Nozzle>
+    From<A2Standard2010Report<A1Report, InducedFlowFanSize<FanSize<A2Series>, A2Nozzle>>>
     for FanDiameter
 {
     fn from(
-        value: A2Standard2010Report<A1Size, InducedFlowFanSize<FanSize<A2Series>, A2Nozzle>>,
+        value: A2Standard2010Report<A1Report, InducedFlowFanSize<FanSize<A2Series>, A2Nozzle>>,
     ) -> Self {
         FanDiameter::from_inches(value.induced_flow_fan_size.fan_size.diameter)
     }
 }
 
-// impl AsRef<FanDiameter> for A2Standard2010Report {
-//     fn as_ref(&self) -> &FanDiameter {
-//         &FanDiameter::from_inches(self.fan_size.diameter)
-//     }
-// }
-
 impl<A1Series: Clone, A2Series: Clone, A2Nozzle: Clone> CanProduceA1A2Curve
-    for A2Standard2010Report<FanSize<A1Series>, InducedFlowFanSize<FanSize<A2Series>, A2Nozzle>>
+    for A2Standard2010Report<
+        A1Standard2010Report<FanSize<A1Series>>,
+        InducedFlowFanSize<FanSize<A2Series>, A2Nozzle>,
+    >
 {
 }
 
@@ -84,6 +165,7 @@ mod tests {
             core::InterpolableFanCurve,
             traits::Interpolable,
             units::{FanDiameter, StaticPressure},
+            CalculationError,
         },
         models::{
             a1_2010_report::{A1Standard2010Determination, A1Standard2010Parameters},
@@ -147,13 +229,16 @@ mod tests {
             id: "Test Id".to_string(),
             fan_size: fan_size.clone(),
             fan_size_id: "SKYPLUME G1-ELLV-18 DMF-150".to_string(),
-            parameters: A1Standard2010Parameters { rpm: 1750.0 },
+            parameters: A1Standard2010Parameters {
+                rpm: 1750.0,
+                air_density: None,
+            },
             determinations: a1_determinations.into(),
         };
 
         let raw_a2_dets = [
             // SP   CFM
-            (0.040, 1530.0),
+            (0.040, 15300.0),
             (1.227, 13816.0),
             (2.537, 12386.0),
             (3.738, 10815.0),
@@ -176,6 +261,8 @@ mod tests {
             .unwrap();
 
         let a2_test_event = A2Standard2010Report {
+            id: "Test A2 Id".to_string(),
+            a1_report_id: a1_test_event.id.clone(),
             a1_report: a1_test_event,
             induced_flow_fan_size_id: "induced_flow_fan_size".to_string(),
             induced_flow_fan_size: InducedFlowFanSize {
@@ -189,11 +276,11 @@ mod tests {
             },
 
             parameters: A2Standard2010Parameters { rpm: 1750.0 },
-            determinations: a2_determinations,
+            determinations: a2_determinations.into(),
         };
 
         let a2_curve: FanCurve<A2OperatingPoint> = FanCurve::from(a2_test_event.clone());
-        let interpolated_outlet: Result<OutletAirflow, String> =
+        let interpolated_outlet: Result<OutletAirflow, CalculationError> =
             a2_curve.interpolate(&StaticPressure::from_inches(2.593));
 
         assert_eq!(
@@ -214,7 +301,16 @@ mod tests {
         );
         assert_eq!(
             pairwise_interpolated_outlet,
-            OutletAirflow::from_cfm(12312.747710241465)
+            Ok(OutletAirflow::from_cfm(12312.747710241465))
+        );
+
+        assert_eq!(
+            a2_curve.interpolate(&StaticPressure::from_inches(20.0)),
+            Err(CalculationError::OutOfRange {
+                target: 20.0,
+                low: 0.04,
+                high: 6.646,
+            })
         );
 
         // rpm 1750            cfm 1281.0,   static 1.911,  BHP 0.850),
@@ -244,7 +340,7 @@ mod tests {
                     .map(|e| e.induced_ratio())
                     .collect::<Vec<_>>(),
                 vec![
-                    1.3396383203908824,
+                    1.389616176379603,
                     1.3859463879155183,
                     1.3834084782728928,
                     1.3701813159900245,
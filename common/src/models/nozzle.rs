@@ -1,4 +1,6 @@
-#[derive(PartialEq, Eq, Clone, Debug)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, PartialEq, Eq, Clone, Debug)]
 pub struct Nozzle {
     pub id: String,
 }
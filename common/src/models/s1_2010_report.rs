@@ -0,0 +1,241 @@
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+
+use crate::{
+    calculations::{
+        core::FanCurve,
+        standards::{CanFindS1SoundRating, S1OperatingPoint},
+        units::{FanDiameter, FanSpeed, InletAirflow, OctaveBandSoundPower, StaticPressure},
+    },
+    models::fan_size::FanSize,
+};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct S1Standard2010Parameters {
+    pub rpm: f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct S1Standard2010Determination {
+    pub cfm: f64,
+    pub static_pressure: f64,
+    // dB re 1 pW in the 63, 125, 250, 500, 1k, 2k, 4k and 8k Hz octave bands
+    pub sound_power_levels: [f64; 8],
+}
+impl Eq for S1Standard2010Determination {}
+impl Hash for S1Standard2010Determination {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.cfm.to_bits().hash(state);
+        self.static_pressure.to_bits().hash(state);
+        for level in self.sound_power_levels {
+            level.to_bits().hash(state);
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct S1Standard2010Report<FanSizeRepr: 'static> {
+    pub id: String,
+    pub fan_size: FanSizeRepr,
+    pub fan_size_id: String,
+    pub parameters: S1Standard2010Parameters,
+    pub determinations: Vec<S1Standard2010Determination>,
+}
+
+impl<FanSizeRepr> From<(S1Standard2010Report<()>, FanSizeRepr)>
+    for S1Standard2010Report<FanSizeRepr>
+{
+    fn from(value: (S1Standard2010Report<()>, FanSizeRepr)) -> Self {
+        let (
+            S1Standard2010Report {
+                id,
+                fan_size: _,
+                fan_size_id,
+                parameters,
+                determinations,
+            },
+            fan_size,
+        ) = value;
+        S1Standard2010Report {
+            id,
+            fan_size,
+            fan_size_id,
+            parameters,
+            determinations,
+        }
+    }
+}
+
+impl<FanSizeRepr> From<S1Standard2010Report<FanSizeRepr>>
+    for (S1Standard2010Report<()>, FanSizeRepr)
+{
+    fn from(value: S1Standard2010Report<FanSizeRepr>) -> Self {
+        let S1Standard2010Report {
+            id,
+            fan_size,
+            fan_size_id,
+            parameters,
+            determinations,
+        } = value;
+        (
+            S1Standard2010Report {
+                id,
+                fan_size: (),
+                fan_size_id,
+                parameters,
+                determinations,
+            },
+            fan_size,
+        )
+    }
+}
+
+impl<R> From<S1Standard2010Report<R>> for FanCurve<S1OperatingPoint> {
+    fn from(value: S1Standard2010Report<R>) -> Self {
+        value
+            .determinations
+            .iter()
+            .map(|op| {
+                S1OperatingPoint::new(
+                    FanSpeed::from_rpm(value.parameters.rpm),
+                    InletAirflow::from_cfm(op.cfm),
+                    StaticPressure::from_inches(op.static_pressure),
+                    OctaveBandSoundPower::from_decibels(op.sound_power_levels),
+                )
+            })
+            .collect()
+    }
+}
+impl<R> From<S1Standard2010Report<FanSize<R>>> for FanDiameter {
+    fn from(value: S1Standard2010Report<FanSize<R>>) -> Self {
+        FanDiameter::from_inches(value.fan_size.diameter)
+    }
+}
+
+impl<R: Clone> CanFindS1SoundRating for S1Standard2010Report<FanSize<R>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        calculations::{
+            standards::{CanFindS1SoundRating, S1InterpolationPoint},
+            traits::MeanErrorSquareComparable,
+            units::{FanDiameter, InletAirflow, StaticPressure},
+        },
+        models::{fan_series::FanSeries, fan_size::FanSize, fan_type::FanType},
+    };
+
+    use super::*;
+
+    #[test]
+    fn it_calculates() {
+        let raw_dets = [
+            // SP   CFM      63   125  250  500  1k   2k   4k   8k
+            (
+                0.001,
+                11077.0,
+                [95.0, 93.0, 90.0, 88.0, 86.0, 83.0, 79.0, 74.0],
+            ),
+            (
+                1.184,
+                9981.0,
+                [95.5, 93.2, 90.5, 88.1, 86.2, 83.1, 79.4, 74.2],
+            ),
+            (
+                2.593,
+                8884.0,
+                [96.1, 93.8, 91.0, 88.7, 86.5, 83.6, 79.8, 74.8],
+            ),
+            (
+                3.789,
+                7749.0,
+                [96.8, 94.5, 91.6, 89.3, 87.0, 84.0, 80.3, 75.1],
+            ),
+            (
+                4.608,
+                6659.0,
+                [97.9, 95.6, 92.4, 90.1, 87.6, 84.7, 80.9, 75.8],
+            ),
+            (
+                5.158,
+                5524.0,
+                [99.2, 96.8, 93.5, 91.0, 88.3, 85.2, 81.6, 76.4],
+            ),
+        ];
+        let test_points: Vec<S1Standard2010Determination> = raw_dets
+            .into_iter()
+            .map(
+                |(static_pressure, cfm, sound_power_levels)| S1Standard2010Determination {
+                    cfm,
+                    static_pressure,
+                    sound_power_levels,
+                },
+            )
+            .collect::<Vec<_>>();
+
+        let fan_series_id = "SKYPLUME G1-ELLV DMF".to_string();
+        let test_event = S1Standard2010Report {
+            id: "report-id-test".to_string(),
+            fan_size_id: "SKYPLUME G1-ELLV-18 DMF-150".to_string(),
+            fan_size: FanSize {
+                id: "SKYPLUME G1-ELLV-18 DMF-150".to_string(),
+                fan_series_id: fan_series_id.clone(),
+                fan_series: FanSeries {
+                    id: fan_series_id.clone(),
+                    fan_type: FanType::Axial,
+                    fan_sizes: (),
+                },
+                diameter: 27.0,
+                outlet_area: 100.0,
+            },
+            parameters: S1Standard2010Parameters { rpm: 1750.0 },
+            determinations: test_points,
+        };
+
+        // At a tested point the rating should be the tested sound
+        let tested_point = test_event
+            .clone()
+            .s1_sound_rating_for(
+                &FanDiameter::from_inches(27.0),
+                &InletAirflow::from_cfm(7749.0),
+                &StaticPressure::from_inches(3.789),
+            )
+            .unwrap();
+        let actual_point = S1InterpolationPoint::new(
+            FanSpeed::from_rpm(1750.0),
+            OctaveBandSoundPower::from_decibels([96.8, 94.5, 91.6, 89.3, 87.0, 84.0, 80.3, 75.1]),
+        );
+        let allowable_percent_error = (0.1_f64 / 100.0).powi(2);
+        assert!(tested_point.error_from(&actual_point) < allowable_percent_error);
+
+        // Doubling the diameter at constant speed adds 70 log(2) dB to every band, and
+        // scaling the tested SP and airflow with it keeps us on the same curve point
+        let scaled_point = test_event
+            .s1_sound_rating_for(
+                &FanDiameter::from_inches(54.0),
+                &InletAirflow::from_cfm(7749.0 * 8.0),
+                &StaticPressure::from_inches(3.789 * 4.0),
+            )
+            .unwrap();
+        let tested_sound: &OctaveBandSoundPower = tested_point.as_ref();
+        let scaled_sound: &OctaveBandSoundPower = scaled_point.as_ref();
+        for (tested, scaled) in tested_sound.decibels().iter().zip(scaled_sound.decibels()) {
+            assert!((scaled - tested - 70.0 * 2_f64.log10()).abs() < 1e-6);
+        }
+        assert!(
+            (scaled_point.a_weighted_sound_power().decibels()
+                - tested_point.a_weighted_sound_power().decibels()
+                - 70.0 * 2_f64.log10())
+            .abs()
+                < 1e-6
+        );
+    }
+
+    #[test]
+    fn it_a_weights() {
+        let flat = OctaveBandSoundPower::from_decibels([80.0; 8]);
+        // Equal bands sum to 10 log(8) above any single band
+        assert!((flat.total().decibels() - (80.0 + 10.0 * 8_f64.log10())).abs() < 1e-9);
+        assert!((flat.a_weighted().decibels() - 86.99).abs() < 0.01);
+    }
+}
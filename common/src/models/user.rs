@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+
+use crate::display::UnitSystem;
+
+// Ordered from least to most trusted, each role can do everything the ones before it can
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Role {
+    #[serde(rename = "viewer")]
+    Viewer,
+    #[serde(rename = "lab_technician")]
+    LabTechnician,
+    #[serde(rename = "engineer")]
+    Engineer,
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl Role {
+    pub fn all_options() -> Vec<Self> {
+        vec![
+            Self::Viewer,
+            Self::LabTechnician,
+            Self::Engineer,
+            Self::Admin,
+        ]
+    }
+
+    // Lab technicians record test reports
+    pub fn can_edit_reports(&self) -> bool {
+        *self >= Self::LabTechnician
+    }
+
+    // Engineers decide which series and sizes are in the catalogue
+    pub fn can_edit_catalogue(&self) -> bool {
+        *self >= Self::Engineer
+    }
+
+    pub fn can_manage_users(&self) -> bool {
+        *self >= Self::Admin
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Viewer => "viewer",
+            Self::LabTechnician => "lab_technician",
+            Self::Engineer => "engineer",
+            Self::Admin => "admin",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseRoleError;
+
+impl TryFrom<&str> for Role {
+    type Error = ParseRoleError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "viewer" => Ok(Self::Viewer),
+            "lab_technician" => Ok(Self::LabTechnician),
+            "engineer" => Ok(Self::Engineer),
+            "admin" => Ok(Self::Admin),
+            _ => Err(ParseRoleError),
+        }
+    }
+}
+
+// Anyone who signs in with Firebase gets a user, but they can't see anything
+// until an admin gives them a role
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct User {
+    pub id: String,
+    pub name: Option<String>,
+    pub role: Option<Role>,
+    #[serde(default)]
+    pub unit_system: UnitSystem,
+}
+
+impl User {
+    pub fn is_authorized(&self) -> bool {
+        self.role.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_round_trips_roles() {
+        for role in Role::all_options() {
+            assert_eq!(Role::try_from(role.as_str()), Ok(role));
+        }
+        assert_eq!(Role::try_from("superuser"), Err(ParseRoleError));
+    }
+
+    #[test]
+    fn it_grants_permissions_by_role() {
+        assert!(!Role::Viewer.can_edit_reports());
+        assert!(Role::LabTechnician.can_edit_reports());
+
+        assert!(!Role::LabTechnician.can_edit_catalogue());
+        assert!(Role::Engineer.can_edit_catalogue());
+        assert!(Role::Engineer.can_edit_reports());
+
+        assert!(!Role::Engineer.can_manage_users());
+        assert!(Role::Admin.can_manage_users());
+        assert!(Role::Admin.can_edit_catalogue());
+    }
+}
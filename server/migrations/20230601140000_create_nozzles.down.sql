@@ -0,0 +1 @@
+DROP TABLE IF EXISTS nozzles CASCADE;
\ No newline at end of file
@@ -0,0 +1,5 @@
+CREATE EXTENSION IF NOT EXISTS "uuid-ossp";
+
+CREATE TABLE nozzles (
+  nozzle_id VARCHAR(255) NOT NULL PRIMARY KEY
+);
\ No newline at end of file
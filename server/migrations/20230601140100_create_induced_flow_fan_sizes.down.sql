@@ -0,0 +1 @@
+DROP TABLE IF EXISTS induced_flow_fan_sizes CASCADE;
\ No newline at end of file
@@ -0,0 +1,9 @@
+CREATE EXTENSION IF NOT EXISTS "uuid-ossp";
+
+CREATE TABLE induced_flow_fan_sizes (
+  induced_flow_fan_size_id VARCHAR(255) NOT NULL PRIMARY KEY,
+  fan_size_id VARCHAR(255) NOT NULL,
+  nozzle_id VARCHAR(255) NOT NULL,
+  CONSTRAINT fk_fan_size_id FOREIGN KEY (fan_size_id) REFERENCES fan_sizes(fan_size_id),
+  CONSTRAINT fk_nozzle_id FOREIGN KEY (nozzle_id) REFERENCES nozzles(nozzle_id)
+);
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS a2_2010_reports CASCADE;
\ No newline at end of file
@@ -0,0 +1,12 @@
+CREATE EXTENSION IF NOT EXISTS "uuid-ossp";
+
+CREATE TABLE a2_2010_reports (
+  a2_2010_report_id VARCHAR(255) NOT NULL PRIMARY KEY,
+  a1_2010_report_id VARCHAR(255) NOT NULL,
+  induced_flow_fan_size_id VARCHAR(255) NOT NULL,
+  rpm FLOAT NOT NULL,
+  -- pub determinations: Vec<A2Standard2010Determination>,
+  determinations JSONB NOT NULL,
+  CONSTRAINT fk_a1_2010_report_id FOREIGN KEY (a1_2010_report_id) REFERENCES a1_2010_reports(a1_2010_report_id) ON UPDATE CASCADE,
+  CONSTRAINT fk_induced_flow_fan_size_id FOREIGN KEY (induced_flow_fan_size_id) REFERENCES induced_flow_fan_sizes(induced_flow_fan_size_id)
+);
\ No newline at end of file
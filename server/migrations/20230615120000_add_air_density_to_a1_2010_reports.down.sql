@@ -0,0 +1 @@
+ALTER TABLE a1_2010_reports DROP COLUMN IF EXISTS air_density;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- lb/ft³ in the test chamber, NULL when tested at standard air
+ALTER TABLE a1_2010_reports ADD COLUMN air_density FLOAT;
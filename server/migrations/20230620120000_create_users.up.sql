@@ -0,0 +1,14 @@
+-- user_id is the Firebase UID, role is NULL until an admin approves the user
+CREATE TABLE users (
+  user_id VARCHAR(255) NOT NULL PRIMARY KEY,
+  name VARCHAR(255),
+  role VARCHAR(64),
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+  CONSTRAINT chk_role CHECK (role IN ('viewer', 'lab_technician', 'engineer', 'admin'))
+);
+
+-- Previously hardcoded in sessions.rs
+INSERT INTO users (user_id, name, role) VALUES
+  ('eY9lIYhF4QTZHdz8VAHoyOeCm1S2', 'RRM', 'admin'),
+  ('DYTCeA3RJNZpks2fQr2clWLKFE83', 'JKJ', 'admin');
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS a1_2010_report_revisions CASCADE;
+DROP FUNCTION IF EXISTS forbid_a1_2010_report_revision_changes;
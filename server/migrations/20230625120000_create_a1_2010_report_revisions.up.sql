@@ -0,0 +1,45 @@
+-- Append-only history of every saved version of an A1 report, kept for AMCA certification audits.
+-- Renaming a report cascades to its revisions, anything else is rejected by the trigger below.
+CREATE TABLE a1_2010_report_revisions (
+  revision_id BIGSERIAL NOT NULL PRIMARY KEY,
+  a1_2010_report_id VARCHAR(255) NOT NULL REFERENCES a1_2010_reports (a1_2010_report_id) ON UPDATE CASCADE,
+  user_id VARCHAR(255) REFERENCES users (user_id),
+  action VARCHAR(16) NOT NULL,
+  restored_from_revision_id BIGINT REFERENCES a1_2010_report_revisions (revision_id),
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  snapshot JSONB NOT NULL,
+
+  CONSTRAINT chk_action CHECK (action IN ('create', 'update', 'restore'))
+);
+
+CREATE INDEX idx_a1_2010_report_revisions_report ON a1_2010_report_revisions (a1_2010_report_id);
+
+CREATE FUNCTION forbid_a1_2010_report_revision_changes() RETURNS TRIGGER AS $$
+BEGIN
+  IF TG_OP = 'DELETE'
+    OR NEW.revision_id IS DISTINCT FROM OLD.revision_id
+    OR NEW.user_id IS DISTINCT FROM OLD.user_id
+    OR NEW.action IS DISTINCT FROM OLD.action
+    OR NEW.restored_from_revision_id IS DISTINCT FROM OLD.restored_from_revision_id
+    OR NEW.created_at IS DISTINCT FROM OLD.created_at
+    OR NEW.snapshot IS DISTINCT FROM OLD.snapshot
+  THEN
+    RAISE EXCEPTION 'A1 report revisions are append-only';
+  END IF;
+  RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER a1_2010_report_revisions_append_only
+  BEFORE UPDATE OR DELETE ON a1_2010_report_revisions
+  FOR EACH ROW EXECUTE FUNCTION forbid_a1_2010_report_revision_changes();
+
+-- Reports saved before revisions were tracked start with their current contents
+INSERT INTO a1_2010_report_revisions (a1_2010_report_id, action, snapshot)
+  SELECT a1_2010_report_id, 'create', jsonb_build_object(
+    'id', a1_2010_report_id,
+    'fan_rpm', rpm,
+    'fan_size_id', fan_size_id,
+    'air_density', air_density,
+    'determinations', determinations
+  ) FROM a1_2010_reports;
@@ -0,0 +1,3 @@
+-- Values are stored in imperial units either way, this only picks how they're shown
+ALTER TABLE users ADD COLUMN unit_system VARCHAR(16) NOT NULL DEFAULT 'imperial'
+  CONSTRAINT chk_unit_system CHECK (unit_system IN ('imperial', 'si'));
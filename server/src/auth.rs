@@ -0,0 +1,240 @@
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use jwt::{AlgorithmType, Header, PKeyWithDigest, Token, VerifyWithKey};
+use openssl::{
+    base64,
+    bn::BigNum,
+    hash::MessageDigest,
+    pkey::{PKey, Public},
+    rsa::Rsa,
+};
+use serde::{Deserialize, Serialize};
+
+pub const GOOGLE_JWKS_URL: &str =
+    "https://www.googleapis.com/service_accounts/v1/jwk/securetoken@system.gserviceaccount.com";
+
+// Used when Google doesn't say how long its keys can be cached for
+const DEFAULT_KEY_LIFETIME: Duration = Duration::from_secs(60 * 60);
+// Unknown key ids refresh the keys, but no more often than this so forged
+// tokens can't make us hammer Google
+const MINIMUM_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+// Allows for our clock drifting a little from Google's
+const CLOCK_SKEW_SECONDS: u64 = 60;
+
+// https://firebase.google.com/docs/auth/admin/verify-id-tokens#verify_id_tokens_using_a_third-party_jwt_library
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Claims {
+    pub aud: String, // Must be the Firebase project ID
+    pub exp: u64,    // Must be in the future, in seconds since the UNIX epoch
+    pub iat: u64,    // Must be in the past, in seconds since the UNIX epoch
+    pub iss: String, // Must be "https://securetoken.google.com/<projectId>"
+    pub sub: String, // Must be non-empty, it's the uid of the user
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeySource {
+    File(PathBuf),
+    Url(String),
+}
+
+impl KeySource {
+    pub fn parse(location: &str) -> Self {
+        if location.starts_with("https://") || location.starts_with("http://") {
+            KeySource::Url(location.to_string())
+        } else {
+            KeySource::File(PathBuf::from(location))
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Jwk {
+    kid: String,
+    kty: String,
+    n: String,
+    e: String,
+}
+
+#[derive(Deserialize)]
+struct JwkSet {
+    keys: Vec<Jwk>,
+}
+
+pub struct Jwks {
+    keys: HashMap<String, PKey<Public>>,
+}
+
+impl Jwks {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let jwk_set: JwkSet =
+            serde_json::from_str(json).map_err(|err| format!("Could not parse JWKS: '{}'", err))?;
+        let keys = jwk_set
+            .keys
+            .into_iter()
+            .filter(|jwk| jwk.kty == "RSA")
+            .map(|jwk| {
+                let modulus = BigNum::from_slice(&decode_base64_url(&jwk.n)?)
+                    .map_err(|err| err.to_string())?;
+                let exponent = BigNum::from_slice(&decode_base64_url(&jwk.e)?)
+                    .map_err(|err| err.to_string())?;
+                let key = Rsa::from_public_components(modulus, exponent)
+                    .and_then(PKey::from_rsa)
+                    .map_err(|err| format!("Invalid key '{}': '{}'", jwk.kid, err))?;
+                Ok((jwk.kid, key))
+            })
+            .collect::<Result<HashMap<_, _>, String>>()?;
+        Ok(Jwks { keys })
+    }
+
+    pub fn get(&self, key_id: &str) -> Option<&PKey<Public>> {
+        self.keys.get(key_id)
+    }
+}
+
+fn decode_base64_url(encoded: &str) -> Result<Vec<u8>, String> {
+    let mut standard = encoded.replace('-', "+").replace('_', "/");
+    standard.push_str(&"=".repeat((4 - standard.len() % 4) % 4));
+    base64::decode_block(&standard).map_err(|err| err.to_string())
+}
+
+struct CachedJwks {
+    jwks: Arc<Jwks>,
+    fetched_at: Instant,
+    lifetime: Option<Duration>,
+}
+
+// Checks Firebase ID tokens against Google's published signing keys, which are
+// read from a file or URL and cached until Google says they expire
+pub struct FirebaseVerifier {
+    project_id: String,
+    key_source: KeySource,
+    cache: RwLock<Option<CachedJwks>>,
+}
+
+impl FirebaseVerifier {
+    pub fn new(project_id: String, key_source: KeySource) -> Self {
+        FirebaseVerifier {
+            project_id,
+            key_source,
+            cache: RwLock::new(None),
+        }
+    }
+
+    pub async fn verify(&self, token: &str) -> Result<Claims, String> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|err| err.to_string())?
+            .as_secs();
+        self.verify_at(token, now).await
+    }
+
+    pub async fn verify_at(&self, token: &str, now: u64) -> Result<Claims, String> {
+        let unverified: Token<Header, Claims, _> =
+            Token::parse_unverified(token).map_err(|err| err.to_string())?;
+        let header = unverified.header();
+        if header.algorithm != AlgorithmType::Rs256 {
+            return Err(format!(
+                "Tokens must be signed with RS256, not {:?}",
+                header.algorithm
+            ));
+        }
+        let key_id = header
+            .key_id
+            .clone()
+            .ok_or_else(|| "Token has no key id".to_string())?;
+
+        let mut jwks = self.jwks(false).await?;
+        if jwks.get(&key_id).is_none() {
+            // Google rotates its keys, so this may be one we haven't seen yet
+            jwks = self.jwks(true).await?;
+        }
+        let key = jwks
+            .get(&key_id)
+            .ok_or_else(|| format!("Token was signed with an unknown key '{}'", key_id))?;
+
+        let verified: Token<Header, Claims, _> = token
+            .verify_with_key(&PKeyWithDigest {
+                digest: MessageDigest::sha256(),
+                key: key.clone(),
+            })
+            .map_err(|err| err.to_string())?;
+        let claims = verified.claims();
+        validate_claims(claims, &self.project_id, now)?;
+        Ok(claims.clone())
+    }
+
+    async fn jwks(&self, refresh: bool) -> Result<Arc<Jwks>, String> {
+        let fresh_jwks = {
+            let cache = self.cache.read().map_err(|err| err.to_string())?;
+            cache.as_ref().and_then(|cached| {
+                let age = cached.fetched_at.elapsed();
+                let expired = cached.lifetime.is_some_and(|lifetime| age > lifetime);
+                (!expired && (!refresh || age < MINIMUM_REFRESH_INTERVAL))
+                    .then(|| Arc::clone(&cached.jwks))
+            })
+        };
+        if let Some(jwks) = fresh_jwks {
+            return Ok(jwks);
+        }
+
+        let (jwks, lifetime) = match &self.key_source {
+            KeySource::File(path) => (
+                Jwks::from_json(&std::fs::read_to_string(path).map_err(|err| {
+                    format!("Could not read JWKS from '{}': '{}'", path.display(), err)
+                })?)?,
+                None,
+            ),
+            KeySource::Url(url) => {
+                let response = reqwest::get(url).await.map_err(|err| err.to_string())?;
+                let lifetime = response
+                    .headers()
+                    .get(reqwest::header::CACHE_CONTROL)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(max_age)
+                    .unwrap_or(DEFAULT_KEY_LIFETIME);
+                let body = response.text().await.map_err(|err| err.to_string())?;
+                (Jwks::from_json(&body)?, Some(lifetime))
+            }
+        };
+
+        let jwks = Arc::new(jwks);
+        *self.cache.write().map_err(|err| err.to_string())? = Some(CachedJwks {
+            jwks: Arc::clone(&jwks),
+            fetched_at: Instant::now(),
+            lifetime,
+        });
+        Ok(jwks)
+    }
+}
+
+fn max_age(cache_control: &str) -> Option<Duration> {
+    cache_control
+        .split(',')
+        .find_map(|directive| directive.trim().strip_prefix("max-age="))
+        .and_then(|seconds| seconds.parse().ok())
+        .map(Duration::from_secs)
+}
+
+fn validate_claims(claims: &Claims, project_id: &str, now: u64) -> Result<(), String> {
+    if claims.exp + CLOCK_SKEW_SECONDS <= now {
+        return Err("Token has expired".to_string());
+    }
+    if claims.iat > now + CLOCK_SKEW_SECONDS {
+        return Err("Token was issued in the future".to_string());
+    }
+    if claims.aud != project_id {
+        return Err(format!("Token is for another project '{}'", claims.aud));
+    }
+    if claims.iss != format!("https://securetoken.google.com/{}", project_id) {
+        return Err(format!("Token was issued by '{}'", claims.iss));
+    }
+    if claims.sub.is_empty() {
+        return Err("Token has no subject".to_string());
+    }
+    Ok(())
+}
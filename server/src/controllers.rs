@@ -1,4 +1,10 @@
 pub mod a1_2010_report;
+pub mod a2_2010_report;
 pub mod fan_series;
 pub mod fan_size;
+pub mod import;
+pub mod me;
+pub mod search;
+pub mod selections;
 pub mod sessions;
+pub mod users;
@@ -1,116 +1,480 @@
-use axum::{extract::Path, Extension, Json};
-use serde::Serialize;
+use axum::{
+    extract::{Path, Query},
+    http::header,
+    response::IntoResponse,
+    Extension, Json,
+};
+use serde::{Deserialize, Serialize};
 use serde_json::value::Serializer;
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, Transaction};
 
 use loquat_common::{
-    api::a1_2010_report::{GetResponse, UpdateBody},
+    api::{
+        a1_2010_report::{
+            CurveFamilyQuery, CurveFamilyResponse, GetResponse, IndexResponse, PdfQuery, Revision,
+            RevisionAction, RevisionDiffQuery, RevisionDiffResponse, RevisionsResponse, UpdateBody,
+        },
+        ApiError, ListQuery, Page,
+    },
+    calculations::{
+        standards::{A1OperatingPoint, CanFindA1OperatingPoint},
+        units::{BrakeHorsepower, FanDiameter, FanSpeed},
+    },
     models::{
         A1Standard2010Determination, A1Standard2010Parameters, A1Standard2010Report, FanSeries,
-        FanSize,
+        FanSize, FanType, User,
     },
 };
 
+use crate::{
+    controllers::fan_size,
+    error::{internal_error, ErrorResponse},
+    pdf::{a1_report_pdf, RatedOperatingPoint},
+};
+
+pub async fn index(
+    Query(query): Query<ListQuery>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<IndexResponse>, ErrorResponse> {
+    query.validate()?;
+    let (search, fan_type) = (
+        query.search(),
+        query.fan_type.as_ref().map(FanType::to_string),
+    );
+    let items = sqlx::query!(
+        "SELECT a1_2010_report_id, a1_2010_reports.fan_size_id, fan_sizes.fan_series_id, diameter, outlet_area, rpm, air_density, determinations
+           FROM a1_2010_reports
+           JOIN fan_sizes ON a1_2010_reports.fan_size_id = fan_sizes.fan_size_id
+           JOIN fan_serieses ON fan_sizes.fan_series_id = fan_serieses.fan_series_id
+           WHERE ($1::text IS NULL OR strpos(lower(a1_2010_report_id), lower($1)) > 0)
+             AND ($2::text IS NULL OR fan_type = $2)
+             AND ($3::text IS NULL OR fan_sizes.fan_series_id = $3)
+             AND ($4::text IS NULL OR a1_2010_reports.fan_size_id = $4)
+             AND ($5::float8 IS NULL OR rpm >= $5)
+             AND ($6::float8 IS NULL OR rpm <= $6)
+           ORDER BY a1_2010_report_id
+           LIMIT $7 OFFSET $8",
+        search,
+        fan_type,
+        query.fan_series_id,
+        query.fan_size_id,
+        query.min_rpm,
+        query.max_rpm,
+        query.limit(),
+        query.offset()
+    )
+    .fetch_all(&pool)
+    .await?
+    .into_iter()
+    .map(|record| {
+        let report = A1Standard2010Report {
+            id: record.a1_2010_report_id,
+            fan_size_id: record.fan_size_id.clone(),
+            fan_size: FanSize {
+                id: record.fan_size_id,
+                fan_series_id: record.fan_series_id,
+                fan_series: (),
+                diameter: record.diameter,
+                outlet_area: record.outlet_area,
+            },
+            parameters: A1Standard2010Parameters {
+                rpm: record.rpm,
+                air_density: record.air_density,
+            },
+            determinations: Vec::<A1Standard2010Determination>::deserialize(
+                record.determinations,
+            )
+            .map_err(|err| internal_error(format!("Could not parse determinations: '{}'", err)))?,
+        };
+        Ok(report.into())
+    })
+    .collect::<Result<_, ErrorResponse>>()?;
+    let total = sqlx::query!(
+        r#"SELECT COUNT(*) AS "total!"
+             FROM a1_2010_reports
+             JOIN fan_sizes ON a1_2010_reports.fan_size_id = fan_sizes.fan_size_id
+             JOIN fan_serieses ON fan_sizes.fan_series_id = fan_serieses.fan_series_id
+             WHERE ($1::text IS NULL OR strpos(lower(a1_2010_report_id), lower($1)) > 0)
+               AND ($2::text IS NULL OR fan_type = $2)
+               AND ($3::text IS NULL OR fan_sizes.fan_series_id = $3)
+               AND ($4::text IS NULL OR a1_2010_reports.fan_size_id = $4)
+               AND ($5::float8 IS NULL OR rpm >= $5)
+               AND ($6::float8 IS NULL OR rpm <= $6)"#,
+        search,
+        fan_type,
+        query.fan_series_id,
+        query.fan_size_id,
+        query.min_rpm,
+        query.max_rpm
+    )
+    .fetch_one(&pool)
+    .await?
+    .total;
+    Ok(Json(Page {
+        items,
+        offset: query.offset(),
+        limit: query.limit(),
+        total,
+    }))
+}
+
 pub async fn get(
     Path(id): Path<String>,
     Extension(pool): Extension<PgPool>,
-) -> Result<Json<GetResponse>, String> {
-    let report = sqlx::query!(
-      "SELECT a1_2010_report_id, fan_sizes.fan_size_id, fan_sizes.fan_series_id, fan_type, diameter, outlet_area, rpm, determinations
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    Ok(Json(fetch_report(&id, &pool).await?.into()))
+}
+
+pub async fn curve_family(
+    Path(id): Path<String>,
+    Query(query): Query<CurveFamilyQuery>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<CurveFamilyResponse>, ErrorResponse> {
+    let report = fetch_report(&id, &pool).await?;
+    let fan_speeds = query.fan_speeds(report.parameters.rpm)?;
+    let fan_diameter = FanDiameter::from_inches(report.fan_size.diameter);
+    Ok(Json(
+        report
+            .a1_curve_family_for(&fan_diameter, &fan_speeds)?
+            .into(),
+    ))
+}
+
+// A printable sheet for the report, or for an operating point rated from it
+pub async fn pdf(
+    Path(id): Path<String>,
+    Query(query): Query<PdfQuery>,
+    Extension(pool): Extension<PgPool>,
+    Extension(user): Extension<User>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let report = fetch_report(&id, &pool).await?;
+    let rated = match query.operating_point()? {
+        Some((inlet_airflow, static_pressure)) => {
+            let fan_size = match &query.fan_size_id {
+                Some(fan_size_id) => {
+                    let Json(fan_size) =
+                        fan_size::get(Path(fan_size_id.clone()), Extension(pool.clone())).await?;
+                    if fan_size.fan_series_id != report.fan_size.fan_series_id {
+                        return Err(ApiError::validation("Invalid operating point")
+                            .with_field_error(
+                                "fan_size_id",
+                                format!(
+                                    "Must be from Fan Series '{}'",
+                                    report.fan_size.fan_series_id
+                                ),
+                            )
+                            .into());
+                    }
+                    fan_size
+                }
+                None => report.fan_size.clone(),
+            };
+            let rating = report.clone().a1_operating_point_for(
+                &FanDiameter::from_inches(fan_size.diameter),
+                &inlet_airflow,
+                &static_pressure,
+                query.fit.unwrap_or_default(),
+            )?;
+            let fan_speed: &FanSpeed = rating.as_ref();
+            let brake_horsepower: &BrakeHorsepower = rating.as_ref();
+            Some(RatedOperatingPoint {
+                operating_point: A1OperatingPoint::new(
+                    *fan_speed,
+                    inlet_airflow,
+                    static_pressure,
+                    *brake_horsepower,
+                ),
+                fan_size,
+            })
+        }
+        None => None,
+    };
+    let body = a1_report_pdf(&report, rated.as_ref(), user.unit_system)?;
+
+    // Ids are free text, so only the safe characters make it into the file name
+    let file_name: String = report
+        .id
+        .chars()
+        .map(|c| match c {
+            'A'..='Z' | 'a'..='z' | '0'..='9' | '.' | '-' | '_' => c,
+            _ => '_',
+        })
+        .collect();
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/pdf".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("inline; filename=\"{}.pdf\"", file_name),
+            ),
+        ],
+        body,
+    ))
+}
+
+pub async fn fetch_report(
+    id: &str,
+    pool: &PgPool,
+) -> Result<A1Standard2010Report<FanSize<FanSeries<()>>>, ErrorResponse> {
+    let record = sqlx::query!(
+      "SELECT a1_2010_report_id, fan_sizes.fan_size_id, fan_sizes.fan_series_id, fan_type, diameter, outlet_area, rpm, air_density, determinations
            FROM a1_2010_reports
            JOIN fan_sizes ON a1_2010_reports.fan_size_id = fan_sizes.fan_size_id
            JOIN fan_serieses ON fan_sizes.fan_series_id = fan_serieses.fan_series_id
            WHERE a1_2010_reports.a1_2010_report_id = $1",
       id
-  )
-  .fetch_one(&pool)
-  .await
-  .map_err(|err| err.to_string())
-  .map(|record|
-     A1Standard2010Report {
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| ApiError::not_found(format!("Could not find A1 report '{}'", id)))?;
+
+    Ok(A1Standard2010Report {
         id: record.a1_2010_report_id,
         fan_size_id: record.fan_size_id.clone(),
         fan_size: FanSize {
-          id: record.fan_size_id,
-          fan_series_id: record.fan_series_id.clone(),
-          fan_series: FanSeries {
-              id: record.fan_series_id,
-              fan_type: record.fan_type[..]
-                  .try_into()
-                  .map_err(|err| format!("Could not parse fan type: '{:?}'", err))
-                  .expect("Could not parse fan type from DB"),
-              fan_sizes: (),
-          },
-          diameter: record.diameter,
-          outlet_area: record.outlet_area,
-      },
+            id: record.fan_size_id,
+            fan_series_id: record.fan_series_id.clone(),
+            fan_series: FanSeries {
+                id: record.fan_series_id,
+                fan_type: record.fan_type[..].try_into().map_err(|err| {
+                    internal_error(format!("Could not parse fan type: '{:?}'", err))
+                })?,
+                fan_sizes: (),
+            },
+            diameter: record.diameter,
+            outlet_area: record.outlet_area,
+        },
         parameters: A1Standard2010Parameters {
-          rpm: record.rpm,
+            rpm: record.rpm,
+            air_density: record.air_density,
         },
-        determinations: record.determinations.as_array().expect("Determinations JSONB was not an array!").iter().map(|el| { let obj = el.as_object().expect("Element of determinants was not an object!"); A1Standard2010Determination{
-            cfm: obj.get("cfm").and_then(|num| num.as_f64()).expect("Object has no CFM"),
-            static_pressure: obj.get("static_pressure").and_then(|num| num.as_f64()).expect("Object has no static_pressure"),
-            brake_horsepower: obj.get("brake_horsepower").and_then(|num| num.as_f64()).expect("Object has no brake_horsepower"),
-        } }).collect()
-    })?;
-
-    Ok(Json(report))
+        determinations: Vec::<A1Standard2010Determination>::deserialize(record.determinations)
+            .map_err(|err| internal_error(format!("Could not parse determinations: '{}'", err)))?,
+    })
 }
 
 pub async fn post(
     Extension(pool): Extension<PgPool>,
-    Json(UpdateBody {
+    Extension(user): Extension<User>,
+    Json(update_body): Json<UpdateBody>,
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    update_body.validate_in(user.unit_system)?;
+    let update_body = update_body.to_imperial();
+    let UpdateBody {
         id,
         fan_rpm,
         fan_size_id,
+        air_density,
         determinations,
-    }): Json<UpdateBody>,
-) -> Result<Json<GetResponse>, String> {
+        ..
+    } = update_body.clone();
+    let mut tx = pool.begin().await?;
     let record = sqlx::query!(
         "
-    INSERT INTO a1_2010_reports (a1_2010_report_id, fan_size_id,rpm, determinations) VALUES
-      ($1,$2,$3,$4) ON CONFLICT DO NOTHING RETURNING a1_2010_report_id;;",
+    INSERT INTO a1_2010_reports (a1_2010_report_id, fan_size_id,rpm, air_density, determinations) VALUES
+      ($1,$2,$3,$4,$5) ON CONFLICT DO NOTHING RETURNING a1_2010_report_id;;",
         id,
         fan_size_id,
         fan_rpm,
-        determinations
-            .serialize(Serializer)
-            .map_err(|e| e.to_string())?
+        air_density,
+        determinations.serialize(Serializer)?
     )
-    .fetch_one(&pool)
-    .await
-    .map_err(|e| e.to_string())?;
+    .fetch_optional(&mut tx)
+    .await?
+    .ok_or_else(|| ApiError::conflict(format!("A1 report '{}' already exists", id)))?;
+    insert_revision(&mut tx, &user, RevisionAction::Create, None, &update_body).await?;
+    tx.commit().await?;
     get(Path(record.a1_2010_report_id), Extension(pool)).await
 }
 
 pub async fn put(
     Path(id): Path<String>,
     Extension(pool): Extension<PgPool>,
-    Json(UpdateBody {
+    Extension(user): Extension<User>,
+    Json(update_body): Json<UpdateBody>,
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    update_body.validate_in(user.unit_system)?;
+    let update_body = update_body.to_imperial();
+    let mut tx = pool.begin().await?;
+    update_report(&mut tx, &id, &update_body).await?;
+    insert_revision(&mut tx, &user, RevisionAction::Update, None, &update_body).await?;
+    tx.commit().await?;
+    get(Path(update_body.id), Extension(pool)).await
+}
+
+pub async fn revisions(
+    Path(id): Path<String>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<RevisionsResponse>, ErrorResponse> {
+    Ok(Json(fetch_revisions(&id, &pool).await?))
+}
+
+pub async fn revision_diff(
+    Path(id): Path<String>,
+    Query(RevisionDiffQuery { from, to }): Query<RevisionDiffQuery>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<RevisionDiffResponse>, ErrorResponse> {
+    let revisions = fetch_revisions(&id, &pool).await?;
+    let find_revision = |revision_id: i64| {
+        revisions
+            .iter()
+            .find(|revision| revision.revision_id == revision_id)
+            .cloned()
+            .ok_or_else(|| {
+                ApiError::not_found(format!(
+                    "Could not find revision {} of '{}'",
+                    revision_id, id
+                ))
+            })
+    };
+    Ok(Json((find_revision(from)?, find_revision(to)?).into()))
+}
+
+// Saves an earlier revision as the report's current state, keeping its current id.
+// Revisions aren't validated again, so reports saved before validation can still be restored
+pub async fn restore_revision(
+    Path((id, revision_id)): Path<(String, i64)>,
+    Extension(pool): Extension<PgPool>,
+    Extension(user): Extension<User>,
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    let revision = fetch_revisions(&id, &pool)
+        .await?
+        .into_iter()
+        .find(|revision| revision.revision_id == revision_id)
+        .ok_or_else(|| {
+            ApiError::not_found(format!(
+                "Could not find revision {} of '{}'",
+                revision_id, id
+            ))
+        })?;
+    let update_body = UpdateBody {
+        id: id.clone(),
+        ..revision.snapshot
+    };
+
+    let mut tx = pool.begin().await?;
+    update_report(&mut tx, &id, &update_body).await?;
+    insert_revision(
+        &mut tx,
+        &user,
+        RevisionAction::Restore,
+        Some(revision_id),
+        &update_body,
+    )
+    .await?;
+    tx.commit().await?;
+    get(Path(id), Extension(pool)).await
+}
+
+async fn update_report(
+    tx: &mut Transaction<'_, Postgres>,
+    id: &str,
+    UpdateBody {
         id: new_id,
         fan_rpm,
         fan_size_id,
+        air_density,
         determinations,
-    }): Json<UpdateBody>,
-) -> Result<Json<GetResponse>, String> {
+        ..
+    }: &UpdateBody,
+) -> Result<(), ErrorResponse> {
+    // Reports created before revisions were tracked get their original contents saved first
     sqlx::query!(
         "
+      INSERT INTO a1_2010_report_revisions (a1_2010_report_id, action, snapshot)
+        SELECT a1_2010_report_id, 'create', jsonb_build_object(
+          'id', a1_2010_report_id,
+          'fan_rpm', rpm,
+          'fan_size_id', fan_size_id,
+          'air_density', air_density,
+          'determinations', determinations
+        ) FROM a1_2010_reports
+        WHERE a1_2010_report_id = $1 AND NOT EXISTS (
+          SELECT 1 FROM a1_2010_report_revisions WHERE a1_2010_report_id = $1
+        )",
+        id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let result = sqlx::query!(
+        "
       UPDATE a1_2010_reports SET
         a1_2010_report_id = $1,
         fan_size_id = $2, 
         rpm = $3,
-        determinations = $4 
-        WHERE a1_2010_report_id = $5",
+        air_density = $4,
+        determinations = $5 
+        WHERE a1_2010_report_id = $6",
         new_id,
         fan_size_id,
-        fan_rpm,
-        determinations
-            .serialize(Serializer)
-            .map_err(|e| e.to_string())?,
+        *fan_rpm,
+        *air_density,
+        determinations.serialize(Serializer)?,
         id,
     )
-    .execute(&pool)
-    .await
-    .map_err(|e| e.to_string())?;
-    get(Path(id), Extension(pool)).await
+    .execute(&mut *tx)
+    .await?;
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found(format!("Could not find A1 report '{}'", id)).into());
+    }
+    Ok(())
+}
+
+async fn insert_revision(
+    tx: &mut Transaction<'_, Postgres>,
+    user: &User,
+    action: RevisionAction,
+    restored_from_revision_id: Option<i64>,
+    update_body: &UpdateBody,
+) -> Result<(), ErrorResponse> {
+    sqlx::query!(
+        "INSERT INTO a1_2010_report_revisions
+           (a1_2010_report_id, user_id, action, restored_from_revision_id, snapshot)
+           VALUES ($1, $2, $3, $4, $5)",
+        update_body.id,
+        user.id,
+        action.as_str(),
+        restored_from_revision_id,
+        update_body.serialize(Serializer)?
+    )
+    .execute(&mut *tx)
+    .await?;
+    Ok(())
+}
+
+async fn fetch_revisions(id: &str, pool: &PgPool) -> Result<Vec<Revision>, ErrorResponse> {
+    sqlx::query!(
+        r#"SELECT revision_id, a1_2010_report_id, a1_2010_report_revisions.user_id, users.name AS "user_name?",
+             action, restored_from_revision_id, snapshot,
+             to_char(a1_2010_report_revisions.created_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS "created_at!"
+           FROM a1_2010_report_revisions
+           LEFT JOIN users ON a1_2010_report_revisions.user_id = users.user_id
+           WHERE a1_2010_report_id = $1
+           ORDER BY revision_id DESC"#,
+        id
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|record| {
+        Ok(Revision {
+            revision_id: record.revision_id,
+            report_id: record.a1_2010_report_id,
+            user_id: record.user_id,
+            user_name: record.user_name,
+            action: record.action[..]
+                .try_into()
+                .map_err(|err| {
+                    internal_error(format!("Could not parse revision action: '{:?}'", err))
+                })?,
+            restored_from_revision_id: record.restored_from_revision_id,
+            created_at: record.created_at,
+            snapshot: serde_json::from_value(record.snapshot)
+                .map_err(|err| {
+                    internal_error(format!("Could not parse revision snapshot: '{}'", err))
+                })?,
+        })
+    })
+    .collect()
 }
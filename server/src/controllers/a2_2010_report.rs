@@ -0,0 +1,152 @@
+use axum::{extract::Path, Extension, Json};
+use serde::{Deserialize, Serialize};
+use serde_json::value::Serializer;
+use sqlx::PgPool;
+
+use loquat_common::{
+    api::{
+        a2_2010_report::{GetResponse, UpdateBody},
+        ApiError,
+    },
+    models::{
+        A2Standard2010Determination, A2Standard2010Parameters, A2Standard2010Report, FanSeries,
+        FanSize, InducedFlowFanSize, Nozzle,
+    },
+};
+
+use super::a1_2010_report;
+use crate::error::{internal_error, ErrorResponse};
+
+pub async fn get(
+    Path(id): Path<String>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    let record = sqlx::query!(
+        "SELECT a2_2010_report_id, a1_2010_report_id, induced_flow_fan_size_id, rpm, determinations
+             FROM a2_2010_reports
+             WHERE a2_2010_report_id = $1",
+        id
+    )
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(|| ApiError::not_found(format!("Could not find A2 report '{}'", id)))?;
+
+    let Json(loquat_common::api::a1_2010_report::GetResponse {
+        report: a1_report, ..
+    }) = a1_2010_report::get(
+        Path(record.a1_2010_report_id.clone()),
+        Extension(pool.clone()),
+    )
+    .await?;
+
+    let induced_flow_fan_size = sqlx::query!(
+        "SELECT induced_flow_fan_size_id, nozzle_id, fan_sizes.fan_size_id, fan_sizes.fan_series_id, fan_type, diameter, outlet_area
+             FROM induced_flow_fan_sizes
+             JOIN fan_sizes ON induced_flow_fan_sizes.fan_size_id = fan_sizes.fan_size_id
+             JOIN fan_serieses ON fan_sizes.fan_series_id = fan_serieses.fan_series_id
+             WHERE induced_flow_fan_size_id = $1",
+        record.induced_flow_fan_size_id
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(ErrorResponse::from)
+    .and_then(|record| {
+        Ok(InducedFlowFanSize {
+            id: record.induced_flow_fan_size_id,
+            fan_size_id: record.fan_size_id.clone(),
+            fan_size: FanSize {
+                id: record.fan_size_id,
+                fan_series_id: record.fan_series_id.clone(),
+                fan_series: FanSeries {
+                    id: record.fan_series_id,
+                    fan_type: record.fan_type[..]
+                        .try_into()
+                        .map_err(|err| {
+                            internal_error(format!("Could not parse fan type: '{:?}'", err))
+                        })?,
+                    fan_sizes: (),
+                },
+                diameter: record.diameter,
+                outlet_area: record.outlet_area,
+            },
+            nozzle_id: record.nozzle_id.clone(),
+            nozzle: Nozzle {
+                id: record.nozzle_id,
+            },
+        })
+    })?;
+
+    let determinations = Vec::<A2Standard2010Determination>::deserialize(record.determinations)
+        .map_err(|err| internal_error(format!("Could not parse determinations: '{}'", err)))?;
+
+    Ok(Json(A2Standard2010Report {
+        id: record.a2_2010_report_id,
+        a1_report_id: record.a1_2010_report_id,
+        a1_report,
+        induced_flow_fan_size_id: record.induced_flow_fan_size_id,
+        induced_flow_fan_size,
+        parameters: A2Standard2010Parameters { rpm: record.rpm },
+        determinations,
+    }))
+}
+
+pub async fn post(
+    Extension(pool): Extension<PgPool>,
+    Json(UpdateBody {
+        id,
+        fan_rpm,
+        a1_report_id,
+        induced_flow_fan_size_id,
+        determinations,
+    }): Json<UpdateBody>,
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    let record = sqlx::query!(
+        "
+    INSERT INTO a2_2010_reports (a2_2010_report_id, a1_2010_report_id, induced_flow_fan_size_id, rpm, determinations) VALUES
+      ($1,$2,$3,$4,$5) ON CONFLICT DO NOTHING RETURNING a2_2010_report_id;",
+        id,
+        a1_report_id,
+        induced_flow_fan_size_id,
+        fan_rpm,
+        determinations.serialize(Serializer)?
+    )
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(|| ApiError::conflict(format!("A2 report '{}' already exists", id)))?;
+    get(Path(record.a2_2010_report_id), Extension(pool)).await
+}
+
+pub async fn put(
+    Path(id): Path<String>,
+    Extension(pool): Extension<PgPool>,
+    Json(UpdateBody {
+        id: new_id,
+        fan_rpm,
+        a1_report_id,
+        induced_flow_fan_size_id,
+        determinations,
+    }): Json<UpdateBody>,
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    let result = sqlx::query!(
+        "
+      UPDATE a2_2010_reports SET
+        a2_2010_report_id = $1,
+        a1_2010_report_id = $2,
+        induced_flow_fan_size_id = $3,
+        rpm = $4,
+        determinations = $5
+        WHERE a2_2010_report_id = $6",
+        new_id,
+        a1_report_id,
+        induced_flow_fan_size_id,
+        fan_rpm,
+        determinations.serialize(Serializer)?,
+        id,
+    )
+    .execute(&pool)
+    .await?;
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found(format!("Could not find A2 report '{}'", id)).into());
+    }
+    get(Path(new_id), Extension(pool)).await
+}
@@ -1,39 +1,76 @@
-use axum::{extract::Path, Extension, Json};
+use axum::{
+    extract::{Path, Query},
+    Extension, Json,
+};
 use itertools::Itertools;
 use sqlx::PgPool;
 
 use loquat_common::{
-    api::fan_series::{GetResponse, IndexResponse},
+    api::{
+        fan_series::{GetResponse, IndexResponse, UpdateBody},
+        ApiError, ListQuery, Page,
+    },
     models::{FanSeries, FanSize, FanType},
 };
 
-pub async fn index(Extension(pool): Extension<PgPool>) -> Result<Json<IndexResponse>, String> {
-    sqlx::query!("SELECT fan_series_id, fan_type FROM fan_serieses LIMIT 50")
-        .fetch_all(&pool)
-        .await
-        .map_err(|err| err.to_string())
-        .map(|records| {
-            Json(
-                records
-                    .into_iter()
-                    .map(|record| FanSeries {
-                        id: record.fan_series_id,
-                        fan_type: record.fan_type[..].try_into().unwrap(),
-                        fan_sizes: (),
-                    })
-                    .collect(),
-            )
+use crate::error::{internal_error, ErrorResponse};
+
+pub async fn index(
+    Query(query): Query<ListQuery>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<IndexResponse>, ErrorResponse> {
+    query.validate()?;
+    let (search, fan_type) = (
+        query.search(),
+        query.fan_type.as_ref().map(FanType::to_string),
+    );
+    let items = sqlx::query!(
+        "SELECT fan_series_id, fan_type FROM fan_serieses
+           WHERE ($1::text IS NULL OR strpos(lower(fan_series_id), lower($1)) > 0)
+             AND ($2::text IS NULL OR fan_type = $2)
+           ORDER BY fan_series_id
+           LIMIT $3 OFFSET $4",
+        search,
+        fan_type,
+        query.limit(),
+        query.offset()
+    )
+    .fetch_all(&pool)
+    .await?
+    .into_iter()
+    .map(|record| {
+        Ok(FanSeries {
+            id: record.fan_series_id,
+            fan_type: parse_fan_type(&record.fan_type)?,
+            fan_sizes: (),
         })
+    })
+    .collect::<Result<_, ErrorResponse>>()?;
+    let total = sqlx::query!(
+        r#"SELECT COUNT(*) AS "total!" FROM fan_serieses
+             WHERE ($1::text IS NULL OR strpos(lower(fan_series_id), lower($1)) > 0)
+               AND ($2::text IS NULL OR fan_type = $2)"#,
+        search,
+        fan_type
+    )
+    .fetch_one(&pool)
+    .await?
+    .total;
+    Ok(Json(Page {
+        items,
+        offset: query.offset(),
+        limit: query.limit(),
+        total,
+    }))
 }
 
 pub async fn get(
     Path(id): Path<String>,
     Extension(pool): Extension<PgPool>,
-) -> Result<Json<GetResponse>, String> {
+) -> Result<Json<GetResponse>, ErrorResponse> {
     let rows = sqlx::query_file!("src/queries/fan_series/get_fan_series.sql", id.clone())
         .fetch_all(&pool)
-        .await
-        .map_err(|err| err.to_string())?;
+        .await?;
 
     let groups = rows
         .into_iter()
@@ -44,25 +81,94 @@ pub async fn get(
         .find(|((fan_series_id, _), _)| fan_series_id == &id);
 
     if let Some(((fan_series_id, fan_type), rows)) = fan_series_group {
+        // A series with no sizes yet comes back as one row with null size columns
         let fan_sizes: Vec<FanSize<()>> = rows
             .into_iter()
-            .map(|row| FanSize {
-                id: row.fan_size_id,
-                fan_series_id: row.fan_series_id,
-                fan_series: (),
-                diameter: row.diameter,
-                outlet_area: row.outlet_area,
+            .filter_map(|row| {
+                Some(FanSize {
+                    id: row.fan_size_id?,
+                    fan_series_id: row.fan_series_id,
+                    fan_series: (),
+                    diameter: row.diameter?,
+                    outlet_area: row.outlet_area?,
+                })
             })
             .collect();
-        let fan_type: FanType = fan_type[..]
-            .try_into()
-            .map_err(|_| "Could not parase FanType".to_string())?;
         Ok(Json(FanSeries {
             id: fan_series_id,
-            fan_type,
+            fan_type: parse_fan_type(&fan_type)?,
             fan_sizes,
         }))
     } else {
-        Err("Could not find Fan Series".to_string())
+        Err(ApiError::not_found(format!("Could not find Fan Series '{}'", id)).into())
+    }
+}
+
+pub async fn post(
+    Extension(pool): Extension<PgPool>,
+    Json(UpdateBody { id, fan_type }): Json<UpdateBody>,
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    let record = sqlx::query!(
+        "INSERT INTO fan_serieses (fan_series_id, fan_type) VALUES ($1, $2)
+           ON CONFLICT DO NOTHING RETURNING fan_series_id",
+        id,
+        fan_type.to_string()
+    )
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(|| ApiError::conflict(format!("Fan Series '{}' already exists", id)))?;
+    get(Path(record.fan_series_id), Extension(pool)).await
+}
+
+// Sizes and reports refer to the series by id, so it can't be renamed
+pub async fn put(
+    Path(id): Path<String>,
+    Extension(pool): Extension<PgPool>,
+    Json(UpdateBody {
+        id: new_id,
+        fan_type,
+    }): Json<UpdateBody>,
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    if new_id != id {
+        return Err(ApiError::validation("Fan Series can't be renamed")
+            .with_field_error("id", format!("Must stay '{}'", id))
+            .into());
+    }
+    let result = sqlx::query!(
+        "UPDATE fan_serieses SET fan_type = $1 WHERE fan_series_id = $2",
+        fan_type.to_string(),
+        id
+    )
+    .execute(&pool)
+    .await?;
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found(format!("Could not find Fan Series '{}'", id)).into());
+    }
+    get(Path(id), Extension(pool)).await
+}
+
+// Refuses while the series has sizes, since they'd take their test reports with them
+pub async fn delete(
+    Path(id): Path<String>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    let Json(fan_series) = get(Path(id.clone()), Extension(pool.clone())).await?;
+    if !fan_series.fan_sizes.is_empty() {
+        return Err(ApiError::conflict(format!(
+            "Fan Series '{}' still has {} fan size(s), delete them first",
+            id,
+            fan_series.fan_sizes.len()
+        ))
+        .into());
     }
+    sqlx::query!("DELETE FROM fan_serieses WHERE fan_series_id = $1", id)
+        .execute(&pool)
+        .await?;
+    Ok(Json(fan_series))
+}
+
+fn parse_fan_type(fan_type: &str) -> Result<FanType, ErrorResponse> {
+    fan_type
+        .try_into()
+        .map_err(|err| internal_error(format!("Could not parse fan type: '{:?}'", err)))
 }
@@ -1,59 +1,278 @@
-use axum::{extract::Path, Extension, Json};
+use axum::{
+    extract::{Path, Query},
+    Extension, Json,
+};
 use sqlx::PgPool;
 
 use loquat_common::{
-    api::fan_size::{GetResponse, IndexResponse},
-    models::{FanSeries, FanSize},
+    api::{
+        fan_size::{GetResponse, IndexResponse, UpdateBody},
+        rating_table::{RatingTableQuery, RatingTableResponse},
+        ApiError, ListQuery, Page,
+    },
+    calculations::{
+        rating_table::{rating_axes, rating_table as build_rating_table},
+        units::{FanDiameter, FanSpeed},
+    },
+    models::{FanSeries, FanSize, FanType},
+};
+
+use crate::{
+    controllers::a1_2010_report::fetch_report,
+    error::{internal_error, ErrorResponse},
 };
 
-pub async fn index(Extension(pool): Extension<PgPool>) -> Result<Json<IndexResponse>, String> {
-    let fan_sizes = sqlx::query!("SELECT * FROM fan_sizes LIMIT 50")
-        .fetch_all(&pool)
-        .await
-        .map_err(|err| err.to_string())
-        .map(|records| {
-            records
-                .into_iter()
-                .map(|record| FanSize {
-                    id: record.fan_size_id,
-                    fan_series_id: record.fan_series_id,
-                    fan_series: (),
-                    diameter: record.diameter,
-                    outlet_area: record.outlet_area,
-                })
-                .collect()
-        })?;
-    Ok(Json(fan_sizes))
+pub async fn index(
+    Query(query): Query<ListQuery>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<IndexResponse>, ErrorResponse> {
+    query.validate()?;
+    let (search, fan_type) = (
+        query.search(),
+        query.fan_type.as_ref().map(FanType::to_string),
+    );
+    let items = sqlx::query!(
+        "SELECT fan_sizes.fan_size_id, fan_sizes.fan_series_id, diameter, outlet_area
+           FROM fan_sizes
+           JOIN fan_serieses ON fan_sizes.fan_series_id = fan_serieses.fan_series_id
+           WHERE ($1::text IS NULL OR strpos(lower(fan_size_id), lower($1)) > 0)
+             AND ($2::text IS NULL OR fan_type = $2)
+             AND ($3::text IS NULL OR fan_sizes.fan_series_id = $3)
+             AND ($4::float8 IS NULL OR diameter >= $4)
+             AND ($5::float8 IS NULL OR diameter <= $5)
+           ORDER BY fan_sizes.fan_size_id
+           LIMIT $6 OFFSET $7",
+        search,
+        fan_type,
+        query.fan_series_id,
+        query.min_diameter,
+        query.max_diameter,
+        query.limit(),
+        query.offset()
+    )
+    .fetch_all(&pool)
+    .await?
+    .into_iter()
+    .map(|record| FanSize {
+        id: record.fan_size_id,
+        fan_series_id: record.fan_series_id,
+        fan_series: (),
+        diameter: record.diameter,
+        outlet_area: record.outlet_area,
+    })
+    .collect();
+    let total = sqlx::query!(
+        r#"SELECT COUNT(*) AS "total!"
+             FROM fan_sizes
+             JOIN fan_serieses ON fan_sizes.fan_series_id = fan_serieses.fan_series_id
+             WHERE ($1::text IS NULL OR strpos(lower(fan_size_id), lower($1)) > 0)
+               AND ($2::text IS NULL OR fan_type = $2)
+               AND ($3::text IS NULL OR fan_sizes.fan_series_id = $3)
+               AND ($4::float8 IS NULL OR diameter >= $4)
+               AND ($5::float8 IS NULL OR diameter <= $5)"#,
+        search,
+        fan_type,
+        query.fan_series_id,
+        query.min_diameter,
+        query.max_diameter
+    )
+    .fetch_one(&pool)
+    .await?
+    .total;
+    Ok(Json(Page {
+        items,
+        offset: query.offset(),
+        limit: query.limit(),
+        total,
+    }))
 }
 
 pub async fn get(
     Path(id): Path<String>,
     Extension(pool): Extension<PgPool>,
-) -> Result<Json<GetResponse>, String> {
-    let fan_size = sqlx::query!(
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    let record = sqlx::query!(
         "SELECT fan_sizes.fan_size_id, fan_sizes.fan_series_id, fan_type, diameter, outlet_area
              FROM fan_sizes
              JOIN fan_serieses ON fan_sizes.fan_series_id = fan_serieses.fan_series_id 
              WHERE fan_size_id = $1",
         id
     )
-    .fetch_one(&pool)
-    .await
-    .map_err(|err| err.to_string())
-    .map(|record| FanSize {
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(|| ApiError::not_found(format!("Could not find Fan Size '{}'", id)))?;
+
+    Ok(Json(FanSize {
         id: record.fan_size_id,
         fan_series_id: record.fan_series_id.clone(),
         fan_series: FanSeries {
             id: record.fan_series_id,
             fan_type: record.fan_type[..]
                 .try_into()
-                .map_err(|err| format!("Could not parse fan type: '{:?}'", err))
-                .unwrap(),
+                .map_err(|err| internal_error(format!("Could not parse fan type: '{:?}'", err)))?,
             fan_sizes: (),
         },
         diameter: record.diameter,
         outlet_area: record.outlet_area,
-    })?;
+    }))
+}
+
+// Rates the size from an A1 report in its series, for catalogue submittals
+pub async fn rating_table(
+    Path(id): Path<String>,
+    Query(query): Query<RatingTableQuery>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<RatingTableResponse>, ErrorResponse> {
+    query.validate()?;
+    let Json(fan_size) = get(Path(id.clone()), Extension(pool.clone())).await?;
+
+    let report_id = match &query.a1_report_id {
+        Some(report_id) => report_id.clone(),
+        None => sqlx::query!(
+            "SELECT a1_2010_report_id FROM a1_2010_reports
+               JOIN fan_sizes ON a1_2010_reports.fan_size_id = fan_sizes.fan_size_id
+               WHERE fan_sizes.fan_series_id = $1
+               ORDER BY a1_2010_reports.fan_size_id = $2 DESC, a1_2010_report_id
+               LIMIT 1",
+            fan_size.fan_series_id,
+            fan_size.id
+        )
+        .fetch_optional(&pool)
+        .await?
+        .map(|record| record.a1_2010_report_id)
+        .ok_or_else(|| {
+            ApiError::not_found(format!(
+                "Fan Series '{}' has no A1 reports to rate from",
+                fan_size.fan_series_id
+            ))
+        })?,
+    };
+    let report = fetch_report(&report_id, &pool).await?;
+    if report.fan_size.fan_series_id != fan_size.fan_series_id {
+        return Err(ApiError::validation("Invalid rating table query")
+            .with_field_error(
+                "a1_report_id",
+                format!("Must be from Fan Series '{}'", fan_size.fan_series_id),
+            )
+            .into());
+    }
 
+    let fan_diameter = FanDiameter::from_inches(fan_size.diameter);
+    let axis_fan_speed = FanSpeed::from_rpm(query.max_rpm.unwrap_or(report.parameters.rpm));
+    let (airflows, static_pressures) = rating_axes(
+        &report,
+        &fan_diameter,
+        &axis_fan_speed,
+        query.rows(),
+        query.columns(),
+    )?;
+    let table = build_rating_table(
+        &report,
+        &fan_diameter,
+        &airflows,
+        &static_pressures,
+        &query.limits(),
+        query.fit.unwrap_or_default(),
+    )?;
+    Ok(Json(RatingTableResponse::new(
+        fan_size, report.id, &query, table,
+    )))
+}
+
+pub async fn post(
+    Extension(pool): Extension<PgPool>,
+    Json(UpdateBody {
+        id,
+        fan_series_id,
+        diameter,
+        outlet_area,
+    }): Json<UpdateBody>,
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    validate_dimensions(diameter, outlet_area)?;
+    let record = sqlx::query!(
+        "INSERT INTO fan_sizes (fan_size_id, fan_series_id, diameter, outlet_area) VALUES ($1, $2, $3, $4)
+           ON CONFLICT DO NOTHING RETURNING fan_size_id",
+        id,
+        fan_series_id,
+        diameter,
+        outlet_area
+    )
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(|| ApiError::conflict(format!("Fan Size '{}' already exists", id)))?;
+    get(Path(record.fan_size_id), Extension(pool)).await
+}
+
+// Test reports refer to the size by id, so it can't be renamed
+pub async fn put(
+    Path(id): Path<String>,
+    Extension(pool): Extension<PgPool>,
+    Json(UpdateBody {
+        id: new_id,
+        fan_series_id,
+        diameter,
+        outlet_area,
+    }): Json<UpdateBody>,
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    if new_id != id {
+        return Err(ApiError::validation("Fan Size can't be renamed")
+            .with_field_error("id", format!("Must stay '{}'", id))
+            .into());
+    }
+    validate_dimensions(diameter, outlet_area)?;
+    let result = sqlx::query!(
+        "UPDATE fan_sizes SET fan_series_id = $1, diameter = $2, outlet_area = $3 WHERE fan_size_id = $4",
+        fan_series_id,
+        diameter,
+        outlet_area,
+        id
+    )
+    .execute(&pool)
+    .await?;
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found(format!("Could not find Fan Size '{}'", id)).into());
+    }
+    get(Path(id), Extension(pool)).await
+}
+
+// Refuses while test reports use the size, rather than deleting lab data along with it
+pub async fn delete(
+    Path(id): Path<String>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    let Json(fan_size) = get(Path(id.clone()), Extension(pool.clone())).await?;
+    let dependents = sqlx::query!(
+        r#"SELECT
+             (SELECT COUNT(*) FROM a1_2010_reports WHERE fan_size_id = $1) AS "a1_reports!",
+             (SELECT COUNT(*) FROM induced_flow_fan_sizes WHERE fan_size_id = $1) AS "induced_flow_fan_sizes!""#,
+        id
+    )
+    .fetch_one(&pool)
+    .await?;
+    if dependents.a1_reports > 0 || dependents.induced_flow_fan_sizes > 0 {
+        return Err(ApiError::conflict(format!(
+            "Fan Size '{}' is used by {} A1 report(s) and {} induced flow fan size(s), delete them first",
+            id, dependents.a1_reports, dependents.induced_flow_fan_sizes
+        ))
+        .into());
+    }
+    sqlx::query!("DELETE FROM fan_sizes WHERE fan_size_id = $1", id)
+        .execute(&pool)
+        .await?;
     Ok(Json(fan_size))
 }
+
+fn validate_dimensions(diameter: f64, outlet_area: f64) -> Result<(), ApiError> {
+    let mut err = ApiError::validation("Invalid Fan Size dimensions");
+    if diameter <= 0.0 {
+        err = err.with_field_error("diameter", "Must be positive");
+    }
+    if outlet_area <= 0.0 {
+        err = err.with_field_error("outlet_area", "Must be positive");
+    }
+    if err.field_errors.is_empty() {
+        Ok(())
+    } else {
+        Err(err)
+    }
+}
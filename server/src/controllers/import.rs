@@ -0,0 +1,17 @@
+use axum::{extract::Query, Json};
+
+use loquat_common::{
+    api::import::{ImportQuery, ImportResponse},
+    import::import_determinations,
+};
+
+use crate::error::ErrorResponse;
+
+// Reads determinations out of an uploaded CSV, TSV or lab export without saving anything,
+// so they can be checked in the form first
+pub async fn a1_determinations(
+    Query(query): Query<ImportQuery>,
+    body: String,
+) -> Result<Json<ImportResponse>, ErrorResponse> {
+    Ok(Json(import_determinations(&body, &query)?))
+}
@@ -0,0 +1,32 @@
+use axum::{Extension, Json};
+use sqlx::PgPool;
+
+use loquat_common::{
+    api::user::{GetResponse, PreferencesBody},
+    models::User,
+};
+
+use crate::error::ErrorResponse;
+
+// The signed in user, so the app knows which units to show values in
+pub async fn get(Extension(current_user): Extension<User>) -> Json<GetResponse> {
+    Json(current_user)
+}
+
+pub async fn put(
+    Extension(pool): Extension<PgPool>,
+    Extension(current_user): Extension<User>,
+    Json(PreferencesBody { unit_system }): Json<PreferencesBody>,
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    sqlx::query!(
+        "UPDATE users SET unit_system = $1 WHERE user_id = $2",
+        unit_system.as_str(),
+        current_user.id
+    )
+    .execute(&pool)
+    .await?;
+    Ok(Json(User {
+        unit_system,
+        ..current_user
+    }))
+}
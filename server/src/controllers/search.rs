@@ -0,0 +1,47 @@
+use axum::{extract::Query, Extension, Json};
+use sqlx::PgPool;
+
+use loquat_common::api::{
+    search::{IndexResponse, SearchResult},
+    ListQuery,
+};
+
+use crate::error::{internal_error, ErrorResponse};
+
+// Finds series, sizes and reports by id, earlier matches in the id come first
+pub async fn index(
+    Query(query): Query<ListQuery>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<IndexResponse>, ErrorResponse> {
+    query.validate()?;
+    let search = match query.search() {
+        Some(search) => search,
+        None => return Ok(Json(vec![])),
+    };
+    sqlx::query!(
+        r#"SELECT kind AS "kind!", id AS "id!" FROM (
+             SELECT 'fan_series' AS kind, fan_series_id AS id FROM fan_serieses
+             UNION ALL SELECT 'fan_size', fan_size_id FROM fan_sizes
+             UNION ALL SELECT 'a1_report', a1_2010_report_id FROM a1_2010_reports
+             UNION ALL SELECT 'a2_report', a2_2010_report_id FROM a2_2010_reports
+           ) AS ids
+           WHERE strpos(lower(id), lower($1)) > 0
+           ORDER BY strpos(lower(id), lower($1)), id
+           LIMIT $2"#,
+        search,
+        query.limit()
+    )
+    .fetch_all(&pool)
+    .await?
+    .into_iter()
+    .map(|record| {
+        Ok(SearchResult {
+            kind: record.kind[..].try_into().map_err(|err| {
+                internal_error(format!("Could not parse search result kind: '{:?}'", err))
+            })?,
+            id: record.id,
+        })
+    })
+    .collect::<Result<_, _>>()
+    .map(Json)
+}
@@ -0,0 +1,98 @@
+use axum::{extract::Query, Extension, Json};
+use serde::Deserialize;
+use sqlx::PgPool;
+
+use loquat_common::{
+    api::selection::{IndexQuery, IndexResponse},
+    calculations::selection::select_fans,
+    models::{
+        A1Standard2010Determination, A1Standard2010Parameters, A1Standard2010Report, FanSeries,
+        FanSize, FanType,
+    },
+};
+
+use crate::error::{internal_error, ErrorResponse};
+
+pub async fn index(
+    Query(query): Query<IndexQuery>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<IndexResponse>, ErrorResponse> {
+    let (inlet_airflow, static_pressure) = query.validate()?;
+    let fan_sizes = sqlx::query!(
+        "SELECT fan_sizes.fan_size_id, fan_sizes.fan_series_id, fan_type, diameter, outlet_area
+             FROM fan_sizes
+             JOIN fan_serieses ON fan_sizes.fan_series_id = fan_serieses.fan_series_id"
+    )
+    .fetch_all(&pool)
+    .await?
+    .into_iter()
+    .map(|record| {
+        Ok(FanSize {
+            id: record.fan_size_id,
+            fan_series_id: record.fan_series_id.clone(),
+            fan_series: FanSeries {
+                id: record.fan_series_id,
+                fan_type: parse_fan_type(&record.fan_type)?,
+                fan_sizes: (),
+            },
+            diameter: record.diameter,
+            outlet_area: record.outlet_area,
+        })
+    })
+    .collect::<Result<Vec<_>, ErrorResponse>>()?;
+
+    let reports = sqlx::query!(
+        "SELECT a1_2010_report_id, fan_sizes.fan_size_id, fan_sizes.fan_series_id, fan_type, diameter, outlet_area, rpm, air_density, determinations
+             FROM a1_2010_reports
+             JOIN fan_sizes ON a1_2010_reports.fan_size_id = fan_sizes.fan_size_id
+             JOIN fan_serieses ON fan_sizes.fan_series_id = fan_serieses.fan_series_id"
+    )
+    .fetch_all(&pool)
+    .await?
+    .into_iter()
+    .map(|record| {
+        Ok(A1Standard2010Report {
+            id: record.a1_2010_report_id,
+            fan_size_id: record.fan_size_id.clone(),
+            fan_size: FanSize {
+                id: record.fan_size_id,
+                fan_series_id: record.fan_series_id.clone(),
+                fan_series: FanSeries {
+                    id: record.fan_series_id,
+                    fan_type: parse_fan_type(&record.fan_type)?,
+                    fan_sizes: (),
+                },
+                diameter: record.diameter,
+                outlet_area: record.outlet_area,
+            },
+            parameters: A1Standard2010Parameters {
+                rpm: record.rpm,
+                air_density: record.air_density,
+            },
+            determinations: Vec::<A1Standard2010Determination>::deserialize(
+                record.determinations,
+            )
+            .map_err(|err| internal_error(format!("Could not parse determinations: '{}'", err)))?,
+        })
+    })
+    .collect::<Result<Vec<_>, ErrorResponse>>()?;
+
+    Ok(Json(
+        select_fans(
+            &fan_sizes,
+            &reports,
+            &inlet_airflow,
+            &static_pressure,
+            query.fit,
+        )
+        .into_iter()
+        .map(|selection| (selection, query.units).into())
+        .collect(),
+    ))
+}
+
+fn parse_fan_type(fan_type: &str) -> Result<FanType, ErrorResponse> {
+    fan_type
+        .try_into()
+        .map_err(|err| internal_error(format!("Could not parse fan type: '{:?}'", err)))
+}
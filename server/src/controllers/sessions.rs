@@ -1,25 +1,22 @@
 use axum::{
-    body::BoxBody,
     extract::{Extension, Json},
-    http::Request,
+    http::{Method, Request},
     middleware::Next,
-    response::Response,
+    response::{IntoResponse, Response},
 };
 use axum_extra::extract::CookieJar;
 
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use std::sync::Arc;
 
-use jwt::{Header, Token};
-#[derive(Debug, Serialize, Deserialize)]
-struct Claims {
-    aud: String, // Audience	Must be your Firebase project ID, the unique identifier for your Firebase project, which can be found in the URL of that project's console.
-    exp: usize, // Expiration time	Must be in the future. The time is measured in seconds since the UNIX epoch.
-    iat: usize, // Issued-at time	Must be in the past. The time is measured in seconds since the UNIX epoch.
-    iss: String, // Issuer	Must be "https://securetoken.google.com/<projectId>", where <projectId> is the same project ID used for aud above.
-    // nbf: usize, // Optional. Not Before (as UTC timestamp)
-    sub: String, // Subject	Must be a non-empty string and must be the uid of the user or device.
-}
+use loquat_common::{api::ApiError, models::User};
+
+use crate::{
+    auth::FirebaseVerifier,
+    controllers::users::{find_or_register_user, find_user},
+    error::ErrorResponse,
+};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PostSessionResponse {
@@ -28,34 +25,29 @@ pub struct PostSessionResponse {
     redirect: Option<String>,
 }
 
-const AUTHORIZED_UIDS: [&str; 2] = [
-    "eY9lIYhF4QTZHdz8VAHoyOeCm1S2", // RRM
-    "DYTCeA3RJNZpks2fQr2clWLKFE83", // JKJ
-];
-
-fn get_authed_user_id(cookie_jar: &CookieJar) -> Option<String> {
+async fn get_authed_user_id(cookie_jar: &CookieJar, verifier: &FirebaseVerifier) -> Option<String> {
     let authorization = cookie_jar.get("Authorization")?;
     let authorization_token = authorization.value().strip_prefix("Bearer ")?;
 
-    // Algorithm::RS256
-    let unverified: Token<Header, Claims, _> = Token::parse_unverified(authorization_token)
-        .map_err(|err| err.to_string())
-        .ok()?;
-
-    let claims = unverified.claims();
-    Some(claims.sub.clone())
+    verifier
+        .verify(authorization_token)
+        .await
+        .ok()
+        .map(|claims| claims.sub)
 }
 
 pub async fn post(
-    Extension(_pool): Extension<PgPool>,
+    Extension(pool): Extension<PgPool>,
+    Extension(verifier): Extension<Arc<FirebaseVerifier>>,
     cookie_jar: CookieJar, // Json(PostBody): Json<()>,
-) -> Result<Json<PostSessionResponse>, String> {
-    let claimed_id = get_authed_user_id(&cookie_jar);
+) -> Result<Json<PostSessionResponse>, ErrorResponse> {
+    let claimed_id = get_authed_user_id(&cookie_jar, &verifier).await;
+    let user = match &claimed_id {
+        Some(id) => Some(find_or_register_user(&pool, id).await?),
+        None => None,
+    };
 
-    if AUTHORIZED_UIDS
-        .iter()
-        .any(|authed_id| Some(authed_id.to_string()) == claimed_id)
-    {
+    if user.as_ref().is_some_and(User::is_authorized) {
         Ok(Json(PostSessionResponse {
             success: true,
             message: "Authenticated and Authorized! Redirecting...".to_string(),
@@ -73,26 +65,68 @@ pub async fn post(
     }
 }
 
-pub async fn auth_middleware<B>(request: Request<B>, next: Next<B>) -> Response {
+pub async fn auth_middleware<B>(
+    Extension(pool): Extension<PgPool>,
+    Extension(verifier): Extension<Arc<FirebaseVerifier>>,
+    mut request: Request<B>,
+    next: Next<B>,
+) -> Response {
     // do something with `request`...
     let cookie_jar = CookieJar::from_headers(request.headers());
-    let claimed_id = get_authed_user_id(&cookie_jar);
+    let claimed_id = get_authed_user_id(&cookie_jar, &verifier).await;
 
     println!("Route: {}", request.uri());
 
     if request.uri().query() == Some("/static/login.html")
         || request.uri().to_string().starts_with("/api/sessions")
     {
-        next.run(request).await
-    } else if AUTHORIZED_UIDS
-        .iter()
-        .any(|authed_id| Some(authed_id.to_string()) == claimed_id)
+        return next.run(request).await;
+    }
+
+    let user = match &claimed_id {
+        Some(id) => find_user(&pool, id).await.ok().flatten(),
+        None => None,
+    };
+    let (user, role) = match user {
+        Some(
+            user @ User {
+                role: Some(role), ..
+            },
+        ) => (user, role),
+        _ => {
+            return ErrorResponse(ApiError::unauthorized(
+                "You must sign in with an authorized account",
+            ))
+            .into_response()
+        }
+    };
+
+    let permitted = if request.uri().path().starts_with("/api/users") {
+        role.can_manage_users()
+    } else if request.uri().path() == "/api/me" {
+        // Everyone can change their own preferences
+        true
+    } else if request.method() == Method::GET || request.method() == Method::HEAD {
+        true
+    } else if request.uri().path().starts_with("/api/fan_series")
+        || request.uri().path().starts_with("/api/fan_sizes")
     {
+        role.can_edit_catalogue()
+    } else {
+        role.can_edit_reports()
+    };
+
+    if permitted {
+        // Lets handlers know who's making the request
+        request.extensions_mut().insert(user);
         next.run(request).await
     } else {
-        Response::builder()
-            .status(401)
-            .body(BoxBody::default())
-            .unwrap()
+        ErrorResponse(ApiError::forbidden(format!(
+            "A {} can't {} {}",
+            role.as_str(),
+            request.method(),
+            request.uri().path()
+        )))
+        .into_response()
     }
 }
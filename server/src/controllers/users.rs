@@ -0,0 +1,126 @@
+use axum::{extract::Path, Extension, Json};
+use sqlx::PgPool;
+
+use loquat_common::{
+    api::{
+        user::{GetResponse, IndexResponse, UpdateBody},
+        ApiError,
+    },
+    display::UnitSystem,
+    models::{Role, User},
+};
+
+use crate::error::{internal_error, ErrorResponse};
+
+pub async fn index(
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<IndexResponse>, ErrorResponse> {
+    sqlx::query!(
+        "SELECT user_id, name, role, unit_system FROM users ORDER BY role IS NOT NULL, created_at DESC LIMIT 200"
+    )
+    .fetch_all(&pool)
+    .await?
+    .into_iter()
+    .map(|record| user_from_row(record.user_id, record.name, record.role, record.unit_system))
+    .collect::<Result<_, _>>()
+    .map(Json)
+}
+
+pub async fn get(
+    Path(id): Path<String>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    find_user(&pool, &id)
+        .await?
+        .map(Json)
+        .ok_or_else(|| ApiError::not_found(format!("Could not find User '{}'", id)).into())
+}
+
+// Invites a user before they've signed in, so they're authorized on their first visit
+pub async fn post(
+    Extension(pool): Extension<PgPool>,
+    Json(UpdateBody { id, name, role }): Json<UpdateBody>,
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    let record = sqlx::query!(
+        "INSERT INTO users (user_id, name, role) VALUES ($1, $2, $3)
+           ON CONFLICT DO NOTHING RETURNING user_id",
+        id,
+        name,
+        role.map(|role| role.as_str())
+    )
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(|| ApiError::conflict(format!("User '{}' already exists", id)))?;
+    get(Path(record.user_id), Extension(pool)).await
+}
+
+// Approves a user who has tried to sign in, or changes their role.
+// Setting the role to null revokes their access.
+pub async fn put(
+    Path(id): Path<String>,
+    Extension(pool): Extension<PgPool>,
+    Extension(current_user): Extension<User>,
+    Json(UpdateBody { id: _, name, role }): Json<UpdateBody>,
+) -> Result<Json<GetResponse>, ErrorResponse> {
+    if current_user.id == id && role != current_user.role {
+        return Err(ApiError::forbidden("You can't change your own role").into());
+    }
+    let result = sqlx::query!(
+        "UPDATE users SET name = $1, role = $2 WHERE user_id = $3",
+        name,
+        role.map(|role| role.as_str()),
+        id
+    )
+    .execute(&pool)
+    .await?;
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found(format!("Could not find User '{}'", id)).into());
+    }
+    get(Path(id), Extension(pool)).await
+}
+
+pub async fn find_user(pool: &PgPool, id: &str) -> Result<Option<User>, ErrorResponse> {
+    sqlx::query!(
+        "SELECT user_id, name, role, unit_system FROM users WHERE user_id = $1",
+        id
+    )
+    .fetch_optional(pool)
+    .await?
+    .map(|record| user_from_row(record.user_id, record.name, record.role, record.unit_system))
+    .transpose()
+}
+
+// Records anyone who signs in, so admins can approve them without copying UIDs around
+pub async fn find_or_register_user(pool: &PgPool, id: &str) -> Result<User, ErrorResponse> {
+    let record = sqlx::query!(
+        "INSERT INTO users (user_id) VALUES ($1)
+           ON CONFLICT (user_id) DO UPDATE SET user_id = EXCLUDED.user_id
+           RETURNING user_id, name, role, unit_system",
+        id
+    )
+    .fetch_one(pool)
+    .await?;
+    user_from_row(record.user_id, record.name, record.role, record.unit_system)
+}
+
+fn user_from_row(
+    id: String,
+    name: Option<String>,
+    role: Option<String>,
+    unit_system: String,
+) -> Result<User, ErrorResponse> {
+    let role = role
+        .map(|role| {
+            Role::try_from(&role[..])
+                .map_err(|err| internal_error(format!("Could not parse role: '{:?}'", err)))
+        })
+        .transpose()?;
+    let unit_system = UnitSystem::try_from(&unit_system[..])
+        .map_err(|err| internal_error(format!("Could not parse unit system: '{:?}'", err)))?;
+    Ok(User {
+        id,
+        name,
+        role,
+        unit_system,
+    })
+}
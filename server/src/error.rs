@@ -0,0 +1,77 @@
+use std::fmt::Display;
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use loquat_common::{api::ApiError, calculations::CalculationError, import::ImportError};
+
+// The error half of every handler's result, rendered as an ApiError body with its status code
+#[derive(Debug)]
+pub struct ErrorResponse(pub ApiError);
+
+impl IntoResponse for ErrorResponse {
+    fn into_response(self) -> Response {
+        let status =
+            StatusCode::from_u16(self.0.status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        (status, Json(self.0)).into_response()
+    }
+}
+
+impl Display for ErrorResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<ApiError> for ErrorResponse {
+    fn from(err: ApiError) -> Self {
+        ErrorResponse(err)
+    }
+}
+
+impl From<CalculationError> for ErrorResponse {
+    fn from(err: CalculationError) -> Self {
+        ErrorResponse(err.into())
+    }
+}
+
+impl From<ImportError> for ErrorResponse {
+    fn from(err: ImportError) -> Self {
+        ErrorResponse(err.into())
+    }
+}
+
+impl From<sqlx::Error> for ErrorResponse {
+    fn from(err: sqlx::Error) -> Self {
+        match &err {
+            sqlx::Error::RowNotFound => {
+                ApiError::not_found("Could not find the requested record").into()
+            }
+            sqlx::Error::Database(db_err) => match db_err.code().as_deref() {
+                // unique_violation, like renaming a report to an id that's already taken
+                Some("23505") => ApiError::conflict(db_err.message()).into(),
+                // foreign_key_violation, like a report for a fan size that doesn't exist
+                Some("23503") => ApiError::validation(db_err.message()).into(),
+                // check_violation
+                Some("23514") => ApiError::validation(db_err.message()).into(),
+                _ => internal_error(err),
+            },
+            _ => internal_error(err),
+        }
+    }
+}
+
+impl From<serde_json::Error> for ErrorResponse {
+    fn from(err: serde_json::Error) -> Self {
+        internal_error(err)
+    }
+}
+
+// The details of unexpected errors only go to the server logs
+pub fn internal_error(err: impl Display) -> ErrorResponse {
+    println!("Internal error: {}", err);
+    ApiError::internal("Something went wrong on the server").into()
+}
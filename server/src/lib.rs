@@ -1 +1,4 @@
+pub mod auth;
 pub mod controllers;
+pub mod error;
+pub mod pdf;
@@ -1,12 +1,15 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Arc};
 
 use axum::{
     http::StatusCode,
     response::IntoResponse,
-    routing::{get, get_service, post, put},
+    routing::{delete, get, get_service, post, put},
     Extension, Router,
 };
-use loquat_server::controllers::{self, sessions::auth_middleware};
+use loquat_server::{
+    auth::{FirebaseVerifier, KeySource, GOOGLE_JWKS_URL},
+    controllers::{self, sessions::auth_middleware},
+};
 use shuttle_secrets::SecretStore;
 use sqlx::{migrate::Migrator, PgPool};
 use tower_http::services::{ServeDir, ServeFile};
@@ -19,7 +22,7 @@ static MIGRATOR: Migrator = sqlx::migrate!();
 
 #[shuttle_runtime::main]
 async fn axum(
-    #[shuttle_secrets::Secrets] _secret_store: SecretStore,
+    #[shuttle_secrets::Secrets] secret_store: SecretStore,
     #[shuttle_static_folder::StaticFolder] static_folder: PathBuf,
     #[shuttle_aws_rds::Postgres(
         local_uri = "postgres://{secrets.PG_USER}:{secrets.PG_PASSWORD}@{secrets.PG_ROUTE}"
@@ -89,10 +92,66 @@ async fn axum(
             \"static_pressure\": 6.839,
             \"brake_horsepower\": 7.204
         }]') ON CONFLICT DO NOTHING;",
+        "INSERT INTO nozzles (nozzle_id) VALUES ('SKYPLUME G1-ELLV NOZZLE-150') ON CONFLICT DO NOTHING;",
+        "INSERT INTO induced_flow_fan_sizes (induced_flow_fan_size_id, fan_size_id, nozzle_id) VALUES ('SKYPLUME G1-ELLV DMF-150 NOZZLE-150', 'SKYPLUME G1-ELLV DMF-150', 'SKYPLUME G1-ELLV NOZZLE-150') ON CONFLICT DO NOTHING;",
+        "INSERT INTO public.a2_2010_reports (a2_2010_report_id,a1_2010_report_id,induced_flow_fan_size_id,rpm,determinations) VALUES
+        ('5000.1-A2','5000.1-A1','SKYPLUME G1-ELLV DMF-150 NOZZLE-150',1750.0,'[
+        {
+            \"cfm\": 15300,
+            \"static_pressure\": 0.040
+        },
+        {
+            \"cfm\": 13816,
+            \"static_pressure\": 1.227
+        },
+        {
+            \"cfm\": 12386,
+            \"static_pressure\": 2.537
+        },
+        {
+            \"cfm\": 10815,
+            \"static_pressure\": 3.738
+        },
+        {
+            \"cfm\": 9316,
+            \"static_pressure\": 4.543
+        },
+        {
+            \"cfm\": 7736,
+            \"static_pressure\": 5.078
+        },
+        {
+            \"cfm\": 6419,
+            \"static_pressure\": 5.448
+        },
+        {
+            \"cfm\": 5035,
+            \"static_pressure\": 5.787
+        },
+        {
+            \"cfm\": 2350,
+            \"static_pressure\": 5.966
+        },
+        {
+            \"cfm\": 0,
+            \"static_pressure\": 6.646
+        }]') ON CONFLICT DO NOTHING;",
     ] {
         sqlx::query(statement).execute(&pool).await.expect("Data insert failed");
     }
 
+    // FIREBASE_JWKS can point at a local file of keys, for testing offline
+    let verifier = Arc::new(FirebaseVerifier::new(
+        secret_store
+            .get("FIREBASE_PROJECT_ID")
+            .unwrap_or_else(|| "loquat-7a013".to_string()),
+        KeySource::parse(
+            &secret_store
+                .get("FIREBASE_JWKS")
+                .unwrap_or_else(|| GOOGLE_JWKS_URL.to_string()),
+        ),
+    ));
+
     // In production, serve from the root static folder
     // In dev, this is empty and the frontend proxies to anything /api to this server
     let serve_dir = get_service(
@@ -103,9 +162,22 @@ async fn axum(
 
     let router = Router::new()
         .route("/api/fan_series", get(controllers::fan_series::index))
+        .route("/api/fan_series", post(controllers::fan_series::post))
         .route("/api/fan_series/:id", get(controllers::fan_series::get))
+        .route("/api/fan_series/:id", put(controllers::fan_series::put))
+        .route(
+            "/api/fan_series/:id",
+            delete(controllers::fan_series::delete),
+        )
         .route("/api/fan_sizes", get(controllers::fan_size::index))
+        .route("/api/fan_sizes", post(controllers::fan_size::post))
         .route("/api/fan_sizes/:id", get(controllers::fan_size::get))
+        .route("/api/fan_sizes/:id", put(controllers::fan_size::put))
+        .route("/api/fan_sizes/:id", delete(controllers::fan_size::delete))
+        .route(
+            "/api/fan_sizes/:id/rating_table",
+            get(controllers::fan_size::rating_table),
+        )
         .route(
             "/api/a1_2010_report/:id",
             get(controllers::a1_2010_report::get),
@@ -114,18 +186,67 @@ async fn axum(
             "/api/a1_2010_report/:id",
             put(controllers::a1_2010_report::put),
         )
+        .route(
+            "/api/a1_2010_report",
+            get(controllers::a1_2010_report::index),
+        )
         .route(
             "/api/a1_2010_report",
             post(controllers::a1_2010_report::post),
         )
+        .route(
+            "/api/a1_2010_report/:id/curve_family",
+            get(controllers::a1_2010_report::curve_family),
+        )
+        .route(
+            "/api/a1_2010_report/:id/pdf",
+            get(controllers::a1_2010_report::pdf),
+        )
+        .route(
+            "/api/a1_2010_report/:id/revisions",
+            get(controllers::a1_2010_report::revisions),
+        )
+        .route(
+            "/api/a1_2010_report/:id/revisions/diff",
+            get(controllers::a1_2010_report::revision_diff),
+        )
+        .route(
+            "/api/a1_2010_report/:id/revisions/:revision_id/restore",
+            post(controllers::a1_2010_report::restore_revision),
+        )
+        .route(
+            "/api/a2_2010_report/:id",
+            get(controllers::a2_2010_report::get),
+        )
+        .route(
+            "/api/a2_2010_report/:id",
+            put(controllers::a2_2010_report::put),
+        )
+        .route(
+            "/api/a2_2010_report",
+            post(controllers::a2_2010_report::post),
+        )
+        .route(
+            "/api/import/a1_determinations",
+            post(controllers::import::a1_determinations),
+        )
+        .route("/api/me", get(controllers::me::get))
+        .route("/api/me", put(controllers::me::put))
+        .route("/api/search", get(controllers::search::index))
+        .route("/api/selections", get(controllers::selections::index))
         .route("/api/sessions", post(controllers::sessions::post))
+        .route("/api/users", get(controllers::users::index))
+        .route("/api/users", post(controllers::users::post))
+        .route("/api/users/:id", get(controllers::users::get))
+        .route("/api/users/:id", put(controllers::users::put))
         .route_service(
             "/login",
             get_service(ServeFile::new(static_folder.join("static/login.html")))
                 .handle_error(handle_error),
         )
-        .layer(Extension(pool))
         .layer(axum::middleware::from_fn(auth_middleware))
+        .layer(Extension(pool))
+        .layer(Extension(verifier))
         .fallback_service(serve_dir);
     Ok(router.into())
 }
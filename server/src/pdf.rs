@@ -0,0 +1,508 @@
+use std::sync::OnceLock;
+
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+use svg2pdf::usvg::{self, fontdb, PostProcessingSteps, TreeParsing, TreePostProc};
+
+use loquat_common::{
+    api::a1_2010_report::GetResponse,
+    calculations::{
+        core::FanCurve,
+        standards::{A1OperatingPoint, CanFindA1OperatingPoint},
+        units::{
+            AirDensity, BrakeHorsepower, FanDiameter, FanSpeed, InletAirflow, OutletArea,
+            OutletVelocity, StaticPressure, TotalPressure,
+        },
+    },
+    chart::a1_fan_chart,
+    display::{Measure, UnitSystem},
+    models::{A1Standard2010Report, FanSeries, FanSize},
+};
+
+use crate::error::{internal_error, ErrorResponse};
+
+// US Letter, in points
+const PAGE_WIDTH: f32 = 612.0;
+const PAGE_HEIGHT: f32 = 792.0;
+const MARGIN: f32 = 48.0;
+const BAND_HEIGHT: f32 = 40.0;
+// Six inches, leaving room for the tables on the same page
+const CHART_WIDTH: f32 = 432.0;
+const BRAND: &str = "LOQUAT";
+
+const REGULAR: Name = Name(b"F1");
+const BOLD: Name = Name(b"F2");
+
+// Chart text is turned into outlines, so the fonts only have to be found once
+fn fonts() -> &'static fontdb::Database {
+    static FONTS: OnceLock<fontdb::Database> = OnceLock::new();
+    FONTS.get_or_init(|| {
+        let mut fonts = fontdb::Database::new();
+        fonts.load_system_fonts();
+        // sans-serif means Arial unless told otherwise, which few servers have
+        let installed = ["Helvetica", "Arial", "Liberation Sans", "DejaVu Sans"]
+            .into_iter()
+            .find(|family| {
+                fonts
+                    .faces()
+                    .any(|face| face.families.iter().any(|(name, _)| name == family))
+            });
+        if let Some(family) = installed {
+            fonts.set_sans_serif_family(family);
+        }
+        fonts
+    })
+}
+
+struct SheetPage {
+    content: Content,
+    charts: Vec<usvg::Tree>,
+}
+
+// Lays out branded pages top to bottom, starting a new page whenever the next
+// block won't fit. Text is set in the standard Helvetica fonts, so nothing has
+// to be embedded.
+pub struct Sheet {
+    title: String,
+    pages: Vec<SheetPage>,
+    y: f32,
+}
+
+impl Sheet {
+    pub fn new(title: impl Into<String>, subtitle: &str) -> Self {
+        let mut sheet = Sheet {
+            title: title.into(),
+            pages: vec![],
+            y: 0.0,
+        };
+        sheet.new_page();
+        let title = sheet.title.clone();
+        sheet.text(BOLD, 18.0, MARGIN, &title);
+        sheet.y -= 16.0;
+        sheet.text(REGULAR, 10.0, MARGIN, subtitle);
+        sheet.y -= 12.0;
+        sheet
+    }
+
+    fn new_page(&mut self) {
+        let mut content = Content::new();
+        content
+            .set_fill_rgb(0.16, 0.29, 0.24)
+            .rect(0.0, PAGE_HEIGHT - BAND_HEIGHT, PAGE_WIDTH, BAND_HEIGHT)
+            .fill_nonzero()
+            .set_fill_rgb(1.0, 1.0, 1.0)
+            .begin_text()
+            .set_font(BOLD, 16.0)
+            .next_line(MARGIN, PAGE_HEIGHT - BAND_HEIGHT + 14.0)
+            .show(Str(&win_ansi(BRAND)))
+            .end_text()
+            .set_fill_gray(0.0);
+        self.pages.push(SheetPage {
+            content,
+            charts: vec![],
+        });
+        self.y = PAGE_HEIGHT - BAND_HEIGHT - 32.0;
+    }
+
+    fn make_room(&mut self, height: f32) {
+        if self.y - height < MARGIN + 24.0 {
+            self.new_page();
+        }
+    }
+
+    fn page(&mut self) -> &mut SheetPage {
+        self.pages.last_mut().expect("A sheet always has a page")
+    }
+
+    fn text(&mut self, font: Name, size: f32, x: f32, text: &str) {
+        let y = self.y;
+        self.page()
+            .content
+            .begin_text()
+            .set_font(font, size)
+            .next_line(x, y)
+            .show(Str(&win_ansi(text)))
+            .end_text();
+    }
+
+    pub fn heading(&mut self, text: &str) {
+        self.make_room(48.0);
+        self.y -= 16.0;
+        self.text(BOLD, 13.0, MARGIN, text);
+        let y = self.y - 4.0;
+        self.page()
+            .content
+            .set_line_width(0.5)
+            .move_to(MARGIN, y)
+            .line_to(PAGE_WIDTH - MARGIN, y)
+            .stroke();
+        self.y -= 18.0;
+    }
+
+    // Labels and values, two pairs to a line
+    pub fn key_values(&mut self, pairs: &[(&str, String)]) {
+        let column_width = (PAGE_WIDTH - 2.0 * MARGIN) / 2.0;
+        for line in pairs.chunks(2) {
+            self.make_room(14.0);
+            for (i, (label, value)) in line.iter().enumerate() {
+                let x = MARGIN + column_width * i as f32;
+                self.text(BOLD, 10.0, x, label);
+                self.text(REGULAR, 10.0, x + 112.0, value);
+            }
+            self.y -= 14.0;
+        }
+    }
+
+    // Header cells can break onto a second line with '\n', and are repeated
+    // at the top of each page the table runs onto
+    pub fn table(&mut self, headers: &[&str], rows: &[Vec<String>]) {
+        let column_width = (PAGE_WIDTH - 2.0 * MARGIN) / headers.len() as f32;
+        let header_lines = headers
+            .iter()
+            .map(|header| header.lines().count())
+            .max()
+            .unwrap_or(1);
+        let header_height = 12.0 * header_lines as f32 + 6.0;
+        let draw_header = |sheet: &mut Sheet| {
+            let top = sheet.y + 10.0;
+            sheet
+                .page()
+                .content
+                .set_fill_gray(0.9)
+                .rect(
+                    MARGIN,
+                    top - header_height,
+                    PAGE_WIDTH - 2.0 * MARGIN,
+                    header_height,
+                )
+                .fill_nonzero()
+                .set_fill_gray(0.0);
+            let y = sheet.y;
+            for (i, header) in headers.iter().enumerate() {
+                for (line_index, line) in header.lines().enumerate() {
+                    sheet.y = y - 12.0 * line_index as f32;
+                    sheet.text(BOLD, 9.0, MARGIN + 4.0 + column_width * i as f32, line);
+                }
+            }
+            sheet.y = y - header_height;
+        };
+
+        self.make_room(header_height + 14.0);
+        draw_header(self);
+        for row in rows {
+            if self.y - 14.0 < MARGIN + 24.0 {
+                self.new_page();
+                draw_header(self);
+            }
+            for (i, cell) in row.iter().enumerate() {
+                self.text(REGULAR, 9.0, MARGIN + 4.0 + column_width * i as f32, cell);
+            }
+            self.y -= 14.0;
+        }
+    }
+
+    // Centered, keeping the chart's aspect ratio
+    pub fn chart(&mut self, svg: &str) -> Result<(), usvg::Error> {
+        let mut tree = usvg::Tree::from_str(svg, &usvg::Options::default())?;
+        tree.postprocess(PostProcessingSteps::default(), fonts());
+        let width = CHART_WIDTH;
+        let height = width * tree.size.height() / tree.size.width();
+        self.make_room(height + 8.0);
+        let bottom = self.y - height;
+        let page = self.page();
+        let name = format!("Chart{}", page.charts.len());
+        page.content
+            .save_state()
+            .transform([width, 0.0, 0.0, height, (PAGE_WIDTH - width) / 2.0, bottom])
+            .x_object(Name(name.as_bytes()))
+            .restore_state();
+        page.charts.push(tree);
+        self.y = bottom - 8.0;
+        Ok(())
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        let mut pdf = Pdf::new();
+        let catalog_ref = Ref::new(1);
+        let page_tree_ref = Ref::new(2);
+        let regular_ref = Ref::new(3);
+        let bold_ref = Ref::new(4);
+        let info_ref = Ref::new(5);
+
+        // Each page and its content stream, then whatever the charts need after them
+        let page_count = self.pages.len();
+        let page_refs: Vec<(Ref, Ref)> = (0..page_count as i32)
+            .map(|i| (Ref::new(6 + 2 * i), Ref::new(7 + 2 * i)))
+            .collect();
+        let mut next_ref = Ref::new(6 + 2 * page_count as i32);
+        pdf.catalog(catalog_ref).pages(page_tree_ref);
+        pdf.pages(page_tree_ref)
+            .kids(page_refs.iter().map(|(page_ref, _)| *page_ref))
+            .count(page_count as i32);
+        for (font_ref, base_font) in [(regular_ref, "Helvetica"), (bold_ref, "Helvetica-Bold")] {
+            pdf.type1_font(font_ref)
+                .base_font(Name(base_font.as_bytes()))
+                .encoding_predefined(Name(b"WinAnsiEncoding"));
+        }
+        pdf.document_info(info_ref)
+            .title(TextStr(&self.title))
+            .creator(TextStr("Loquat"));
+
+        for (index, (sheet_page, (page_ref, content_ref))) in
+            self.pages.into_iter().zip(page_refs).enumerate()
+        {
+            let SheetPage {
+                mut content,
+                charts,
+            } = sheet_page;
+            content
+                .set_fill_gray(0.4)
+                .begin_text()
+                .set_font(REGULAR, 8.0)
+                .next_line(MARGIN, MARGIN / 2.0)
+                .show(Str(&win_ansi(&self.title)))
+                .next_line(PAGE_WIDTH - 2.0 * MARGIN - 40.0, 0.0)
+                .show(Str(&win_ansi(&format!(
+                    "Page {} of {}",
+                    index + 1,
+                    page_count
+                ))))
+                .end_text();
+
+            let chart_refs: Vec<Ref> = charts
+                .iter()
+                .map(|tree| {
+                    let chart_ref = next_ref;
+                    next_ref = svg2pdf::convert_tree_into(
+                        tree,
+                        svg2pdf::Options::default(),
+                        &mut pdf,
+                        chart_ref,
+                    );
+                    chart_ref
+                })
+                .collect();
+
+            let mut page = pdf.page(page_ref);
+            page.parent(page_tree_ref)
+                .media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT))
+                .contents(content_ref);
+            let mut resources = page.resources();
+            resources
+                .fonts()
+                .pair(REGULAR, regular_ref)
+                .pair(BOLD, bold_ref);
+            let mut x_objects = resources.x_objects();
+            for (i, chart_ref) in chart_refs.iter().enumerate() {
+                x_objects.pair(Name(format!("Chart{}", i).as_bytes()), *chart_ref);
+            }
+            x_objects.finish();
+            resources.finish();
+            page.finish();
+            pdf.stream(content_ref, &content.finish());
+        }
+        pdf.finish()
+    }
+}
+
+// The standard fonts are set in WinAnsiEncoding, which matches Latin-1 for the
+// symbols units use, like ² and ³
+fn win_ansi(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c as u32 {
+            code @ (0x20..=0x7e | 0xa0..=0xff) => code as u8,
+            _ => b'?',
+        })
+        .collect()
+}
+
+// A duty picked from the report, rated on a fan size in the same series
+pub struct RatedOperatingPoint {
+    pub fan_size: FanSize<FanSeries<()>>,
+    pub operating_point: A1OperatingPoint,
+}
+
+pub fn a1_report_pdf(
+    report: &A1Standard2010Report<FanSize<FanSeries<()>>>,
+    rated: Option<&RatedOperatingPoint>,
+    units: UnitSystem,
+) -> Result<Vec<u8>, ErrorResponse> {
+    let mut sheet = match rated {
+        Some(rated) => Sheet::new(
+            format!("{} Performance", rated.fan_size.id),
+            &format!("Rated from AMCA 210 (A1) report {}", report.id),
+        ),
+        None => Sheet::new(
+            format!("{} Performance", report.fan_size_id),
+            &format!("AMCA 210 (A1) report {}", report.id),
+        ),
+    };
+
+    let (chart_title, fan_curve) = match rated {
+        Some(RatedOperatingPoint {
+            fan_size,
+            operating_point,
+        }) => {
+            let fan_speed: &FanSpeed = operating_point.as_ref();
+            let fan_curve = report
+                .clone()
+                .a1_fan_curve_at(&FanDiameter::from_inches(fan_size.diameter), fan_speed)?;
+            sheet.heading("Operating Point");
+            sheet.key_values(&operating_point_summary(fan_size, operating_point, units));
+            (
+                format!("{} at {}", fan_size.id, fan_speed.format(units)),
+                fan_curve,
+            )
+        }
+        None => {
+            let fan_curve: FanCurve<A1OperatingPoint> = report.clone().into();
+            (
+                format!(
+                    "{} at {}",
+                    report.fan_size_id,
+                    FanSpeed::from_rpm(report.parameters.rpm).format(units)
+                ),
+                fan_curve,
+            )
+        }
+    };
+    sheet.heading("Fan Curve");
+    let chart = a1_fan_chart(
+        chart_title,
+        &fan_curve,
+        rated.map(|rated| &rated.operating_point),
+        units,
+    );
+    sheet.chart(&chart.to_svg()).map_err(internal_error)?;
+
+    sheet.heading("Test Parameters");
+    sheet.key_values(&test_parameters(report, units));
+
+    sheet.heading("Determinations");
+    let GetResponse {
+        report,
+        performance,
+        ..
+    } = report.clone().into();
+    let headers = [
+        "#".to_string(),
+        format!("Airflow\n({})", InletAirflow::symbol(units)),
+        format!("Static Press.\n({})", StaticPressure::symbol(units)),
+        format!("Brake Power\n({})", BrakeHorsepower::symbol(units)),
+        format!("Outlet Vel.\n({})", OutletVelocity::symbol(units)),
+        format!("Total Press.\n({})", TotalPressure::symbol(units)),
+        "Static Eff.\n(%)".to_string(),
+        "Total Eff.\n(%)".to_string(),
+    ];
+    let rows: Vec<Vec<String>> = report
+        .determinations
+        .iter()
+        .zip(performance.iter())
+        .enumerate()
+        .map(|(i, (determination, performance))| {
+            vec![
+                (i + 1).to_string(),
+                value(InletAirflow::from_cfm(determination.cfm), units),
+                value(
+                    StaticPressure::from_inches(determination.static_pressure),
+                    units,
+                ),
+                value(
+                    BrakeHorsepower::from_hp(determination.brake_horsepower),
+                    units,
+                ),
+                value(OutletVelocity::from_fpm(performance.outlet_velocity), units),
+                value(
+                    TotalPressure::from_inches(performance.total_pressure),
+                    units,
+                ),
+                format!("{:.1}", performance.static_efficiency * 100.0),
+                format!("{:.1}", performance.total_efficiency * 100.0),
+            ]
+        })
+        .collect();
+    sheet.table(
+        &headers.iter().map(String::as_str).collect::<Vec<&str>>(),
+        &rows,
+    );
+
+    Ok(sheet.finish())
+}
+
+fn value<M: Measure>(measure: M, units: UnitSystem) -> String {
+    measure.measurement(units).value_string()
+}
+
+fn operating_point_summary(
+    fan_size: &FanSize<FanSeries<()>>,
+    operating_point: &A1OperatingPoint,
+    units: UnitSystem,
+) -> Vec<(&'static str, String)> {
+    let outlet_area = OutletArea::from_square_inches(fan_size.outlet_area);
+    vec![
+        ("Fan Size", fan_size.id.clone()),
+        ("Fan Series", fan_size.fan_series_id.clone()),
+        (
+            "Airflow",
+            (operating_point.as_ref() as &InletAirflow).format(units),
+        ),
+        (
+            "Static Pressure",
+            (operating_point.as_ref() as &StaticPressure).format(units),
+        ),
+        (
+            "Fan Speed",
+            (operating_point.as_ref() as &FanSpeed).format(units),
+        ),
+        (
+            "Brake Power",
+            (operating_point.as_ref() as &BrakeHorsepower).format(units),
+        ),
+        (
+            "Outlet Velocity",
+            operating_point.outlet_velocity(&outlet_area).format(units),
+        ),
+        (
+            "Total Pressure",
+            operating_point.total_pressure(&outlet_area).format(units),
+        ),
+        (
+            "Static Efficiency",
+            operating_point.static_efficiency().format(units),
+        ),
+        (
+            "Total Efficiency",
+            operating_point.total_efficiency(&outlet_area).format(units),
+        ),
+    ]
+}
+
+fn test_parameters(
+    report: &A1Standard2010Report<FanSize<FanSeries<()>>>,
+    units: UnitSystem,
+) -> Vec<(&'static str, String)> {
+    vec![
+        ("Report", report.id.clone()),
+        ("Fan Size Tested", report.fan_size_id.clone()),
+        ("Fan Series", report.fan_size.fan_series_id.clone()),
+        ("Fan Type", report.fan_size.fan_series.fan_type.to_string()),
+        (
+            "Diameter",
+            FanDiameter::from_inches(report.fan_size.diameter).format(units),
+        ),
+        (
+            "Outlet Area",
+            OutletArea::from_square_inches(report.fan_size.outlet_area).format(units),
+        ),
+        (
+            "Test Speed",
+            FanSpeed::from_rpm(report.parameters.rpm).format(units),
+        ),
+        (
+            "Air Density",
+            match report.parameters.air_density {
+                Some(air_density) => AirDensity::from_lb_per_cubic_foot(air_density).format(units),
+                None => format!("{} (standard)", AirDensity::standard().format(units)),
+            },
+        ),
+    ]
+}
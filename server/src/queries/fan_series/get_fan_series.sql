@@ -1,5 +1,5 @@
-SELECT fan_sizes.fan_size_id, diameter, fan_sizes.fan_series_id, fan_type, outlet_area
+SELECT fan_sizes.fan_size_id, diameter, fan_serieses.fan_series_id, fan_type, outlet_area
             FROM fan_serieses
-            JOIN fan_sizes
+            LEFT JOIN fan_sizes
             ON fan_sizes.fan_series_id = fan_serieses.fan_series_id
             WHERE fan_serieses.fan_series_id = $1
\ No newline at end of file
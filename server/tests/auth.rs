@@ -0,0 +1,147 @@
+use std::path::PathBuf;
+
+use jwt::{AlgorithmType, Header, PKeyWithDigest, SignWithKey, Token};
+use loquat_server::auth::{Claims, FirebaseVerifier, KeySource};
+use openssl::{hash::MessageDigest, pkey::PKey};
+
+const PROJECT_ID: &str = "loquat-7a013";
+const NOW: u64 = 1_687_000_000;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+fn verifier() -> FirebaseVerifier {
+    FirebaseVerifier::new(
+        PROJECT_ID.to_string(),
+        KeySource::File(fixture("jwks.json")),
+    )
+}
+
+fn valid_claims() -> Claims {
+    Claims {
+        aud: PROJECT_ID.to_string(),
+        exp: NOW + 3600,
+        iat: NOW - 60,
+        iss: format!("https://securetoken.google.com/{}", PROJECT_ID),
+        sub: "eY9lIYhF4QTZHdz8VAHoyOeCm1S2".to_string(),
+    }
+}
+
+fn sign(claims: Claims, key_id: &str) -> String {
+    let signing_key = PKeyWithDigest {
+        digest: MessageDigest::sha256(),
+        key: PKey::private_key_from_pem(
+            &std::fs::read(fixture("signing_key.pem")).expect("Missing signing key fixture"),
+        )
+        .expect("Invalid signing key fixture"),
+    };
+    let header = Header {
+        algorithm: AlgorithmType::Rs256,
+        key_id: Some(key_id.to_string()),
+        ..Default::default()
+    };
+    Token::new(header, claims)
+        .sign_with_key(&signing_key)
+        .expect("Could not sign token")
+        .as_str()
+        .to_string()
+}
+
+#[tokio::test]
+async fn it_accepts_tokens_signed_by_a_published_key() {
+    let claims = verifier()
+        .verify_at(&sign(valid_claims(), "loquat-test-key"), NOW)
+        .await
+        .unwrap();
+    assert_eq!(claims, valid_claims());
+}
+
+#[tokio::test]
+async fn it_rejects_forged_signatures() {
+    let token = sign(valid_claims(), "loquat-test-key");
+    // Swap in another subject while keeping the original signature
+    let mut parts: Vec<String> = token.split('.').map(str::to_string).collect();
+    parts[1] = sign(
+        Claims {
+            sub: "someone-else".to_string(),
+            ..valid_claims()
+        },
+        "loquat-test-key",
+    )
+    .split('.')
+    .nth(1)
+    .unwrap()
+    .to_string();
+    assert!(verifier().verify_at(&parts.join("."), NOW).await.is_err());
+}
+
+// Re-encodes the header of a valid token, keeping its claims and signature
+fn with_algorithm(token: &str, algorithm: &str) -> String {
+    let (_, rest) = token.split_once('.').unwrap();
+    let header = format!(r#"{{"alg":"{}","kid":"loquat-test-key"}}"#, algorithm);
+    let encoded = openssl::base64::encode_block(header.as_bytes())
+        .trim_end_matches('=')
+        .replace('+', "-")
+        .replace('/', "_");
+    format!("{}.{}", encoded, rest)
+}
+
+#[tokio::test]
+async fn it_rejects_other_algorithms() {
+    let token = sign(valid_claims(), "loquat-test-key");
+    let verifier = verifier();
+    let unsigned = with_algorithm(&token, "none");
+    let (unsigned, _) = unsigned.rsplit_once('.').unwrap();
+    assert!(verifier
+        .verify_at(&format!("{}.", unsigned), NOW)
+        .await
+        .is_err());
+    assert!(verifier
+        .verify_at(&with_algorithm(&token, "HS256"), NOW)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn it_rejects_unknown_keys() {
+    assert!(verifier()
+        .verify_at(&sign(valid_claims(), "some-other-key"), NOW)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn it_validates_claims() {
+    let verifier = verifier();
+    let rejected = [
+        Claims {
+            exp: NOW - 3600,
+            ..valid_claims()
+        },
+        Claims {
+            iat: NOW + 3600,
+            ..valid_claims()
+        },
+        Claims {
+            aud: "another-project".to_string(),
+            ..valid_claims()
+        },
+        Claims {
+            iss: "https://securetoken.google.com/another-project".to_string(),
+            ..valid_claims()
+        },
+        Claims {
+            sub: "".to_string(),
+            ..valid_claims()
+        },
+    ];
+    for claims in rejected {
+        assert!(verifier
+            .verify_at(&sign(claims.clone(), "loquat-test-key"), NOW)
+            .await
+            .is_err());
+    }
+}
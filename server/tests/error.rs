@@ -0,0 +1,47 @@
+use axum::{
+    http::{header, StatusCode},
+    response::IntoResponse,
+};
+
+use loquat_common::{
+    api::{ApiError, ApiErrorCode},
+    import::{ImportError, Quantity},
+};
+use loquat_server::error::ErrorResponse;
+
+#[test]
+fn it_responds_with_json_and_the_codes_status() {
+    let response = ErrorResponse(ApiError::conflict("Fan Size 'A' already exists")).into_response();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert_eq!(
+        response.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/json"
+    );
+
+    let response = ErrorResponse(
+        ApiError::validation("Invalid Fan Size dimensions")
+            .with_field_error("diameter", "Must be positive"),
+    )
+    .into_response();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[test]
+fn it_places_import_errors_on_the_option_to_change() {
+    let ErrorResponse(err) = ImportError::MissingColumn(Quantity::StaticPressure).into();
+    assert_eq!(err.code, ApiErrorCode::Validation);
+    assert_eq!(err.field_errors[0].field, "static_pressure_column");
+}
+
+#[test]
+fn it_maps_missing_rows_to_not_found() {
+    let ErrorResponse(err) = sqlx::Error::RowNotFound.into();
+    assert_eq!(err.code, ApiErrorCode::NotFound);
+}
+
+#[test]
+fn it_hides_the_details_of_unexpected_errors() {
+    let ErrorResponse(err) = sqlx::Error::PoolTimedOut.into();
+    assert_eq!(err.code, ApiErrorCode::Internal);
+    assert!(!err.message.contains("pool"));
+}
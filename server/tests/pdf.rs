@@ -0,0 +1,93 @@
+use loquat_common::{
+    calculations::{
+        standards::A1OperatingPoint,
+        units::{BrakeHorsepower, FanSpeed, InletAirflow, StaticPressure},
+    },
+    display::UnitSystem,
+    models::{
+        A1Standard2010Determination, A1Standard2010Parameters, A1Standard2010Report, FanSeries,
+        FanSize, FanType,
+    },
+};
+use loquat_server::pdf::{a1_report_pdf, RatedOperatingPoint, Sheet};
+
+fn report() -> A1Standard2010Report<FanSize<FanSeries<()>>> {
+    let fan_size = FanSize {
+        id: "SKYPLUME G1-ELLV DMF-150".to_string(),
+        fan_series_id: "SKYPLUME G1-ELLV DMF".to_string(),
+        fan_series: FanSeries {
+            id: "SKYPLUME G1-ELLV DMF".to_string(),
+            fan_type: FanType::MixedFlow,
+            fan_sizes: (),
+        },
+        diameter: 18.25,
+        outlet_area: 200.5,
+    };
+    A1Standard2010Report {
+        id: "5000.1-A1".to_string(),
+        fan_size_id: fan_size.id.clone(),
+        fan_size,
+        parameters: A1Standard2010Parameters {
+            rpm: 1750.0,
+            air_density: None,
+        },
+        determinations: [
+            (0.001, 11077.0, 6.320),
+            (1.184, 9981.0, 6.632),
+            (2.593, 8884.0, 7.243),
+            (3.789, 7749.0, 7.481),
+            (4.608, 6659.0, 7.416),
+            (5.158, 5524.0, 7.079),
+            (5.532, 4436.0, 6.606),
+            (5.795, 3311.0, 6.171),
+            (6.054, 1549.0, 6.419),
+            (6.839, 0.0, 7.204),
+        ]
+        .into_iter()
+        .map(
+            |(static_pressure, cfm, brake_horsepower)| A1Standard2010Determination {
+                cfm,
+                static_pressure,
+                brake_horsepower,
+            },
+        )
+        .collect(),
+    }
+}
+
+fn page_count(pdf: &[u8]) -> usize {
+    String::from_utf8_lossy(pdf).matches("/Type /Page\n").count()
+}
+
+#[test]
+fn it_renders_a_report_on_one_page() {
+    let pdf = a1_report_pdf(&report(), None, UnitSystem::Imperial).unwrap();
+    assert!(pdf.starts_with(b"%PDF-"));
+    assert_eq!(page_count(&pdf), 1);
+    assert!(String::from_utf8_lossy(&pdf).contains("/Subtype /Form"));
+}
+
+#[test]
+fn it_renders_a_rated_operating_point_in_si() {
+    let report = report();
+    let rated = RatedOperatingPoint {
+        fan_size: report.fan_size.clone(),
+        operating_point: A1OperatingPoint::new(
+            FanSpeed::from_rpm(1750.0),
+            InletAirflow::from_cfm(7749.0),
+            StaticPressure::from_inches(3.789),
+            BrakeHorsepower::from_hp(7.481),
+        ),
+    };
+    let pdf = a1_report_pdf(&report, Some(&rated), UnitSystem::Si).unwrap();
+    assert!(pdf.starts_with(b"%PDF-"));
+    assert!(page_count(&pdf) >= 1);
+}
+
+#[test]
+fn it_continues_long_tables_onto_new_pages() {
+    let mut sheet = Sheet::new("Long Table", "Every row");
+    let rows: Vec<Vec<String>> = (0..100).map(|i| vec![i.to_string()]).collect();
+    sheet.table(&["#"], &rows);
+    assert_eq!(page_count(&sheet.finish()), 3);
+}